@@ -53,6 +53,40 @@ pub enum Commands {
     /// Finds nodes whose names match a substring or regex, and optionally
     /// reports their degree, neighbors, or incident edge types.
     Search(SearchArgs),
+
+    /// Score a batch of alignment files against the same network pair.
+    ///
+    /// Merges and scores every `.align` file in a directory, producing one
+    /// row per file. This automates the sweep-and-compare workflow used to
+    /// pick the best of many candidate alignments (e.g. an importance-weight
+    /// sweep across `s3_001.align` .. `s3_100.align`).
+    AlignSweep(AlignSweepArgs),
+
+    /// Compute whole-network structural metrics (diameter, density).
+    Analyze(AnalyzeArgs),
+
+    /// Report the hop-count distance between two nodes.
+    ///
+    /// Useful for sanity-checking an extracted subnetwork, or confirming
+    /// two nodes aren't as closely related as their shared annotations
+    /// might suggest.
+    Distance(DistanceArgs),
+
+    /// Compare two networks and report added/removed nodes and links.
+    ///
+    /// Useful for comparing two experimental conditions, or two revisions
+    /// of the same network, before deciding whether to align them.
+    Diff(DiffArgs),
+
+    /// Rank nodes by a centrality metric (currently: PageRank).
+    Centrality(CentralityArgs),
+
+    /// Render a network straight to an image or vector file.
+    ///
+    /// Computes the default layout, then writes it as SVG (.svg) or a
+    /// raster image (.png/.webp/.bmp/.tif/.tiff), picked from `--output`'s
+    /// extension.
+    Render(RenderArgs),
 }
 
 // ==========================================================================
@@ -115,6 +149,14 @@ pub struct LayoutArgs {
     #[arg(long)]
     pub set_attribute: Option<String>,
 
+    /// Flip which side of each edge is the "set" and which is the
+    /// "member" (--algorithm set).
+    ///
+    /// Equivalent to re-running with the opposite set-membership
+    /// semantics (belongs-to vs. contains), without reloading the input.
+    #[arg(long)]
+    pub transpose: bool,
+
     /// Attribute name for control-top layouts (--algorithm control-top).
     ///
     /// Nodes with a non-empty value for this attribute are treated as
@@ -155,6 +197,29 @@ pub struct LayoutArgs {
     /// layout algorithm. The file must list every node in the network.
     #[arg(long)]
     pub node_order: Option<PathBuf>,
+
+    /// Cap every node's degree at this value before laying out, dropping
+    /// the lowest-priority edges of any hub that exceeds it.
+    ///
+    /// Useful for a readable approximation of hub-dominated ("hairball")
+    /// networks. See `Network::cap_degree` for the edge-selection rule.
+    #[arg(long)]
+    pub cap_degree: Option<usize>,
+
+    /// Write the computed layout to this file as a `bincode` cache, in
+    /// addition to `--output`.
+    ///
+    /// A cache written here can be loaded back with
+    /// `NetworkLayout::load_cache` (e.g. by `export-order --layout-cache`)
+    /// to skip recomputing the layout on a later run.
+    #[arg(long)]
+    pub cache: Option<PathBuf>,
+
+    /// Treat `input` as a directory instead of a single file: lay out every
+    /// `.sif`/`.gw` file in it and write a `.bif` session per file into the
+    /// directory named by `--output`. Files are processed concurrently.
+    #[arg(long)]
+    pub batch: bool,
 }
 
 // ==========================================================================
@@ -170,6 +235,13 @@ pub struct InfoArgs {
     #[arg(long, default_value = "text", value_enum)]
     pub format: InfoFormat,
 
+    /// Shorthand for `--format json --degree-distribution --components`, so
+    /// a script can pull node count, link count, shadow count, lone-node
+    /// count, component count, and degree distribution from one JSON object
+    /// without remembering which flags to combine.
+    #[arg(long)]
+    pub json: bool,
+
     /// Show degree distribution histogram.
     #[arg(long)]
     pub degree_distribution: bool,
@@ -182,9 +254,28 @@ pub struct InfoArgs {
     #[arg(long)]
     pub components: bool,
 
-    /// Show all information (equivalent to --degree-distribution --relations --components).
+    /// Show the average clustering coefficient.
+    #[arg(long)]
+    pub clustering: bool,
+
+    /// Show the network diameter (largest connected component) and an
+    /// eccentricity summary over every node, each within its own component.
+    #[arg(long)]
+    pub diameter: bool,
+
+    /// Show all information (equivalent to --degree-distribution --relations --components --clustering --diameter).
     #[arg(long)]
     pub all: bool,
+
+    /// Compute the default layout and validate its internal consistency.
+    #[arg(long)]
+    pub validate: bool,
+
+    /// Compute the default layout and report its crossing count, so
+    /// different layout algorithms or parameter choices can be compared
+    /// quantitatively on the same network.
+    #[arg(long)]
+    pub crossings: bool,
 }
 
 // ==========================================================================
@@ -193,7 +284,7 @@ pub struct InfoArgs {
 
 #[derive(Args, Debug)]
 pub struct ConvertArgs {
-    /// Input network file.
+    /// Input network file. `-` reads SIF from stdin.
     pub input: PathBuf,
 
     /// Output format.
@@ -202,7 +293,7 @@ pub struct ConvertArgs {
 
     /// Output file path.
     ///
-    /// If omitted, output is written to stdout (not supported for XML/binary).
+    /// If omitted, or `-`, output is written to stdout (not supported for XML/binary).
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
@@ -276,6 +367,91 @@ pub struct AlignArgs {
     /// Output scores as JSON (for programmatic consumption).
     #[arg(long)]
     pub json: bool,
+
+    /// Write the node-to-node mapping table (G1 name, G2 name, color class)
+    /// to a CSV file — the human-readable inverse of the alignment file.
+    #[arg(long)]
+    pub mapping_csv: Option<PathBuf>,
+
+    /// Print every edge of the given type's short code (e.g. `pRr`), one
+    /// per line, for debugging why an alignment scores the way it does.
+    #[arg(long)]
+    pub dump_edge_type: Option<String>,
+
+    /// Write each merged node's group assignment (node, color, group
+    /// symbol) to a CSV file.
+    #[arg(long)]
+    pub groups_out: Option<PathBuf>,
+}
+
+// ==========================================================================
+// Align-sweep command
+// ==========================================================================
+
+#[derive(Args, Debug)]
+pub struct AlignSweepArgs {
+    /// First network file (G1 — the smaller network).
+    pub g1: PathBuf,
+
+    /// Second network file (G2 — the larger network).
+    pub g2: PathBuf,
+
+    /// Directory of `.align` files to score, one row per file.
+    #[arg(long)]
+    pub aligns: PathBuf,
+
+    /// Perfect (reference) alignment file for evaluation metrics.
+    ///
+    /// When provided, additional metrics are computed for every row:
+    /// NC (node correctness), NGS, LGS, JS.
+    #[arg(long)]
+    pub perfect: Option<PathBuf>,
+
+    /// Output TSV file. If omitted, the table is printed to stdout.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+// ==========================================================================
+// Analyze command
+// ==========================================================================
+
+#[derive(Args, Debug)]
+pub struct AnalyzeArgs {
+    /// Input network file.
+    pub input: PathBuf,
+
+    /// Which metric to compute.
+    #[arg(long, value_enum)]
+    pub metric: AnalyzeMetric,
+
+    /// Estimate diameter via BFS from a sample of nodes instead of every
+    /// node, trading exactness for speed on large networks. Takes the
+    /// sample size (number of nodes to BFS from).
+    ///
+    /// Ignored for `--metric density`, which is always exact (it doesn't
+    /// require any shortest-path search).
+    #[arg(long, value_name = "SAMPLE_SIZE")]
+    pub approx: Option<usize>,
+
+    /// Output format.
+    #[arg(long, default_value = "text", value_enum)]
+    pub format: InfoFormat,
+}
+
+/// Metric computed by `biofabric analyze`.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum AnalyzeMetric {
+    /// Longest shortest path in the largest connected component.
+    Diameter,
+    /// Fraction of possible node-pair edges actually present.
+    Density,
+    /// Average local clustering coefficient over all nodes.
+    ClusteringCoefficient,
+    /// Whether the network contains a cycle (self-loop, or a back edge
+    /// found while treating non-shadow, non-explicitly-directed links as
+    /// undirected, or a directed cycle among explicitly-directed links).
+    HasCycle,
 }
 
 // ==========================================================================
@@ -287,15 +463,20 @@ pub struct CompareArgs {
     /// Input network file.
     pub input: PathBuf,
 
-    /// First node to compare.
-    pub node_a: String,
+    /// First node to compare. Omit when using `--matrix`.
+    pub node_a: Option<String>,
 
-    /// Second node to compare.
-    pub node_b: String,
+    /// Second node to compare. Omit when using `--matrix`.
+    pub node_b: Option<String>,
 
     /// Output format.
     #[arg(long, default_value = "text", value_enum)]
     pub format: InfoFormat,
+
+    /// Comma-separated node names to print a pairwise Jaccard similarity
+    /// matrix for, instead of comparing just `node_a`/`node_b`.
+    #[arg(long)]
+    pub matrix: Option<String>,
 }
 
 // ==========================================================================
@@ -335,15 +516,43 @@ pub struct ExtractArgs {
 #[derive(Args, Debug)]
 pub struct ExportOrderArgs {
     /// Input session file (.bif, .xml) or layout JSON.
-    pub input: PathBuf,
+    ///
+    /// Not required when `--layout-cache` is given instead.
+    pub input: Option<PathBuf>,
 
     /// What to export.
     #[arg(long, default_value = "nodes", value_enum)]
     pub what: OrderExportType,
 
+    /// Output format.
+    #[arg(long, default_value = "text", value_enum)]
+    pub format: OrderExportFormat,
+
     /// Output file (stdout if omitted).
     #[arg(short, long)]
     pub output: Option<PathBuf>,
+
+    /// Load the layout from a `bincode` cache written by
+    /// `layout --cache` instead of parsing `input`.
+    #[arg(long, conflicts_with = "input")]
+    pub layout_cache: Option<PathBuf>,
+
+    /// Write a NOA (node order attribute) file to this path.
+    ///
+    /// Combine with `--eda` to write both files from one call. When either
+    /// is given, `--what`/`--format`/`--output` are ignored.
+    #[arg(long)]
+    pub noa: Option<PathBuf>,
+
+    /// Write an EDA (edge/link order attribute) file to this path. See
+    /// `--noa`.
+    #[arg(long)]
+    pub eda: Option<PathBuf>,
+
+    /// When writing `--eda`, omit shadow links and number columns as if
+    /// shadows were never added.
+    #[arg(long)]
+    pub no_shadows: bool,
 }
 
 // ==========================================================================
@@ -425,6 +634,18 @@ pub enum LayoutAlgorithm {
     Set,
     /// Hub-spoke grouping.
     WorldBank,
+    /// Fiedler (graph Laplacian) spectral ordering.
+    Spectral,
+    /// Reverse Cuthill-McKee bandwidth minimization.
+    Rcm,
+    /// Pure descending-degree ordering ("hubs at top").
+    Degree,
+    /// k-core decomposition ordering (densest core first).
+    Kcore,
+    /// Median-neighbor barycenter crossing-reduction sweeps.
+    Barycenter,
+    /// Descending PageRank score ordering.
+    Pagerank,
 }
 
 /// Link group organization mode.
@@ -512,6 +733,8 @@ pub enum ConvertFormat {
     Json,
     /// BioFabric XML session (.bif).
     Xml,
+    /// DOT/Graphviz (.dot). Write-only.
+    Dot,
 }
 
 /// Info output format.
@@ -533,3 +756,148 @@ pub enum OrderExportType {
     /// Link ordering (source<TAB>relation<TAB>target per line, in column order).
     Links,
 }
+
+/// Output format for the export-order command.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OrderExportFormat {
+    /// Plain text, one entry per line (honors `--what`).
+    #[default]
+    Text,
+    /// The full computed layout geometry (node rows/spans, link columns,
+    /// drain zones, annotation bands) as JSON. Ignores `--what`, since the
+    /// JSON export always includes both nodes and links.
+    Json,
+}
+
+// ==========================================================================
+// Distance command
+// ==========================================================================
+
+#[derive(Args, Debug)]
+pub struct DistanceArgs {
+    /// Input network file.
+    pub input: PathBuf,
+
+    /// First node.
+    pub node_a: String,
+
+    /// Second node.
+    pub node_b: String,
+
+    /// Output format.
+    #[arg(long, default_value = "text", value_enum)]
+    pub format: InfoFormat,
+}
+
+// ==========================================================================
+// Diff command
+// ==========================================================================
+
+#[derive(Args, Debug)]
+pub struct DiffArgs {
+    /// "Before" network file.
+    pub a: PathBuf,
+
+    /// "After" network file.
+    pub b: PathBuf,
+
+    /// Output format.
+    #[arg(long, default_value = "text", value_enum)]
+    pub format: InfoFormat,
+
+    /// Also write a layout of the union of `a` and `b` to this file, with
+    /// added/removed links called out in its link annotations. `.json`
+    /// writes layout JSON; `.bif`/`.xml` saves a full session.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+// ==========================================================================
+// Centrality command
+// ==========================================================================
+
+#[derive(Args, Debug)]
+pub struct CentralityArgs {
+    /// Input network file.
+    pub input: PathBuf,
+
+    /// Which centrality metric to compute.
+    #[arg(long, value_enum, default_value = "pagerank")]
+    pub metric: CentralityMetric,
+
+    /// PageRank damping factor.
+    #[arg(long, default_value_t = 0.85)]
+    pub damping: f64,
+
+    /// Number of power-iteration rounds.
+    #[arg(long, default_value_t = 100)]
+    pub iters: usize,
+
+    /// Output format.
+    #[arg(long, default_value = "text", value_enum)]
+    pub format: InfoFormat,
+}
+
+/// Centrality metric selector for [`CentralityArgs`].
+#[derive(ValueEnum, Debug, Clone, Copy, Default)]
+pub enum CentralityMetric {
+    /// PageRank score over the undirected, non-shadow graph.
+    #[default]
+    Pagerank,
+}
+
+// ==========================================================================
+// Render command
+// ==========================================================================
+
+#[derive(Args, Debug)]
+pub struct RenderArgs {
+    /// Input network file (.sif, .gw, .json, .bif/.xml).
+    pub input: PathBuf,
+
+    /// Output file. `.svg` writes vector output; `.png`/`.webp`/`.bmp`/
+    /// `.tif`/`.tiff` write a raster image.
+    #[arg(short, long)]
+    pub output: PathBuf,
+
+    /// Include shadow links. Enabled by default; use --no-shadows to disable.
+    #[arg(long, default_value_t = true)]
+    pub shadows: bool,
+
+    /// Disable shadow links.
+    #[arg(long)]
+    pub no_shadows: bool,
+
+    /// Quantize PNG output to an indexed palette instead of truecolor,
+    /// substantially shrinking file size for figures with few distinct
+    /// colors. Has no effect on other output formats.
+    #[arg(long)]
+    pub indexed: bool,
+
+    /// Crop to this inclusive row range before rendering, e.g. `0:100`.
+    /// Defaults to the full layout height.
+    #[arg(long, value_name = "MIN:MAX")]
+    pub rows: Option<String>,
+
+    /// Crop to this inclusive column range before rendering, e.g.
+    /// `200:400`. Defaults to the full layout width.
+    #[arg(long, value_name = "MIN:MAX")]
+    pub cols: Option<String>,
+
+    /// Render a downscaled density-map thumbnail instead of the full
+    /// fabric, useful as a fast "is the layout sane" check. Rejected for
+    /// `.svg` output.
+    #[arg(long)]
+    pub overview: bool,
+
+    /// Longer side, in pixels, of the `--overview` thumbnail.
+    #[arg(long, default_value_t = 512)]
+    pub overview_max_dim: usize,
+
+    /// Render in independently-allocated tiles of this many layout rows/
+    /// columns each, then stitch them together, instead of allocating one
+    /// `width * height` buffer. Avoids OOMs on huge exports. Rejected for
+    /// `.svg` output.
+    #[arg(long)]
+    pub tile_size: Option<usize>,
+}
@@ -29,11 +29,7 @@ pub fn run(args: AlignArgs, quiet: bool) -> Result<(), Box<dyn std::error::Error
 
     // Compute scores if requested
     if args.score {
-        let scores = if let Some(ref perf) = perfect {
-            AlignmentScores::with_evaluation(&merged, perf, &NoopMonitor)
-        } else {
-            AlignmentScores::topological(&merged, &NoopMonitor)
-        };
+        let scores = AlignmentScores::compute(&g1, &g2, &alignment, perfect.as_ref(), &NoopMonitor)?;
 
         if args.json {
             println!("{}", serde_json::to_string_pretty(&scores)?);
@@ -56,6 +52,51 @@ pub fn run(args: AlignArgs, quiet: bool) -> Result<(), Box<dyn std::error::Error
         }
     }
 
+    // Write the node mapping table if requested
+    if let Some(csv_path) = &args.mapping_csv {
+        use std::io::Write;
+
+        let mut out = std::fs::File::create(csv_path)?;
+        writeln!(out, "g1,g2,color")?;
+        for (g1_name, g2_name, color) in merged.mapping_table() {
+            writeln!(
+                out,
+                "{},{},{}",
+                g1_name.unwrap_or_default(),
+                g2_name.unwrap_or_default(),
+                color
+            )?;
+        }
+
+        if !quiet {
+            eprintln!("Mapping table written to {}", csv_path.display());
+        }
+    }
+
+    // Write the node-group assignment table if requested
+    if let Some(groups_path) = &args.groups_out {
+        use biofabric_core::alignment::groups::NodeGroupMap;
+
+        let groups = NodeGroupMap::from_merged(&merged, &NoopMonitor);
+        let mut out = std::fs::File::create(groups_path)?;
+        groups.to_csv(&mut out)?;
+
+        if !quiet {
+            eprintln!("Node groups written to {}", groups_path.display());
+        }
+    }
+
+    // Dump all edges of a given type, for debugging alignment scores
+    if let Some(code) = &args.dump_edge_type {
+        use biofabric_core::alignment::types::EdgeType;
+
+        let edge_type = EdgeType::from_short_code(code)
+            .ok_or_else(|| format!("Unknown edge type code '{}'", code))?;
+        for link in merged.links_of_type(edge_type) {
+            println!("{}\t{}\t{}", link.source, link.relation, link.target);
+        }
+    }
+
     // Compute layout if output is requested
     if let Some(output) = &args.output {
         use biofabric_core::alignment::layout::{
@@ -0,0 +1,72 @@
+//! `biofabric align-sweep` — score a batch of alignment files.
+
+use crate::args::AlignSweepArgs;
+use biofabric_core::alignment::scoring::{self, AlignmentScores};
+use biofabric_core::io::factory::FabricFactory;
+use biofabric_core::worker::NoopMonitor;
+use std::io::Write;
+
+pub fn run(args: AlignSweepArgs, quiet: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let g1 = FabricFactory::load_network(&args.g1)?;
+    let g2 = FabricFactory::load_network(&args.g2)?;
+
+    let mut align_paths: Vec<_> = std::fs::read_dir(&args.aligns)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("align"))
+        .collect();
+    align_paths.sort();
+
+    // Labels are kept in their own `Vec` so `aligns` below can borrow `&str`
+    // from them while moving (not cloning) each `AlignmentMap`.
+    let labels: Vec<String> = align_paths
+        .iter()
+        .map(|path| path.file_stem().and_then(|s| s.to_str()).unwrap_or_default().to_string())
+        .collect();
+    let alignments = align_paths
+        .iter()
+        .map(|path| FabricFactory::load_alignment(path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let aligns: Vec<(&str, _)> = labels.iter().map(|s| s.as_str()).zip(alignments).collect();
+
+    let perfect = args
+        .perfect
+        .as_ref()
+        .map(|p| FabricFactory::load_alignment(p))
+        .transpose()?;
+
+    // Each alignment's merge + score is independent of every other one, so
+    // this is computed concurrently via `scoring::sweep_par` rather than
+    // `scoring::sweep`'s sequential loop.
+    let results: Vec<(String, AlignmentScores)> =
+        scoring::sweep_par(&g1, &g2, &aligns, perfect.as_ref(), &NoopMonitor)?;
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(std::fs::File::create(path)?),
+        None => Box::new(std::io::stdout()),
+    };
+
+    writeln!(out, "alignment\tEC\tS3\tICS\tNC\tNGS\tLGS\tJS")?;
+    for (label, scores) in &results {
+        writeln!(
+            out,
+            "{}\t{:.4}\t{:.4}\t{:.4}\t{}\t{}\t{}\t{}",
+            label,
+            scores.ec,
+            scores.s3,
+            scores.ics,
+            scores.nc.map(|v| format!("{:.4}", v)).unwrap_or_default(),
+            scores.ngs.map(|v| format!("{:.4}", v)).unwrap_or_default(),
+            scores.lgs.map(|v| format!("{:.4}", v)).unwrap_or_default(),
+            scores.js.map(|v| format!("{:.4}", v)).unwrap_or_default(),
+        )?;
+    }
+
+    if !quiet {
+        if let Some(path) = &args.output {
+            eprintln!("Scored {} alignments to {}", results.len(), path.display());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! `biofabric analyze` — compute whole-network structural metrics.
+
+use crate::args::{AnalyzeArgs, AnalyzeMetric, InfoFormat};
+use biofabric_core::analysis;
+use biofabric_core::io::factory::FabricFactory;
+
+pub fn run(args: AnalyzeArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let network = FabricFactory::load_network(&args.input)?;
+
+    match args.metric {
+        AnalyzeMetric::Diameter => {
+            let diameter = match args.approx {
+                Some(sample_size) => analysis::diameter_approx(&network, sample_size),
+                None => analysis::diameter(&network),
+            };
+
+            match args.format {
+                InfoFormat::Text => match diameter {
+                    Some(d) => println!("Diameter: {}{}", d, if args.approx.is_some() { " (approx)" } else { "" }),
+                    None => println!("Diameter: n/a (empty network)"),
+                },
+                InfoFormat::Json => {
+                    let json = serde_json::json!({
+                        "metric": "diameter",
+                        "value": diameter,
+                        "approx": args.approx.is_some(),
+                    });
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                }
+            }
+        }
+        AnalyzeMetric::Density => {
+            let density = analysis::density(&network);
+
+            match args.format {
+                InfoFormat::Text => println!("Density: {:.4}", density),
+                InfoFormat::Json => {
+                    let json = serde_json::json!({
+                        "metric": "density",
+                        "value": density,
+                    });
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                }
+            }
+        }
+        AnalyzeMetric::ClusteringCoefficient => {
+            let (_, average) = analysis::clustering_coefficient(&network);
+
+            match args.format {
+                InfoFormat::Text => println!("Clustering coefficient: {:.4}", average),
+                InfoFormat::Json => {
+                    let json = serde_json::json!({
+                        "metric": "clustering_coefficient",
+                        "value": average,
+                    });
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                }
+            }
+        }
+        AnalyzeMetric::HasCycle => {
+            let has_cycle = analysis::has_cycle(&network) || analysis::find_cycle(&network).has_cycle;
+
+            match args.format {
+                InfoFormat::Text => println!("Has cycle: {}", has_cycle),
+                InfoFormat::Json => {
+                    let json = serde_json::json!({
+                        "metric": "has_cycle",
+                        "value": has_cycle,
+                    });
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
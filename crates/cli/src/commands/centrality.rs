@@ -0,0 +1,36 @@
+//! `biofabric centrality` — rank nodes by a centrality metric.
+
+use crate::args::{CentralityArgs, CentralityMetric, InfoFormat};
+use biofabric_core::analysis;
+use biofabric_core::io::factory::FabricFactory;
+
+pub fn run(args: CentralityArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let network = FabricFactory::load_network(&args.input)?;
+
+    let scores = match args.metric {
+        CentralityMetric::Pagerank => analysis::pagerank(&network, args.damping, args.iters),
+    };
+
+    let mut ranked: Vec<(String, f64)> = scores.into_iter().map(|(id, score)| (id.to_string(), score)).collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(&b.0)));
+
+    match args.format {
+        InfoFormat::Text => {
+            for (node, score) in &ranked {
+                println!("{score:.6}\t{node}");
+            }
+        }
+        InfoFormat::Json => {
+            let json = serde_json::json!({
+                "metric": "pagerank",
+                "ranking": ranked.iter().map(|(node, score)| serde_json::json!({
+                    "node": node,
+                    "score": score,
+                })).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+    }
+
+    Ok(())
+}
@@ -2,21 +2,28 @@
 
 use crate::args::{CompareArgs, InfoFormat};
 use biofabric_core::io::factory::FabricFactory;
+use biofabric_core::model::Network;
 use biofabric_core::NodeId;
 
 pub fn run(args: CompareArgs) -> Result<(), Box<dyn std::error::Error>> {
     let network = FabricFactory::load_network(&args.input)?;
 
-    let node_a = NodeId::new(&args.node_a);
-    let node_b = NodeId::new(&args.node_b);
+    if let Some(matrix_arg) = &args.matrix {
+        return run_matrix(&network, matrix_arg, args.format);
+    }
+
+    let node_a = NodeId::new(args.node_a.as_deref().ok_or("Missing <node_a> (or pass --matrix)")?);
+    let node_b = NodeId::new(args.node_b.as_deref().ok_or("Missing <node_b> (or pass --matrix)")?);
 
     let comparison = network.compare_nodes(&node_a, &node_b).ok_or_else(|| {
-        let missing: Vec<&str> = [(&node_a, &args.node_a), (&node_b, &args.node_b)]
-            .iter()
-            .filter(|(id, _)| !network.contains_node(id))
-            .map(|(_, name)| name.as_str())
+        let missing: Vec<&NodeId> = [&node_a, &node_b]
+            .into_iter()
+            .filter(|id| !network.contains_node(id))
             .collect();
-        format!("Node(s) not found: {}", missing.join(", "))
+        format!(
+            "Node(s) not found: {}",
+            missing.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")
+        )
     })?;
 
     match args.format {
@@ -61,3 +68,40 @@ pub fn run(args: CompareArgs) -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/// Print the pairwise Jaccard similarity matrix for `--matrix n1,n2,n3`.
+fn run_matrix(network: &Network, matrix_arg: &str, format: InfoFormat) -> Result<(), Box<dyn std::error::Error>> {
+    let names: Vec<&str> = matrix_arg.split(',').map(str::trim).collect();
+    let nodes: Vec<NodeId> = names.iter().map(|n| NodeId::new(*n)).collect();
+
+    let missing: Vec<&str> = names
+        .iter()
+        .zip(&nodes)
+        .filter(|(_, id)| !network.contains_node(id))
+        .map(|(name, _)| *name)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Node(s) not found: {}", missing.join(", ")).into());
+    }
+
+    let matrix = network.neighborhood_similarity_matrix(&nodes);
+
+    match format {
+        InfoFormat::Text => {
+            println!("\t{}", names.join("\t"));
+            for (name, row) in names.iter().zip(&matrix) {
+                let cells: Vec<String> = row.iter().map(|v| format!("{v:.4}")).collect();
+                println!("{}\t{}", name, cells.join("\t"));
+            }
+        }
+        InfoFormat::Json => {
+            let json = serde_json::json!({
+                "nodes": names,
+                "matrix": matrix,
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+    }
+
+    Ok(())
+}
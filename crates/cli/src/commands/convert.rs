@@ -1,10 +1,24 @@
 //! `biofabric convert` — convert a network between file formats.
 
 use crate::args::{ConvertArgs, ConvertFormat};
-use biofabric_core::io::factory::{FabricFactory, OutputFormat};
+use biofabric_core::io::factory::{FabricFactory, InputFormat, OutputFormat};
+use std::io::Read;
+use std::path::Path;
+
+/// Placeholder path accepted wherever a real file path is otherwise
+/// required, meaning "use stdin" (as input) or "use stdout" (as output).
+const STDIN_STDOUT_MARKER: &str = "-";
 
 pub fn run(args: ConvertArgs, quiet: bool) -> Result<(), Box<dyn std::error::Error>> {
-    let mut network = FabricFactory::load_network(&args.input)?;
+    let reading_stdin = args.input == Path::new(STDIN_STDOUT_MARKER);
+
+    let mut network = if reading_stdin {
+        let mut data = String::new();
+        std::io::stdin().read_to_string(&mut data)?;
+        FabricFactory::parse_network(InputFormat::Sif, &data)?
+    } else {
+        FabricFactory::load_network(&args.input)?
+    };
 
     // Strip shadows unless --keep-shadows
     if !args.keep_shadows {
@@ -16,9 +30,20 @@ pub fn run(args: ConvertArgs, quiet: bool) -> Result<(), Box<dyn std::error::Err
         ConvertFormat::Gw => OutputFormat::Gw,
         ConvertFormat::Json => OutputFormat::Json,
         ConvertFormat::Xml => OutputFormat::Xml,
+        ConvertFormat::Dot => OutputFormat::Dot,
     };
 
-    if let Some(path) = &args.output {
+    let writing_stdout = match &args.output {
+        Some(path) => path == Path::new(STDIN_STDOUT_MARKER),
+        None => true,
+    };
+
+    if writing_stdout {
+        // Write to stdout (not supported for XML)
+        let s = FabricFactory::write_network_string(&network, out_format)?;
+        print!("{}", s);
+    } else {
+        let path = args.output.as_ref().expect("writing_stdout is false only when output is Some");
         FabricFactory::write_network(&network, out_format, path)?;
         if !quiet {
             eprintln!(
@@ -29,10 +54,6 @@ pub fn run(args: ConvertArgs, quiet: bool) -> Result<(), Box<dyn std::error::Err
                 network.link_count(),
             );
         }
-    } else {
-        // Write to stdout (not supported for XML)
-        let s = FabricFactory::write_network_string(&network, out_format)?;
-        print!("{}", s);
     }
 
     Ok(())
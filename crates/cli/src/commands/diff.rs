@@ -0,0 +1,76 @@
+//! `biofabric diff` — compare two networks and report added/removed nodes and links.
+
+use crate::args::{DiffArgs, InfoFormat};
+use biofabric_core::io::factory::FabricFactory;
+use biofabric_core::layout::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+use biofabric_core::layout::{DefaultEdgeLayout, DefaultNodeLayout};
+use biofabric_core::worker::NoopMonitor;
+
+pub fn run(args: DiffArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let before = FabricFactory::load_network(&args.a)?;
+    let after = FabricFactory::load_network(&args.b)?;
+
+    let diff = before.diff(&after);
+
+    match args.format {
+        InfoFormat::Text => {
+            println!("Added nodes ({}): {}", diff.added_nodes.len(), join_ids(&diff.added_nodes));
+            println!("Removed nodes ({}): {}", diff.removed_nodes.len(), join_ids(&diff.removed_nodes));
+            println!("Added links ({}):", diff.added_links.len());
+            for link in &diff.added_links {
+                println!("  + {}\t{}\t{}", link.source, link.relation, link.target);
+            }
+            println!("Removed links ({}):", diff.removed_links.len());
+            for link in &diff.removed_links {
+                println!("  - {}\t{}\t{}", link.source, link.relation, link.target);
+            }
+        }
+        InfoFormat::Json => {
+            let json = serde_json::json!({
+                "added_nodes": diff.added_nodes.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+                "removed_nodes": diff.removed_nodes.iter().map(|n| n.to_string()).collect::<Vec<_>>(),
+                "added_links": diff.added_links.iter().map(|l| serde_json::json!({
+                    "source": l.source.to_string(),
+                    "relation": l.relation,
+                    "target": l.target.to_string(),
+                })).collect::<Vec<_>>(),
+                "removed_links": diff.removed_links.iter().map(|l| serde_json::json!({
+                    "source": l.source.to_string(),
+                    "relation": l.relation,
+                    "target": l.target.to_string(),
+                })).collect::<Vec<_>>(),
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+    }
+
+    if let Some(output) = &args.output {
+        let merged = before.union(&after);
+        let two_phase = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let mut layout = two_phase.layout(&merged, &LayoutParams::default(), &NoopMonitor)?;
+        let annotations = layout.diff_annotations(&diff);
+        layout.link_annotations = annotations.clone();
+        layout.link_annotations_no_shadows = annotations;
+
+        let ext = output.extension().and_then(|e| e.to_str()).unwrap_or("");
+        match ext {
+            "json" => {
+                let json = serde_json::to_string_pretty(&layout)?;
+                std::fs::write(output, json)?;
+            }
+            "bif" | "xml" => {
+                let session = biofabric_core::io::session::Session::with_layout(merged, layout);
+                FabricFactory::save_session(&session, output)?;
+            }
+            _ => {
+                return Err(format!("Unsupported output format '{}'. Use .json, .bif, or .xml", ext).into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn join_ids(ids: &[biofabric_core::NodeId]) -> String {
+    ids.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")
+}
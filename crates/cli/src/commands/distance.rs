@@ -0,0 +1,40 @@
+//! `biofabric distance` — hop-count distance between two nodes.
+
+use crate::args::{DistanceArgs, InfoFormat};
+use biofabric_core::io::factory::FabricFactory;
+use biofabric_core::NodeId;
+
+pub fn run(args: DistanceArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let network = FabricFactory::load_network(&args.input)?;
+
+    let node_a = NodeId::new(&args.node_a);
+    let node_b = NodeId::new(&args.node_b);
+
+    let missing: Vec<&str> = [(&args.node_a, &node_a), (&args.node_b, &node_b)]
+        .into_iter()
+        .filter(|(_, id)| !network.contains_node(id))
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Node(s) not found: {}", missing.join(", ")).into());
+    }
+
+    let distance = network.shortest_path_len(&node_a, &node_b);
+
+    match args.format {
+        InfoFormat::Text => match distance {
+            Some(d) => println!("{}", d),
+            None => println!("unreachable"),
+        },
+        InfoFormat::Json => {
+            let json = serde_json::json!({
+                "node_a": args.node_a,
+                "node_b": args.node_b,
+                "distance": distance,
+            });
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+    }
+
+    Ok(())
+}
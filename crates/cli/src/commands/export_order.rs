@@ -1,32 +1,55 @@
 //! `biofabric export-order` — export node or link ordering.
 
-use crate::args::{ExportOrderArgs, OrderExportType};
+use crate::args::{ExportOrderArgs, OrderExportFormat, OrderExportType};
 use biofabric_core::io::factory::FabricFactory;
 
 pub fn run(args: ExportOrderArgs) -> Result<(), Box<dyn std::error::Error>> {
-    // Determine input type by extension
-    let ext = args.input.extension().and_then(|e| e.to_str()).unwrap_or("");
-
-    let layout = match ext {
-        "bif" | "xml" => {
-            let session = FabricFactory::load_session(&args.input)?;
-            session
-                .layout
-                .ok_or("Session file has no saved layout")?
+    let layout = if let Some(cache_path) = &args.layout_cache {
+        biofabric_core::layout::NetworkLayout::load_cache(cache_path)?
+    } else {
+        let input = args
+            .input
+            .as_ref()
+            .ok_or("Provide an input file or --layout-cache")?;
+
+        // Determine input type by extension
+        let ext = input.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+        match ext {
+            "bif" | "xml" => {
+                let session = FabricFactory::load_session(input)?;
+                session
+                    .layout
+                    .ok_or("Session file has no saved layout")?
+            }
+            "json" => {
+                let data = std::fs::read_to_string(input)?;
+                serde_json::from_str(&data)
+                    .map_err(|e| format!("Failed to parse layout JSON: {}", e))?
+            }
+            _ => {
+                return Err(format!(
+                    "Unsupported input format '{}'. Provide a .bif/.xml session, .json layout file, or --layout-cache.",
+                    ext
+                )
+                .into());
+            }
         }
-        "json" => {
-            let data = std::fs::read_to_string(&args.input)?;
-            serde_json::from_str(&data)
-                .map_err(|e| format!("Failed to parse layout JSON: {}", e))?
+    };
+
+    if args.noa.is_some() || args.eda.is_some() {
+        if let Some(path) = &args.noa {
+            biofabric_core::io::order::write_noa_file(path, &layout)?;
         }
-        _ => {
-            return Err(format!(
-                "Unsupported input format '{}'. Provide a .bif/.xml session or .json layout file.",
-                ext
-            )
-            .into());
+        if let Some(path) = &args.eda {
+            if args.no_shadows {
+                biofabric_core::io::order::write_eda_no_shadows_file(path, &layout)?;
+            } else {
+                biofabric_core::io::order::write_eda_file(path, &layout)?;
+            }
         }
-    };
+        return Ok(());
+    }
 
     // Write output
     let mut writer: Box<dyn std::io::Write> = if let Some(path) = &args.output {
@@ -35,6 +58,11 @@ pub fn run(args: ExportOrderArgs) -> Result<(), Box<dyn std::error::Error>> {
         Box::new(std::io::stdout())
     };
 
+    if args.format == OrderExportFormat::Json {
+        biofabric_core::io::json::write_layout(&layout, &mut writer)?;
+        return Ok(());
+    }
+
     match args.what {
         OrderExportType::Nodes => {
             biofabric_core::io::order::write_node_order(&mut writer, &layout)?;
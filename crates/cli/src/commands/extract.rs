@@ -27,6 +27,7 @@ pub fn run(args: ExtractArgs, quiet: bool) -> Result<(), Box<dyn std::error::Err
         ConvertFormat::Gw => OutputFormat::Gw,
         ConvertFormat::Json => OutputFormat::Json,
         ConvertFormat::Xml => OutputFormat::Xml,
+        ConvertFormat::Dot => OutputFormat::Dot,
     };
 
     if let Some(path) = &args.output {
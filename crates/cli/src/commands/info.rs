@@ -2,12 +2,18 @@
 
 use crate::args::{InfoArgs, InfoFormat};
 use biofabric_core::io::factory::FabricFactory;
+use biofabric_core::layout::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+use biofabric_core::layout::{DefaultEdgeLayout, DefaultNodeLayout};
+use biofabric_core::worker::NoopMonitor;
 use std::collections::HashMap;
 
 pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
     let network = FabricFactory::load_network(&args.input)?;
 
+    let format = if args.json { InfoFormat::Json } else { args.format };
     let show_all = args.all;
+    let show_degree_distribution = args.degree_distribution || args.json;
+    let show_components = args.components || args.json;
 
     // Basic stats
     let node_count = network.node_count();
@@ -22,20 +28,54 @@ pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
     };
 
     // Degree distribution (compute if requested)
-    let degrees: Vec<usize> = if args.degree_distribution || show_all {
+    let degrees: Vec<usize> = if show_degree_distribution || show_all {
         network.node_ids().map(|id| network.degree(id)).collect()
     } else {
         Vec::new()
     };
 
     // Connected components (compute if requested)
-    let components: Vec<Vec<biofabric_core::NodeId>> = if args.components || show_all {
+    let components: Vec<Vec<biofabric_core::NodeId>> = if show_components || show_all {
         biofabric_core::analysis::connected_components(&network)
     } else {
         Vec::new()
     };
 
-    match args.format {
+    // Average clustering coefficient (compute if requested)
+    let clustering: Option<f64> = if args.clustering || show_all {
+        let (_, average) = biofabric_core::analysis::clustering_coefficient(&network);
+        Some(average)
+    } else {
+        None
+    };
+
+    // Diameter and eccentricity (compute if requested)
+    let diameter: Option<(usize, usize, usize, f64)> = if args.diameter || show_all {
+        biofabric_core::analysis::diameter(&network).map(|d| {
+            let ecc = biofabric_core::analysis::eccentricity(&network);
+            let min = ecc.values().copied().min().unwrap_or(0);
+            let sum: usize = ecc.values().sum();
+            let mean = sum as f64 / ecc.len().max(1) as f64;
+            (d, min, ecc.values().copied().max().unwrap_or(0), mean)
+        })
+    } else {
+        None
+    };
+
+    // Layout validation and crossing count (compute the layout once if
+    // either is requested, since both need the default layout).
+    let layout = if args.validate || args.crossings {
+        let two_phase = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let params = LayoutParams::default();
+        Some(two_phase.layout(&network, &params, &NoopMonitor)?)
+    } else {
+        None
+    };
+    let validation: Option<Result<(), String>> =
+        if args.validate { layout.as_ref().map(|l| l.validate().map_err(|e| e.to_string())) } else { None };
+    let crossings: Option<usize> = if args.crossings { layout.as_ref().map(|l| l.crossing_count()) } else { None };
+
+    match format {
         InfoFormat::Text => {
             println!("Network: {}", args.input.display());
             if let Some(name) = &network.metadata.name {
@@ -61,7 +101,7 @@ pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
 
-            if (args.degree_distribution || show_all) && !degrees.is_empty() {
+            if (show_degree_distribution || show_all) && !degrees.is_empty() {
                 println!();
                 let min = degrees.iter().copied().min().unwrap_or(0);
                 let max = degrees.iter().copied().max().unwrap_or(0);
@@ -77,7 +117,7 @@ pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
                 println!("  Median: {:.0}", median);
             }
 
-            if (args.components || show_all) && !components.is_empty() {
+            if (show_components || show_all) && !components.is_empty() {
                 println!();
                 println!("Connected components: {}", components.len());
                 for (i, comp) in components.iter().enumerate() {
@@ -89,6 +129,30 @@ pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
                     }
                 }
             }
+
+            if let Some(average) = clustering {
+                println!();
+                println!("Clustering coefficient: {:.4}", average);
+            }
+
+            if let Some((d, min, max, mean)) = diameter {
+                println!();
+                println!("Diameter: {}", d);
+                println!("Eccentricity: min {}, max {}, mean {:.2}", min, max, mean);
+            }
+
+            if let Some(result) = &validation {
+                println!();
+                match result {
+                    Ok(()) => println!("Layout validation: OK"),
+                    Err(message) => println!("Layout validation: FAILED ({})", message),
+                }
+            }
+
+            if let Some(count) = crossings {
+                println!();
+                println!("Crossings: {}", count);
+            }
         }
         InfoFormat::Json => {
             let mut info = serde_json::json!({
@@ -102,7 +166,7 @@ pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
                 "relation_types": relation_types,
             });
 
-            if (args.degree_distribution || show_all) && !degrees.is_empty() {
+            if (show_degree_distribution || show_all) && !degrees.is_empty() {
                 let min = degrees.iter().copied().min().unwrap_or(0);
                 let max = degrees.iter().copied().max().unwrap_or(0);
                 let sum: usize = degrees.iter().sum();
@@ -114,7 +178,7 @@ pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
                 });
             }
 
-            if args.components || show_all {
+            if show_components || show_all {
                 let comp_sizes: Vec<usize> = components.iter().map(|c| c.len()).collect();
                 info["components"] = serde_json::json!({
                     "count": components.len(),
@@ -122,6 +186,30 @@ pub fn run(args: InfoArgs) -> Result<(), Box<dyn std::error::Error>> {
                 });
             }
 
+            if let Some(average) = clustering {
+                info["clustering_coefficient"] = serde_json::json!(average);
+            }
+
+            if let Some((d, min, max, mean)) = diameter {
+                info["diameter"] = serde_json::json!(d);
+                info["eccentricity"] = serde_json::json!({
+                    "min": min,
+                    "max": max,
+                    "mean": mean,
+                });
+            }
+
+            if let Some(result) = &validation {
+                info["layout_validation"] = match result {
+                    Ok(()) => serde_json::json!({ "valid": true }),
+                    Err(message) => serde_json::json!({ "valid": false, "message": message }),
+                };
+            }
+
+            if let Some(count) = crossings {
+                info["crossings"] = serde_json::json!(count);
+            }
+
             println!("{}", serde_json::to_string_pretty(&info)?);
         }
     }
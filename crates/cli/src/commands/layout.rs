@@ -2,13 +2,79 @@
 
 use crate::args::{LayoutAlgorithm, LayoutArgs, LinkGroupMode};
 use biofabric_core::io::factory::FabricFactory;
+use biofabric_core::layout::result::NetworkLayout;
 use biofabric_core::layout::traits::{LayoutMode, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
-use biofabric_core::layout::{DefaultEdgeLayout, DefaultNodeLayout};
-use biofabric_core::model::NodeId;
+use biofabric_core::layout::{BarycenterLayout, DefaultEdgeLayout, DefaultNodeLayout, DegreeSortLayout, KCoreLayout, PageRankLayout, RcmLayout, SpectralLayout};
+use biofabric_core::model::{Network, NodeId};
 use biofabric_core::worker::NoopMonitor;
+use rayon::prelude::*;
+use std::path::PathBuf;
 
 pub fn run(args: LayoutArgs, quiet: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if args.batch {
+        return run_batch(&args, quiet);
+    }
+
     let mut network = FabricFactory::load_network(&args.input)?;
+    let layout_result = compute_layout(&mut network, &args, quiet)?;
+
+    if let Some(cache_path) = &args.cache {
+        layout_result.save_cache(cache_path)?;
+        if !quiet {
+            eprintln!("Layout cache written to {}", cache_path.display());
+        }
+    }
+
+    // Write output
+    if let Some(output) = &args.output {
+        let ext = output.extension().and_then(|e| e.to_str()).unwrap_or("");
+        match ext {
+            "json" => {
+                let json = serde_json::to_string_pretty(&layout_result)?;
+                std::fs::write(output, json)?;
+            }
+            "bif" | "xml" => {
+                let session =
+                    biofabric_core::io::session::Session::with_layout(network, layout_result);
+                FabricFactory::save_session(&session, output)?;
+            }
+            _ => {
+                return Err(format!(
+                    "Unsupported output format '{}'. Use .json, .bif, or .xml",
+                    ext
+                )
+                .into());
+            }
+        }
+        if !quiet {
+            eprintln!("Layout written to {}", output.display());
+        }
+    } else {
+        // Print layout JSON to stdout
+        let json = serde_json::to_string_pretty(&layout_result)?;
+        println!("{}", json);
+    }
+
+    Ok(())
+}
+
+/// Apply degree capping and shadow generation to `network`, then run the
+/// layout algorithm selected by `args` against it.
+///
+/// Shared by the single-file path in [`run`] and the per-file work in
+/// [`run_batch`], so a batch run lays out each network exactly the way a
+/// standalone `biofabric layout` invocation would.
+fn compute_layout(network: &mut Network, args: &LayoutArgs, quiet: bool) -> Result<NetworkLayout, Box<dyn std::error::Error>> {
+    // Cap hub degree before anything else, so shadow generation and layout
+    // both see the reduced edge set.
+    if let Some(max_degree) = args.cap_degree {
+        let before = network.link_count();
+        *network = network.cap_degree(max_degree);
+        let dropped = before - network.link_count();
+        if !quiet && dropped > 0 {
+            eprintln!("Dropped {dropped} edge(s) capping degree at {max_degree}");
+        }
+    }
 
     // Generate shadows if requested
     let show_shadows = args.shadows && !args.no_shadows;
@@ -23,7 +89,7 @@ pub fn run(args: LayoutArgs, quiet: bool) -> Result<(), Box<dyn std::error::Erro
     };
 
     let params = LayoutParams {
-        start_node: args.start_node.map(|s| NodeId::new(&s)),
+        start_node: args.start_node.clone().map(|s| NodeId::new(&s)),
         include_shadows: show_shadows,
         layout_mode,
         link_groups: args.link_group_order.clone(),
@@ -53,39 +119,97 @@ pub fn run(args: LayoutArgs, quiet: bool) -> Result<(), Box<dyn std::error::Erro
                 );
             }
             let two_phase = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
-            two_phase.layout(&network, &params, &NoopMonitor)?
+            two_phase.layout(network, &params, &NoopMonitor)?
+        }
+        LayoutAlgorithm::Spectral => {
+            let two_phase = TwoPhaseLayout::new(SpectralLayout::new(), DefaultEdgeLayout::new());
+            two_phase.layout(network, &params, &NoopMonitor)?
+        }
+        LayoutAlgorithm::Rcm => {
+            let two_phase = TwoPhaseLayout::new(RcmLayout::new(), DefaultEdgeLayout::new());
+            two_phase.layout(network, &params, &NoopMonitor)?
+        }
+        LayoutAlgorithm::Degree => {
+            let two_phase = TwoPhaseLayout::new(DegreeSortLayout::new(), DefaultEdgeLayout::new());
+            two_phase.layout(network, &params, &NoopMonitor)?
+        }
+        LayoutAlgorithm::Kcore => {
+            let two_phase = TwoPhaseLayout::new(KCoreLayout::new(), DefaultEdgeLayout::new());
+            two_phase.layout(network, &params, &NoopMonitor)?
+        }
+        LayoutAlgorithm::Barycenter => {
+            let two_phase = TwoPhaseLayout::new(BarycenterLayout::new(), DefaultEdgeLayout::new());
+            two_phase.layout(network, &params, &NoopMonitor)?
+        }
+        LayoutAlgorithm::Pagerank => {
+            let two_phase = TwoPhaseLayout::new(PageRankLayout::new(), DefaultEdgeLayout::new());
+            two_phase.layout(network, &params, &NoopMonitor)?
         }
     };
 
-    // Write output
-    if let Some(output) = &args.output {
-        let ext = output.extension().and_then(|e| e.to_str()).unwrap_or("");
-        match ext {
-            "json" => {
-                let json = serde_json::to_string_pretty(&layout_result)?;
-                std::fs::write(output, json)?;
-            }
-            "bif" | "xml" => {
-                let session =
-                    biofabric_core::io::session::Session::with_layout(network, layout_result);
-                FabricFactory::save_session(&session, output)?;
-            }
-            _ => {
-                return Err(format!(
-                    "Unsupported output format '{}'. Use .json, .bif, or .xml",
-                    ext
-                )
-                .into());
+    Ok(layout_result)
+}
+
+/// Lay out every `.sif`/`.gw` file directly inside `args.input`, writing a
+/// `.bif` session per file into the directory named by `args.output`.
+///
+/// Files are processed concurrently with `rayon`, but each file's output is
+/// computed independently of the others, so the result for any one file is
+/// the same regardless of how many run alongside it.
+fn run_batch(args: &LayoutArgs, quiet: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let out_dir = args
+        .output
+        .clone()
+        .ok_or("--batch requires -o/--output <outdir>")?;
+    std::fs::create_dir_all(&out_dir)?;
+
+    let mut inputs: Vec<PathBuf> = std::fs::read_dir(&args.input)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| matches!(path.extension().and_then(|e| e.to_str()), Some("sif") | Some("gw")))
+        .collect();
+    inputs.sort();
+
+    let results: Vec<(PathBuf, Result<(), String>)> = inputs
+        .par_iter()
+        .map(|path| {
+            let outcome = lay_out_one(path, &out_dir, args);
+            (path.clone(), outcome.map_err(|e| e.to_string()))
+        })
+        .collect();
+
+    let failed: Vec<&(PathBuf, Result<(), String>)> = results.iter().filter(|(_, r)| r.is_err()).collect();
+
+    if !quiet {
+        eprintln!(
+            "Batch layout: {} succeeded, {} failed (of {})",
+            results.len() - failed.len(),
+            failed.len(),
+            results.len()
+        );
+        for (path, outcome) in &failed {
+            if let Err(message) = outcome {
+                eprintln!("  {}: {}", path.display(), message);
             }
         }
-        if !quiet {
-            eprintln!("Layout written to {}", output.display());
-        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
     } else {
-        // Print layout JSON to stdout
-        let json = serde_json::to_string_pretty(&layout_result)?;
-        println!("{}", json);
+        Err(format!("{} of {} file(s) failed to lay out", failed.len(), results.len()).into())
     }
+}
+
+/// Lay out a single file for [`run_batch`] and write its `.bif` session.
+fn lay_out_one(input: &std::path::Path, out_dir: &std::path::Path, args: &LayoutArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut network = FabricFactory::load_network(input)?;
+    let layout_result = compute_layout(&mut network, args, true)?;
+
+    let stem = input.file_stem().and_then(|s| s.to_str()).unwrap_or("network");
+    let out_path = out_dir.join(format!("{stem}.bif"));
+    let session = biofabric_core::io::session::Session::with_layout(network, layout_result);
+    FabricFactory::save_session(&session, &out_path)?;
 
     Ok(())
 }
@@ -0,0 +1,54 @@
+//! `biofabric --list-commands` — machine-readable subcommand listing.
+//!
+//! This is a hidden flag, distinct from clap's built-in `--help`: it's meant
+//! for shell completion scripts and other external tooling that wants to
+//! introspect the CLI's subcommands and options programmatically, rather
+//! than parse human-oriented help text. It walks the same clap metadata
+//! `--help` is generated from, so it can never drift from the real
+//! subcommand surface.
+
+use clap::CommandFactory;
+
+/// List every subcommand and its arguments in `format` (`"json"` or
+/// `"text"`).
+pub fn run(format: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let command = crate::Cli::command();
+    let commands: Vec<serde_json::Value> = command.get_subcommands().map(describe_command).collect();
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&commands)?);
+    } else {
+        for cmd in &commands {
+            println!("{}", cmd["name"].as_str().unwrap_or(""));
+            for arg in cmd["args"].as_array().into_iter().flatten() {
+                let flag = arg["long"]
+                    .as_str()
+                    .map(|l| format!("--{l}"))
+                    .unwrap_or_else(|| arg["name"].as_str().unwrap_or("").to_string());
+                println!("  {flag}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn describe_command(cmd: &clap::Command) -> serde_json::Value {
+    serde_json::json!({
+        "name": cmd.get_name(),
+        "about": cmd.get_about().map(|s| s.to_string()),
+        "args": cmd.get_arguments().map(describe_arg).collect::<Vec<_>>(),
+    })
+}
+
+fn describe_arg(arg: &clap::Arg) -> serde_json::Value {
+    serde_json::json!({
+        "name": arg.get_id().as_str(),
+        "long": arg.get_long(),
+        "short": arg.get_short().map(|c| c.to_string()),
+        "positional": arg.is_positional(),
+        "required": arg.is_required_set(),
+        "takes_value": arg.get_action().takes_values(),
+        "help": arg.get_help().map(|s| s.to_string()),
+    })
+}
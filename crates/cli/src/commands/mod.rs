@@ -4,10 +4,17 @@
 //! `Result<(), Box<dyn std::error::Error>>`.
 
 pub mod align;
+pub mod align_sweep;
+pub mod analyze;
+pub mod centrality;
 pub mod compare;
 pub mod convert;
+pub mod diff;
+pub mod distance;
 pub mod export_order;
 pub mod extract;
 pub mod info;
 pub mod layout;
+pub mod list_commands;
+pub mod render;
 pub mod search;
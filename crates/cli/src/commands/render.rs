@@ -0,0 +1,117 @@
+//! `biofabric render` — lay out a network and write it straight to an
+//! image or vector file.
+
+use crate::args::RenderArgs;
+use crate::image_io;
+use biofabric_core::io::display_options::{CropRegion, DisplayOptions};
+use biofabric_core::io::factory::FabricFactory;
+use biofabric_core::layout::result::NetworkLayout;
+use biofabric_core::layout::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+use biofabric_core::layout::{DefaultEdgeLayout, DefaultNodeLayout};
+use biofabric_core::worker::NoopMonitor;
+use std::path::Path;
+
+pub fn run(args: RenderArgs, quiet: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if args.tile_size == Some(0) {
+        return Err("--tile-size must be at least 1".into());
+    }
+
+    let mut network = FabricFactory::load_network(&args.input)?;
+
+    let show_shadows = args.shadows && !args.no_shadows;
+    if show_shadows {
+        network.generate_shadows();
+    }
+
+    let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+    let layout = layout_algo.layout(&network, &LayoutParams::default(), &NoopMonitor)?;
+
+    let mut display = DisplayOptions::default();
+    if args.rows.is_some() || args.cols.is_some() {
+        display.crop = Some(parse_crop(&args, &layout)?);
+    }
+
+    let overview_max_dim = args.overview.then_some(args.overview_max_dim);
+    render_layout_to_path(&layout, &display, &args.output, args.indexed, overview_max_dim, args.tile_size)?;
+
+    if !quiet {
+        eprintln!("Rendered to {}", args.output.display());
+    }
+
+    Ok(())
+}
+
+/// Build the [`CropRegion`] requested by `--rows`/`--cols`, defaulting
+/// either side to the layout's full extent when only one flag is given.
+fn parse_crop(args: &RenderArgs, layout: &NetworkLayout) -> Result<CropRegion, Box<dyn std::error::Error>> {
+    let (min_row, max_row) = match &args.rows {
+        Some(spec) => parse_range(spec, "rows")?,
+        None => (0, layout.row_count.saturating_sub(1)),
+    };
+    let (min_col, max_col) = match &args.cols {
+        Some(spec) => parse_range(spec, "cols")?,
+        None => (0, layout.column_count.saturating_sub(1)),
+    };
+    Ok(CropRegion { min_row, max_row, min_col, max_col })
+}
+
+/// Parse a `MIN:MAX` range like `0:100`, used by `--rows`/`--cols`.
+///
+/// # Errors
+///
+/// Returns an error if the syntax is malformed, either bound doesn't parse
+/// as a `usize`, or `min > max` — an inverted range would otherwise reach
+/// `CropRegion` and underflow when the renderer computes its width/height.
+fn parse_range(spec: &str, flag: &str) -> Result<(usize, usize), Box<dyn std::error::Error>> {
+    let (min, max) = spec
+        .split_once(':')
+        .ok_or_else(|| format!("--{flag} expects MIN:MAX (e.g. 0:100), got '{spec}'"))?;
+    let min: usize = min.parse().map_err(|_| format!("invalid --{flag} start '{min}'"))?;
+    let max: usize = max.parse().map_err(|_| format!("invalid --{flag} end '{max}'"))?;
+    if min > max {
+        return Err(format!("--{flag} start must be <= end, got '{spec}'").into());
+    }
+    Ok((min, max))
+}
+
+/// Write `layout` to `output`, picking vector or raster encoding from its
+/// extension: `.svg` renders to SVG text, everything else builds a
+/// [`Canvas`](biofabric_render::Canvas) — a density thumbnail when
+/// `overview_max_dim` is set, stitched-together tiles when `tile_size` is
+/// set, or a single [`biofabric_render::render_layout_to_image`] buffer
+/// otherwise — and writes it via [`image_io::save_canvas`] or, when
+/// `indexed` is set and the output is `.png`, the smaller
+/// [`image_io::save_canvas_indexed`].
+fn render_layout_to_path(
+    layout: &NetworkLayout,
+    display: &DisplayOptions,
+    output: &Path,
+    indexed: bool,
+    overview_max_dim: Option<usize>,
+    tile_size: Option<usize>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let ext = output.extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase();
+
+    if ext == "svg" {
+        if overview_max_dim.is_some() || tile_size.is_some() {
+            return Err("--overview and --tile-size only apply to raster output, not .svg".into());
+        }
+        let svg = biofabric_render::render_layout_to_svg(layout, display);
+        std::fs::write(output, svg)?;
+        return Ok(());
+    }
+
+    let canvas = match (overview_max_dim, tile_size) {
+        (Some(max_dim), _) => biofabric_render::render_density_overview(layout, max_dim),
+        (None, Some(tile_size)) => {
+            let tiles = biofabric_render::render_layout_to_tiles(layout, display, tile_size);
+            biofabric_render::stitch_tiles(tiles, tile_size)
+        }
+        (None, None) => biofabric_render::render_layout_to_image(layout, display),
+    };
+    if indexed && ext == "png" {
+        image_io::save_canvas_indexed(&canvas, output)
+    } else {
+        image_io::save_canvas(&canvas, output, &image_io::RenderImageOptions::default())
+    }
+}
@@ -0,0 +1,228 @@
+//! Writing a [`biofabric_render::Canvas`] to an image file on disk.
+//!
+//! `biofabric-render` deliberately stays encoder-agnostic (see that crate's
+//! top-level docs), so the `image` crate dependency and the file-extension
+//! convention for picking a format live here instead.
+
+use biofabric_render::Canvas;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// Image formats BioFabric can write. Keep this list, [`image_format_from_path`],
+/// and the extensions listed in its error message in sync.
+const SUPPORTED_EXTENSIONS: &[&str] = &["png", "webp", "bmp", "tif", "tiff"];
+
+/// Infer the [`image::ImageFormat`] to encode with from `path`'s extension.
+///
+/// # Errors
+///
+/// Returns an error naming `path` and listing the supported extensions if
+/// the extension is missing or unrecognized.
+pub fn image_format_from_path(path: &Path) -> Result<image::ImageFormat, String> {
+    match path.extension().and_then(|e| e.to_str()).map(|e| e.to_ascii_lowercase()).as_deref() {
+        Some("png") => Ok(image::ImageFormat::Png),
+        Some("webp") => Ok(image::ImageFormat::WebP),
+        Some("bmp") => Ok(image::ImageFormat::Bmp),
+        Some("tif") | Some("tiff") => Ok(image::ImageFormat::Tiff),
+        _ => Err(format!(
+            "Unsupported image extension in '{}'. Supported: {}",
+            path.display(),
+            SUPPORTED_EXTENSIONS.join(", ")
+        )),
+    }
+}
+
+/// Per-save knobs for [`save_canvas`] beyond what the file extension alone
+/// determines.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RenderImageOptions {
+    /// Physical resolution to embed as the PNG `pHYs` chunk or TIFF
+    /// `XResolution`/`YResolution`/`ResolutionUnit` tags, in pixels per
+    /// inch — e.g. `300` for a journal figure submission. `None` leaves
+    /// the format's default (no physical size asserted). Has no effect on
+    /// WebP or BMP, which have no equivalent metadata field.
+    pub dpi: Option<u32>,
+}
+
+/// Write `canvas` to `path`, picking the encoding from its extension (see
+/// [`image_format_from_path`]) and applying `options`.
+pub fn save_canvas(canvas: &Canvas, path: &Path, options: &RenderImageOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let format = image_format_from_path(path)?;
+    let (width, height) = (canvas.width() as u32, canvas.height() as u32);
+
+    let mut rgba = Vec::with_capacity(canvas.width() * canvas.height() * 4);
+    for y in 0..canvas.height() {
+        for x in 0..canvas.width() {
+            let color = canvas.get_pixel(x, y).unwrap();
+            rgba.extend_from_slice(&[color.r, color.g, color.b, color.a]);
+        }
+    }
+
+    match (format, options.dpi) {
+        (image::ImageFormat::Png, Some(dpi)) => save_png_with_dpi(&rgba, width, height, path, dpi),
+        (image::ImageFormat::Tiff, Some(dpi)) => save_tiff_with_dpi(&rgba, width, height, path, dpi),
+        _ => {
+            let buf = image::RgbaImage::from_raw(width, height, rgba).expect("buffer sized to width * height * 4");
+            buf.save_with_format(path, format)?;
+            Ok(())
+        }
+    }
+}
+
+/// Write `canvas` to `path` as an indexed PNG, quantizing it to the palette
+/// of colors it actually uses (see [`biofabric_render::quantize`]) instead
+/// of encoding every pixel as 4 bytes of truecolor RGBA. Substantially
+/// shrinks file size for figures, which are mostly flat fill colors plus a
+/// handful of annotation tints.
+///
+/// # Errors
+///
+/// Returns [`biofabric_render::QuantizeError`] if `canvas` uses more than
+/// 256 distinct colors.
+pub fn save_canvas_indexed(canvas: &Canvas, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let indexed = biofabric_render::quantize(canvas)?;
+
+    let mut palette = Vec::with_capacity(indexed.palette.len() * 3);
+    let mut trns = Vec::with_capacity(indexed.palette.len());
+    for color in &indexed.palette {
+        palette.extend_from_slice(&[color.r, color.g, color.b]);
+        trns.push(color.a);
+    }
+
+    let writer = BufWriter::new(std::fs::File::create(path)?);
+    let mut encoder = png::Encoder::new(writer, indexed.width as u32, indexed.height as u32);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_palette(palette);
+    encoder.set_trns(trns);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&indexed.indices)?;
+    Ok(())
+}
+
+/// Pixels-per-inch to pixels-per-meter, the unit PNG's `pHYs` chunk uses.
+fn dpi_to_pixels_per_meter(dpi: u32) -> u32 {
+    (dpi as f64 / 0.0254).round() as u32
+}
+
+/// Encode `rgba` as a PNG at `path` via the lower-level `png` encoder,
+/// since `image`'s high-level `save_with_format` has no way to set the
+/// `pHYs` chunk.
+fn save_png_with_dpi(rgba: &[u8], width: u32, height: u32, path: &Path, dpi: u32) -> Result<(), Box<dyn std::error::Error>> {
+    let writer = BufWriter::new(std::fs::File::create(path)?);
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let pixels_per_meter = dpi_to_pixels_per_meter(dpi);
+    encoder.set_pixel_dims(Some(png::PixelDimensions {
+        xppu: pixels_per_meter,
+        yppu: pixels_per_meter,
+        unit: png::Unit::Meter,
+    }));
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(rgba)?;
+    Ok(())
+}
+
+/// Encode `rgba` as a TIFF at `path` via the lower-level `tiff` encoder,
+/// since `image`'s high-level `save_with_format` has no way to set the
+/// resolution tags.
+fn save_tiff_with_dpi(rgba: &[u8], width: u32, height: u32, path: &Path, dpi: u32) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tiff_encoder = tiff::encoder::TiffEncoder::new(std::fs::File::create(path)?)?;
+    let mut image = tiff_encoder.new_image::<tiff::encoder::colortype::RGBA8>(width, height)?;
+    image.resolution(tiff::tags::ResolutionUnit::Inch, tiff::encoder::Rational { n: dpi, d: 1 });
+    image.write_data(rgba)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biofabric_core::io::display_options::DisplayOptions;
+    use biofabric_core::layout::{DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use biofabric_core::model::{Link, Network};
+    use biofabric_core::worker::NoopMonitor;
+
+    fn tiny_layout() -> biofabric_core::layout::result::NetworkLayout {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        layout_algo.layout(&network, &LayoutParams::default(), &NoopMonitor).unwrap()
+    }
+
+    #[test]
+    fn test_image_format_from_path_rejects_unknown_extensions() {
+        assert!(image_format_from_path(Path::new("out.json")).is_err());
+        assert!(image_format_from_path(Path::new("out")).is_err());
+    }
+
+    #[test]
+    fn test_save_canvas_as_webp_decodes_back_to_the_requested_dimensions() {
+        let layout = tiny_layout();
+        let canvas = biofabric_render::render_layout_to_image(&layout, &DisplayOptions::default());
+        let (width, height) = (canvas.width(), canvas.height());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.webp");
+        save_canvas(&canvas, &path, &RenderImageOptions::default()).unwrap();
+
+        let metadata = std::fs::metadata(&path).unwrap();
+        assert!(metadata.len() > 0, "expected a non-empty .webp file");
+
+        let decoded = image::open(&path).unwrap();
+        assert_eq!(decoded.width() as usize, width);
+        assert_eq!(decoded.height() as usize, height);
+    }
+
+    #[test]
+    fn test_save_canvas_indexed_decodes_close_to_the_truecolor_render() {
+        let layout = tiny_layout();
+        let canvas = biofabric_render::render_layout_to_image(&layout, &DisplayOptions::default());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.png");
+        save_canvas_indexed(&canvas, &path).unwrap();
+
+        let decoded = image::open(&path).unwrap().to_rgba8();
+        assert_eq!(decoded.width() as usize, canvas.width());
+        assert_eq!(decoded.height() as usize, canvas.height());
+
+        const TOLERANCE: i32 = 2;
+        for y in 0..canvas.height() {
+            for x in 0..canvas.width() {
+                let expected = canvas.get_pixel(x, y).unwrap();
+                let actual = decoded.get_pixel(x as u32, y as u32);
+                for (e, a) in [expected.r, expected.g, expected.b, expected.a].iter().zip(actual.0.iter()) {
+                    assert!((*e as i32 - *a as i32).abs() <= TOLERANCE, "pixel ({x}, {y}) differs beyond tolerance");
+                }
+            }
+        }
+    }
+
+    /// Pull the `pHYs` chunk's pixels-per-meter value straight out of the
+    /// PNG byte stream — `image`'s decoder doesn't surface it, since it has
+    /// no high-level concept of physical resolution.
+    fn read_png_phys_ppu(bytes: &[u8]) -> Option<u32> {
+        let marker = bytes.windows(4).position(|w| w == b"pHYs")?;
+        let xppu = &bytes[marker + 4..marker + 8];
+        Some(u32::from_be_bytes(xppu.try_into().unwrap()))
+    }
+
+    #[test]
+    fn test_save_canvas_as_png_with_dpi_writes_the_phys_chunk() {
+        let layout = tiny_layout();
+        let canvas = biofabric_render::render_layout_to_image(&layout, &DisplayOptions::default());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.png");
+        save_canvas(&canvas, &path, &RenderImageOptions { dpi: Some(300) }).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        let ppu = read_png_phys_ppu(&bytes).expect("expected a pHYs chunk");
+        assert_eq!(ppu, dpi_to_pixels_per_meter(300));
+
+        let decoded = image::open(&path).unwrap();
+        assert_eq!(decoded.width() as usize, canvas.width());
+        assert_eq!(decoded.height() as usize, canvas.height());
+    }
+}
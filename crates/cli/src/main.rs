@@ -11,14 +11,18 @@
 //! biofabric info          <input>                    Print network info
 //! biofabric convert       <input>  -f <format>       Convert formats
 //! biofabric align         <g1> <g2> <align>          Alignment analysis
+//! biofabric align-sweep   <g1> <g2> --aligns <dir>   Score a batch of alignments
 //! biofabric compare       <input>  <nodeA> <nodeB>   Compare node neighborhoods
 //! biofabric extract       <input>  --node <start>    Extract subnetwork
 //! biofabric export-order  <input>  -o <file>         Export node/link order
 //! biofabric search        <input>  <pattern>         Search nodes/relations
+//! biofabric analyze       <input>  --metric <m>      Compute structural metrics
 //! ```
 
 mod args;
 mod commands;
+mod image_io;
+mod output_template;
 
 use args::Commands;
 use clap::Parser;
@@ -35,7 +39,7 @@ use clap::Parser;
 #[derive(Parser, Debug)]
 #[command(name = "biofabric", version, about, long_about = None)]
 #[command(propagate_version = true)]
-struct Cli {
+pub struct Cli {
     #[command(subcommand)]
     command: Commands,
 
@@ -49,6 +53,25 @@ struct Cli {
 // ==========================================================================
 
 fn main() {
+    // `--list-commands` is a hidden, tooling-facing flag handled outside
+    // clap's normal subcommand dispatch (the `Commands` subcommand is
+    // otherwise required, so a bare `biofabric --list-commands` would
+    // never reach clap's own argument matching).
+    let raw_args: Vec<String> = std::env::args().skip(1).collect();
+    if raw_args.iter().any(|a| a == "--list-commands") {
+        let format = raw_args
+            .iter()
+            .position(|a| a == "--format")
+            .and_then(|i| raw_args.get(i + 1))
+            .map(String::as_str)
+            .unwrap_or("text");
+        if let Err(e) = commands::list_commands::run(format) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let cli = Cli::parse();
 
     let result = match cli.command {
@@ -56,10 +79,16 @@ fn main() {
         Commands::Info(args) => commands::info::run(args),
         Commands::Convert(args) => commands::convert::run(args, cli.quiet),
         Commands::Align(args) => commands::align::run(args, cli.quiet),
+        Commands::AlignSweep(args) => commands::align_sweep::run(args, cli.quiet),
         Commands::Compare(args) => commands::compare::run(args),
         Commands::Extract(args) => commands::extract::run(args, cli.quiet),
         Commands::ExportOrder(args) => commands::export_order::run(args),
         Commands::Search(args) => commands::search::run(args),
+        Commands::Analyze(args) => commands::analyze::run(args),
+        Commands::Distance(args) => commands::distance::run(args),
+        Commands::Diff(args) => commands::diff::run(args),
+        Commands::Centrality(args) => commands::centrality::run(args),
+        Commands::Render(args) => commands::render::run(args, cli.quiet),
     };
 
     if let Err(e) = result {
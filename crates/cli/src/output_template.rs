@@ -0,0 +1,81 @@
+//! Output filename templating, shared by any command that writes more than
+//! one output file (e.g. a batch of per-viewport or per-tile renders).
+//!
+//! No subcommand currently writes multiple outputs from a single input —
+//! `align-sweep` writes one summary file, and there is no tiled/contact-sheet
+//! render command yet. This expander is reserved for wiring into such a
+//! command once it exists; until then it is a standalone, tested primitive.
+
+/// Expand `{name}` and `{index}` placeholders in an output filename
+/// template.
+///
+/// `name` is typically the input file's stem and `index` a 0-based
+/// viewport/tile index, e.g. `expand_output_template("{name}_{index}.png",
+/// "network", 3)` yields `"network_3.png"`.
+///
+/// Returns an error naming the offending placeholder if the template
+/// contains a `{...}` segment other than `{name}` or `{index}`.
+// Not yet called from any command — reserved until a batch/tiled render
+// command exists to wire it into.
+#[allow(dead_code)]
+pub fn expand_output_template(template: &str, name: &str, index: usize) -> Result<String, String> {
+    let mut result = String::with_capacity(template.len());
+    let mut chars = template.char_indices().peekable();
+
+    while let Some((start, ch)) = chars.next() {
+        if ch != '{' {
+            result.push(ch);
+            continue;
+        }
+
+        let end = loop {
+            match chars.next() {
+                Some((i, '}')) => break i,
+                Some(_) => continue,
+                None => return Err(format!("Unterminated placeholder in template: '{}'", &template[start..])),
+            }
+        };
+
+        let placeholder = &template[start + 1..end];
+        match placeholder {
+            "name" => result.push_str(name),
+            "index" => result.push_str(&index.to_string()),
+            other => {
+                return Err(format!(
+                    "Unknown placeholder '{{{other}}}' in output template '{template}'. \
+                     Supported placeholders: {{name}}, {{index}}"
+                ))
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_output_template_for_two_inputs() {
+        assert_eq!(
+            expand_output_template("{name}_{index}.png", "alpha", 0).unwrap(),
+            "alpha_0.png"
+        );
+        assert_eq!(
+            expand_output_template("{name}_{index}.png", "beta", 1).unwrap(),
+            "beta_1.png"
+        );
+    }
+
+    #[test]
+    fn test_expand_output_template_with_no_placeholders() {
+        assert_eq!(expand_output_template("output.png", "alpha", 0).unwrap(), "output.png");
+    }
+
+    #[test]
+    fn test_expand_output_template_rejects_unknown_placeholder() {
+        let err = expand_output_template("{name}_{page}.png", "alpha", 0).unwrap_err();
+        assert!(err.contains("{page}"), "error should name the offending placeholder: {err}");
+    }
+}
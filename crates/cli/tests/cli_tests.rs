@@ -187,6 +187,25 @@ fn info_json_output() {
         .stdout(predicate::str::contains("\"link_count\""));
 }
 
+#[test]
+fn info_json_flag_is_a_format_json_shorthand() {
+    let output = biofabric()
+        .args(["info", &test_sif("triangle.sif"), "--json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let info: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert!(info["node_count"].is_number());
+    assert!(info["link_count"].is_number());
+    assert!(info["shadow_link_count"].is_number());
+    assert!(info["lone_node_count"].is_number());
+    assert!(info["components"]["count"].is_number());
+    assert!(info["degree_distribution"]["mean"].is_number());
+}
+
 #[test]
 fn info_missing_file() {
     biofabric()
@@ -239,6 +258,17 @@ fn convert_sif_to_json_file() {
     assert!(content.contains("nodes"));
 }
 
+#[test]
+fn convert_reads_sif_from_stdin_and_writes_json_to_stdout() {
+    biofabric()
+        .args(["convert", "-", "--format", "json"])
+        .write_stdin("Alpha\tpp\tBeta\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Alpha"))
+        .stdout(predicate::str::contains("Beta"));
+}
+
 #[test]
 fn convert_missing_format() {
     biofabric()
@@ -298,6 +328,16 @@ fn compare_json_output() {
         .stdout(predicate::str::contains("\"jaccard_similarity\""));
 }
 
+#[test]
+fn compare_matrix() {
+    biofabric()
+        .args(["compare", &test_sif("triangle.sif"), "--matrix", "A,B,C"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("A"))
+        .stdout(predicate::str::contains("1.0000"));
+}
+
 // =========================================================================
 // Search command
 // =========================================================================
@@ -484,6 +524,88 @@ fn layout_to_bif_file() {
     assert!(content.contains("BioTapestry") || content.contains("xml") || content.contains("node"));
 }
 
+#[test]
+fn layout_batch_writes_a_bif_per_network_in_the_input_directory() {
+    let in_dir = TempDir::new().unwrap();
+    let out_dir = TempDir::new().unwrap();
+
+    fs::write(in_dir.path().join("one.sif"), "Alpha\tpp\tBeta\n").unwrap();
+    fs::write(in_dir.path().join("two.sif"), "Gamma\tpp\tDelta\n").unwrap();
+
+    biofabric()
+        .args([
+            "layout",
+            in_dir.path().to_str().unwrap(),
+            "--batch",
+            "-o",
+            out_dir.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    assert!(out_dir.path().join("one.bif").exists());
+    assert!(out_dir.path().join("two.bif").exists());
+}
+
+#[test]
+fn diff_reports_added_and_removed_links() {
+    let tmp = TempDir::new().unwrap();
+    let before = tmp.path().join("before.sif");
+    let after = tmp.path().join("after.sif");
+    fs::write(&before, "A\tpp\tB\n").unwrap();
+    fs::write(&after, "A\tpp\tC\n").unwrap();
+
+    biofabric()
+        .args([
+            "diff",
+            before.to_str().unwrap(),
+            after.to_str().unwrap(),
+            "--format",
+            "json",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"C\""))
+        .stdout(predicate::str::contains("\"B\""));
+}
+
+#[test]
+fn diff_writes_an_annotated_union_layout_when_output_is_given() {
+    let tmp = TempDir::new().unwrap();
+    let before = tmp.path().join("before.sif");
+    let after = tmp.path().join("after.sif");
+    let out = tmp.path().join("merged.bif");
+    fs::write(&before, "A\tpp\tB\n").unwrap();
+    fs::write(&after, "A\tpp\tC\n").unwrap();
+
+    biofabric()
+        .args(["diff", before.to_str().unwrap(), after.to_str().unwrap(), "-o", out.to_str().unwrap()])
+        .assert()
+        .success();
+
+    assert!(out.exists());
+}
+
+#[test]
+fn centrality_pagerank_ranks_the_hub_first() {
+    let tmp = TempDir::new().unwrap();
+    let input = tmp.path().join("star.sif");
+    fs::write(&input, "hub\tpp\tA\nhub\tpp\tB\nhub\tpp\tC\n").unwrap();
+
+    let output = biofabric()
+        .args(["centrality", input.to_str().unwrap(), "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let ranking = json["ranking"].as_array().unwrap();
+    assert_eq!(ranking.len(), 4);
+    assert_eq!(ranking[0]["node"], "hub");
+}
+
 #[test]
 fn layout_with_start_node() {
     biofabric()
@@ -608,6 +730,44 @@ fn align_missing_args() {
         .failure();
 }
 
+// =========================================================================
+// Align-sweep command
+// =========================================================================
+
+#[test]
+fn align_sweep_scores_every_file_in_the_directory() {
+    let tmp = TempDir::new().unwrap();
+    let aligns_dir = tmp.path().join("aligns");
+    fs::create_dir(&aligns_dir).unwrap();
+    fs::copy(test_align("test_perfect.align"), aligns_dir.join("good.align")).unwrap();
+    fs::copy(test_align("test_partial.align"), aligns_dir.join("partial.align")).unwrap();
+
+    let output = tmp.path().join("scores.tsv");
+
+    biofabric()
+        .args([
+            "align-sweep",
+            &test_sif("align_net1.sif"),
+            &test_sif("align_net2.sif"),
+            "--aligns",
+            aligns_dir.to_str().unwrap(),
+            "--perfect",
+            &test_align("test_perfect.align"),
+            "--output",
+            output.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let contents = fs::read_to_string(&output).unwrap();
+    let mut lines = contents.lines();
+    assert_eq!(lines.next(), Some("alignment\tEC\tS3\tICS\tNC\tNGS\tLGS\tJS"));
+    let rows: Vec<&str> = lines.collect();
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().any(|row| row.starts_with("good\t")));
+    assert!(rows.iter().any(|row| row.starts_with("partial\t")));
+}
+
 // =========================================================================
 // Export-order command
 // =========================================================================
@@ -705,3 +865,381 @@ fn export_order_to_file() {
     let lines: Vec<&str> = content.lines().collect();
     assert_eq!(lines.len(), 3);
 }
+
+#[test]
+fn export_order_from_bif_matches_json_layout() {
+    // Exporting from a saved .bif session must reproduce the same node and
+    // link order as exporting from the equivalent .json layout, since both
+    // read the already-computed layout straight off disk without relayout.
+    let tmp = TempDir::new().unwrap();
+    let json_file = tmp.path().join("layout.json");
+    let bif_file = tmp.path().join("session.bif");
+
+    biofabric()
+        .args([
+            "layout",
+            &test_sif("dense_clique.sif"),
+            "-o",
+            json_file.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    biofabric()
+        .args([
+            "layout",
+            &test_sif("dense_clique.sif"),
+            "-o",
+            bif_file.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let json_nodes = biofabric()
+        .args(["export-order", json_file.to_str().unwrap()])
+        .output()
+        .unwrap();
+    let bif_nodes = biofabric()
+        .args(["export-order", bif_file.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert_eq!(json_nodes.stdout, bif_nodes.stdout);
+
+    let json_links = biofabric()
+        .args(["export-order", json_file.to_str().unwrap(), "--what", "links"])
+        .output()
+        .unwrap();
+    let bif_links = biofabric()
+        .args(["export-order", bif_file.to_str().unwrap(), "--what", "links"])
+        .output()
+        .unwrap();
+    assert_eq!(json_links.stdout, bif_links.stdout);
+}
+
+// =========================================================================
+// Analyze command
+// =========================================================================
+
+#[test]
+fn analyze_diameter_linear_chain() {
+    biofabric()
+        .args(["analyze", &test_sif("linear_chain.sif"), "--metric", "diameter"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Diameter: 9"));
+}
+
+#[test]
+fn analyze_diameter_approx() {
+    biofabric()
+        .args([
+            "analyze",
+            &test_sif("linear_chain.sif"),
+            "--metric",
+            "diameter",
+            "--approx",
+            "3",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("approx"));
+}
+
+#[test]
+fn analyze_density_triangle() {
+    biofabric()
+        .args(["analyze", &test_sif("triangle.sif"), "--metric", "density"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Density: 1.0000"));
+}
+
+// =========================================================================
+// --list-commands
+// =========================================================================
+
+#[test]
+fn list_commands_json_lists_every_subcommand_with_its_options() {
+    let output = biofabric()
+        .args(["--list-commands", "--format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let commands: Vec<serde_json::Value> = serde_json::from_slice(&output).unwrap();
+
+    let names: Vec<&str> = commands.iter().map(|c| c["name"].as_str().unwrap()).collect();
+    for expected in [
+        "layout",
+        "info",
+        "convert",
+        "align",
+        "compare",
+        "extract",
+        "export-order",
+        "search",
+        "align-sweep",
+        "analyze",
+    ] {
+        assert!(names.contains(&expected), "missing subcommand {expected} in {names:?}");
+    }
+
+    // The layout subcommand reports its key options.
+    let layout = commands.iter().find(|c| c["name"] == "layout").unwrap();
+    let layout_args: Vec<&str> = layout["args"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["name"].as_str().unwrap())
+        .collect();
+    assert!(layout_args.contains(&"algorithm"));
+    assert!(layout_args.contains(&"output"));
+}
+
+#[test]
+fn list_commands_text_lists_subcommand_names() {
+    biofabric()
+        .args(["--list-commands"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("layout"))
+        .stdout(predicate::str::contains("align-sweep"));
+}
+
+// =========================================================================
+// Render command
+// =========================================================================
+
+#[test]
+fn render_to_svg_writes_a_line_per_node_and_link() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.svg");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let svg = fs::read_to_string(&out).unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("<line"));
+}
+
+#[test]
+fn render_to_png_writes_a_decodable_image() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.png");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    assert!(out.exists());
+    assert!(image::open(&out).is_ok());
+}
+
+#[test]
+fn render_with_rows_crops_to_a_smaller_image() {
+    let tmp = TempDir::new().unwrap();
+    let full = tmp.path().join("full.png");
+    let cropped = tmp.path().join("cropped.png");
+
+    biofabric()
+        .args(["render", &test_sif("triangle.sif"), "-o", full.to_str().unwrap()])
+        .assert()
+        .success();
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            cropped.to_str().unwrap(),
+            "--rows",
+            "0:0",
+        ])
+        .assert()
+        .success();
+
+    let full_image = image::open(&full).unwrap();
+    let cropped_image = image::open(&cropped).unwrap();
+    assert!(cropped_image.height() < full_image.height());
+}
+
+#[test]
+fn render_rejects_a_malformed_rows_range() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.png");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+            "--rows",
+            "nonsense",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--rows"));
+}
+
+#[test]
+fn render_rejects_an_inverted_rows_range() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.png");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+            "--rows",
+            "5:2",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--rows"));
+}
+
+#[test]
+fn render_rejects_an_inverted_cols_range() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.png");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+            "--cols",
+            "3:1",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--cols"));
+}
+
+#[test]
+fn render_overview_writes_a_thumbnail_within_max_dim() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("overview.png");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+            "--overview",
+            "--overview-max-dim",
+            "32",
+        ])
+        .assert()
+        .success();
+
+    let decoded = image::open(&out).unwrap();
+    assert!(decoded.width() <= 32);
+    assert!(decoded.height() <= 32);
+}
+
+#[test]
+fn render_rejects_overview_combined_with_svg_output() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.svg");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+            "--overview",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--overview"));
+}
+
+#[test]
+fn render_with_tile_size_matches_a_single_buffer_render() {
+    let tmp = TempDir::new().unwrap();
+    let whole = tmp.path().join("whole.png");
+    let tiled = tmp.path().join("tiled.png");
+
+    biofabric()
+        .args(["render", &test_sif("triangle.sif"), "-o", whole.to_str().unwrap()])
+        .assert()
+        .success();
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            tiled.to_str().unwrap(),
+            "--tile-size",
+            "1",
+        ])
+        .assert()
+        .success();
+
+    let whole_image = image::open(&whole).unwrap().to_rgba8();
+    let tiled_image = image::open(&tiled).unwrap().to_rgba8();
+    assert_eq!(whole_image.dimensions(), tiled_image.dimensions());
+    assert_eq!(whole_image.into_raw(), tiled_image.into_raw());
+}
+
+#[test]
+fn render_rejects_a_zero_tile_size() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.png");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+            "--tile-size",
+            "0",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--tile-size"));
+}
+
+#[test]
+fn render_indexed_writes_a_decodable_png() {
+    let tmp = TempDir::new().unwrap();
+    let out = tmp.path().join("out.png");
+
+    biofabric()
+        .args([
+            "render",
+            &test_sif("triangle.sif"),
+            "-o",
+            out.to_str().unwrap(),
+            "--indexed",
+        ])
+        .assert()
+        .success();
+
+    assert!(image::open(&out).is_ok());
+}
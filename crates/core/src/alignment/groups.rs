@@ -257,16 +257,26 @@ impl NodeGroupMap {
             PerfectNGMode::None,
             None,
             DEFAULT_JACCARD_THRESHOLD,
+            false,
             _monitor,
         )
     }
 
     /// Build the node group map from a merged network with a PerfectNG mode.
+    ///
+    /// When `stable_ordering` is `true`, any groups whose tag isn't in the
+    /// canonical [`NODE_GROUP_ANNOTS`]/[`NODE_GROUP_ANNOTS_PERFECT`] order
+    /// (which shouldn't normally happen, but isn't ruled out) are appended
+    /// sorted by tag instead of in `HashMap` iteration order, so repeated
+    /// runs on the same input produce byte-identical output. Defaults to
+    /// `false` to preserve parity with the Java goldens, which were
+    /// generated against the (nondeterministic) `HashMap` order.
     pub fn from_merged_with_mode(
         merged: &MergedNetwork,
         mode: PerfectNGMode,
         jaccard_correct: Option<&HashMap<NodeId, bool>>,
         jaccard_threshold: f64,
+        stable_ordering: bool,
         _monitor: &dyn ProgressMonitor,
     ) -> Self {
         // Build adjacency: for each node, collect incident edge types
@@ -378,9 +388,16 @@ impl NodeGroupMap {
             });
         }
 
-        // Append any unexpected groups at the end to avoid dropping nodes
+        // Append any unexpected groups at the end to avoid dropping nodes.
+        // `tag_to_nodes` is a HashMap, so its iteration order (and thus
+        // which unexpected tag ends up first) varies run to run unless
+        // `stable_ordering` asks us to sort it out explicitly.
         if !tag_to_nodes.is_empty() {
-            for (tag, mut members) in tag_to_nodes {
+            let mut leftover: Vec<(NodeGroupTag, Vec<NodeId>)> = tag_to_nodes.into_iter().collect();
+            if stable_ordering {
+                leftover.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+            }
+            for (tag, mut members) in leftover {
                 members.sort();
                 let group_idx = groups.len();
                 for node_id in &members {
@@ -453,4 +470,59 @@ impl NodeGroupMap {
         }
         counts.iter().map(|&c| c as f64 / total as f64).collect()
     }
+
+    /// Write a `node,color,group_symbol` row for every node in the map.
+    pub fn to_csv<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writeln!(writer, "node,color,group_symbol")?;
+        for group in &self.groups {
+            for node in &group.members {
+                writeln!(writer, "{},{},{}", node, group.color, group.tag.0)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::align::AlignmentMap;
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    #[test]
+    fn test_to_csv_emits_one_row_per_merged_node_with_a_known_group_symbol() {
+        let mut g1 = crate::model::Network::new();
+        g1.add_link(Link::new("A", "B", "pp"));
+        let mut g2 = crate::model::Network::new();
+        g2.add_link(Link::new("X", "Y", "pp"));
+        g2.add_link(Link::new("Y", "Z", "pp"));
+
+        let alignment: AlignmentMap = [("A", "X"), ("B", "Y")]
+            .iter()
+            .map(|(a, b)| (NodeId::new(*a), NodeId::new(*b)))
+            .collect();
+
+        let merged = MergedNetwork::from_alignment(&g1, &g2, &alignment, None, &NoopMonitor).unwrap();
+        let groups = NodeGroupMap::from_merged(&merged, &NoopMonitor);
+
+        let mut csv = Vec::new();
+        groups.to_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next(), Some("node,color,group_symbol"));
+
+        let known_symbols: std::collections::HashSet<&str> =
+            NODE_GROUP_ANNOTS.iter().map(|(tag, _)| *tag).collect();
+        let data_rows: Vec<&str> = lines.collect();
+
+        let node_count = merged.network.nodes().count();
+        assert_eq!(data_rows.len(), node_count);
+
+        for row in data_rows {
+            let symbol = row.rsplit(',').next().unwrap();
+            assert!(known_symbols.contains(symbol), "unexpected group symbol: {symbol}");
+        }
+    }
 }
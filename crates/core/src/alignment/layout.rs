@@ -146,11 +146,16 @@ impl AlignmentNodeLayout {
     ) -> LayoutResult<Vec<NodeId>> {
         use std::collections::{BTreeMap, BTreeSet};
 
-        let groups = self
-            .groups
-            .as_ref()
-            .map(|g| g.clone())
-            .unwrap_or_else(|| NodeGroupMap::from_merged(&self.merged, monitor));
+        let groups = self.groups.as_ref().map(|g| g.clone()).unwrap_or_else(|| {
+            NodeGroupMap::from_merged_with_mode(
+                &self.merged,
+                super::groups::PerfectNGMode::None,
+                None,
+                super::groups::DEFAULT_JACCARD_THRESHOLD,
+                params.stable_ordering,
+                monitor,
+            )
+        });
 
         let mut link_counts: HashMap<NodeId, usize> = HashMap::new();
         let mut targs_per_source: HashMap<NodeId, HashSet<NodeId>> = HashMap::new();
@@ -621,3 +626,45 @@ impl EdgeLayout for AlignmentEdgeLayout {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::merge::MergedNetwork;
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    fn two_node_network(a: &str, b: &str) -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new(a, b, "pp"));
+        network
+    }
+
+    fn alignment(pairs: &[(&str, &str)]) -> crate::io::align::AlignmentMap {
+        pairs
+            .iter()
+            .map(|(g1, g2)| (NodeId::new(*g1), NodeId::new(*g2)))
+            .collect()
+    }
+
+    #[test]
+    fn test_stable_ordering_is_reproducible_across_independently_built_merges() {
+        let g1 = two_node_network("A", "B");
+        let g2 = two_node_network("X", "Y");
+        let align = alignment(&[("A", "X"), ("B", "Y")]);
+
+        let params = LayoutParams {
+            stable_ordering: true,
+            ..Default::default()
+        };
+
+        let run = || {
+            let merged =
+                MergedNetwork::from_alignment(&g1, &g2, &align, Some(&align), &NoopMonitor).unwrap();
+            let node_layout = AlignmentNodeLayout::new(merged, AlignmentLayoutMode::Group);
+            node_layout.layout_nodes(&g1, &params, &NoopMonitor).unwrap()
+        };
+
+        assert_eq!(run(), run());
+    }
+}
@@ -10,7 +10,7 @@
 
 use super::types::{EdgeType, MergedNodeId, NodeColor};
 use crate::io::align::AlignmentMap;
-use crate::model::{Network, NodeId};
+use crate::model::{Link, Network, NodeId};
 use crate::worker::ProgressMonitor;
 use std::collections::HashMap;
 
@@ -366,6 +366,23 @@ impl MergedNetwork {
         })
     }
 
+    /// Build a human-readable node mapping table: one row per merged node,
+    /// with its original G1 name (if any), G2 name (if any), and color
+    /// classification.
+    ///
+    /// This is the inverse of the `.align` file the merge was built from,
+    /// enriched with [`NodeColor`] — handy for exporting as CSV for manual
+    /// review. Rows are sorted by G1 name then G2 name for a stable order.
+    pub fn mapping_table(&self) -> Vec<(Option<String>, Option<String>, NodeColor)> {
+        let mut rows: Vec<(Option<String>, Option<String>, NodeColor)> = self
+            .node_origins
+            .values()
+            .map(|origin| (origin.g1.clone(), origin.g2.clone(), origin.color()))
+            .collect();
+        rows.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+        rows
+    }
+
     /// Count of nodes by color.
     pub fn count_by_color(&self, color: NodeColor) -> usize {
         self.node_colors.values().filter(|&&c| c == color).count()
@@ -391,6 +408,21 @@ impl MergedNetwork {
         self.edge_types.get(link_index).copied()
     }
 
+    /// Every link of the given edge type, for inspecting why an alignment
+    /// scores the way it does (e.g. listing all `EdgeType::Covered` edges).
+    ///
+    /// Excludes shadow links, since `edge_types` classifies the merged
+    /// network's canonical links and shadows merely mirror them.
+    pub fn links_of_type(&self, edge_type: EdgeType) -> Vec<&Link> {
+        self.network
+            .links_slice()
+            .iter()
+            .zip(self.edge_types.iter())
+            .filter(|(link, &ty)| !link.is_shadow && ty == edge_type)
+            .map(|(link, _)| link)
+            .collect()
+    }
+
     /// Whether a node is aligned (purple).
     pub fn is_aligned_node(&self, node_id: &NodeId) -> bool {
         matches!(self.node_color(node_id), Some(NodeColor::Purple))
@@ -436,3 +468,73 @@ impl MergedNetwork {
         Some(correct as f64 / map.len() as f64)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    #[test]
+    fn test_mapping_table_for_perfect_alignment_has_both_names_and_purple_color() {
+        let mut g1 = Network::new();
+        g1.add_link(Link::new("a", "b", "pp"));
+
+        let mut g2 = Network::new();
+        g2.add_link(Link::new("x", "y", "pp"));
+
+        let alignment: AlignmentMap = [
+            (NodeId::new("a"), NodeId::new("x")),
+            (NodeId::new("b"), NodeId::new("y")),
+        ]
+        .into_iter()
+        .collect();
+
+        let merged =
+            MergedNetwork::from_alignment(&g1, &g2, &alignment, None, &NoopMonitor).unwrap();
+
+        let table = merged.mapping_table();
+        assert_eq!(table.len(), 2);
+        for (g1_name, g2_name, color) in &table {
+            assert!(g1_name.is_some());
+            assert!(g2_name.is_some());
+            assert_eq!(*color, NodeColor::Purple);
+        }
+    }
+
+    #[test]
+    fn test_links_of_type_union_equals_full_merged_link_set_excluding_shadows() {
+        let mut g1 = Network::new();
+        g1.add_link(Link::new("a", "b", "pp"));
+        g1.add_link(Link::new("b", "c", "pp"));
+
+        let mut g2 = Network::new();
+        g2.add_link(Link::new("x", "y", "pp"));
+        g2.add_link(Link::new("y", "z", "pp"));
+
+        let alignment: AlignmentMap = [
+            (NodeId::new("a"), NodeId::new("x")),
+            (NodeId::new("b"), NodeId::new("y")),
+        ]
+        .into_iter()
+        .collect();
+
+        let merged =
+            MergedNetwork::from_alignment(&g1, &g2, &alignment, None, &NoopMonitor).unwrap();
+
+        let non_shadow_count = merged.network.links().filter(|l| !l.is_shadow).count();
+        let union_count: usize = EdgeType::all()
+            .iter()
+            .map(|&ty| merged.links_of_type(ty).len())
+            .sum();
+
+        assert_eq!(union_count, non_shadow_count);
+        for link in merged.network.links().filter(|l| !l.is_shadow) {
+            assert!(merged
+                .edge_types
+                .iter()
+                .zip(merged.network.links_slice().iter())
+                .any(|(&ty, l)| l == link && merged.links_of_type(ty).contains(&link)));
+        }
+    }
+}
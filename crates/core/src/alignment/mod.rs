@@ -47,5 +47,5 @@ pub use jaccard::JaccardSimilarity;
 pub use merge::MergedNetwork;
 pub use loader::AlignmentLoader;
 pub use orphan::OrphanFilter;
-pub use scoring::AlignmentScores;
-pub use types::{EdgeType, GraphType, MergedNodeId, NodeColor};
+pub use scoring::{sweep, AlignmentScores};
+pub use types::{alignment_relation_color, EdgeType, GraphType, MergedNodeId, NodeColor};
@@ -22,7 +22,9 @@
 
 use super::merge::MergedNetwork;
 use crate::io::align::AlignmentMap;
+use crate::model::Network;
 use crate::worker::ProgressMonitor;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// All computed alignment quality scores.
@@ -48,6 +50,53 @@ pub struct AlignmentScores {
 }
 
 impl AlignmentScores {
+    /// Compute every score available for `alignment` of `g1` onto `g2`,
+    /// choosing topological-only scoring (EC/S3/ICS) when no reference
+    /// alignment is given and the full NC/NGS/LGS/JS evaluation when
+    /// `perfect` is provided.
+    ///
+    /// This is the single entry point callers should reach for: it builds
+    /// the [`MergedNetwork`] (and, when `perfect` is given, a second one
+    /// merged under `perfect` for the NGS/LGS baseline) internally, so
+    /// scoring an alignment no longer requires constructing a
+    /// `MergedNetwork` by hand or reading golden fixture files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either merge fails (see
+    /// [`MergedNetwork::from_alignment`]).
+    pub fn compute(
+        g1: &Network,
+        g2: &Network,
+        alignment: &AlignmentMap,
+        perfect: Option<&AlignmentMap>,
+        monitor: &dyn ProgressMonitor,
+    ) -> Result<Self, String> {
+        let merged = MergedNetwork::from_alignment(g1, g2, alignment, perfect, monitor)?;
+
+        match perfect {
+            Some(perfect_alignment) => {
+                let perfect_merged = MergedNetwork::from_alignment(
+                    g1,
+                    g2,
+                    perfect_alignment,
+                    Some(perfect_alignment),
+                    monitor,
+                )?;
+                Ok(Self::with_full_evaluation(
+                    &merged,
+                    &perfect_merged,
+                    g1,
+                    g2,
+                    alignment,
+                    perfect_alignment,
+                    monitor,
+                ))
+            }
+            None => Ok(Self::topological(&merged, monitor)),
+        }
+    }
+
     /// Compute topological scores from a merged network.
     ///
     /// These metrics only require the merged network (no reference alignment).
@@ -301,6 +350,96 @@ impl AlignmentScores {
     }
 }
 
+/// Score a batch of `.align` files against a fixed `(g1, g2)` network pair.
+///
+/// This automates the sweep-and-compare workflow alignment case studies use
+/// when comparing many candidate alignments (e.g. an importance-weight sweep
+/// across `s3_001.align` .. `s3_100.align`): each entry in `aligns` is merged
+/// and scored independently, and the results are returned in input order,
+/// labeled by the caller-supplied name.
+///
+/// When `perfect` is provided, it is merged once up front and reused as the
+/// NGS/LGS/JS reference for every entry via [`AlignmentScores::with_full_evaluation`].
+/// Without a reference, only the topological scores (EC, S3, ICS) are computed.
+///
+/// # Errors
+///
+/// Returns an error if merging the perfect alignment, or any `(g1, g2,
+/// alignment)` triple, fails (see [`MergedNetwork::from_alignment`]).
+pub fn sweep(
+    g1: &crate::model::Network,
+    g2: &crate::model::Network,
+    aligns: &[(&str, AlignmentMap)],
+    perfect: Option<&AlignmentMap>,
+    monitor: &dyn ProgressMonitor,
+) -> Result<Vec<(String, AlignmentScores)>, String> {
+    let perfect_merged = perfect
+        .map(|p| MergedNetwork::from_alignment(g1, g2, p, Some(p), monitor))
+        .transpose()?;
+
+    aligns
+        .iter()
+        .map(|(label, alignment)| score_one(g1, g2, label, alignment, perfect, perfect_merged.as_ref(), monitor))
+        .collect()
+}
+
+/// Parallel counterpart to [`sweep`], using `rayon` to merge and score every
+/// entry in `aligns` concurrently rather than in sequence.
+///
+/// Each alignment's merge and score is independent of every other one, so
+/// this only helps when `aligns` is large enough (e.g. a hundred-alignment
+/// importance-weight sweep) for the parallelism to outweigh its overhead;
+/// `monitor` is shared read-only across threads, same as every other
+/// `&dyn ProgressMonitor` use in this crate.
+///
+/// # Errors
+///
+/// Same as [`sweep`].
+pub fn sweep_par(
+    g1: &crate::model::Network,
+    g2: &crate::model::Network,
+    aligns: &[(&str, AlignmentMap)],
+    perfect: Option<&AlignmentMap>,
+    monitor: &dyn ProgressMonitor,
+) -> Result<Vec<(String, AlignmentScores)>, String> {
+    let perfect_merged = perfect
+        .map(|p| MergedNetwork::from_alignment(g1, g2, p, Some(p), monitor))
+        .transpose()?;
+
+    aligns
+        .par_iter()
+        .map(|(label, alignment)| score_one(g1, g2, label, alignment, perfect, perfect_merged.as_ref(), monitor))
+        .collect()
+}
+
+/// Merge and score a single alignment against `(g1, g2)`. Shared by
+/// [`sweep`] and [`sweep_par`], which differ only in whether entries are
+/// visited sequentially or via `rayon`.
+fn score_one(
+    g1: &crate::model::Network,
+    g2: &crate::model::Network,
+    label: &str,
+    alignment: &AlignmentMap,
+    perfect: Option<&AlignmentMap>,
+    perfect_merged: Option<&MergedNetwork>,
+    monitor: &dyn ProgressMonitor,
+) -> Result<(String, AlignmentScores), String> {
+    let merged = MergedNetwork::from_alignment(g1, g2, alignment, perfect, monitor)?;
+    let scores = match (perfect_merged, perfect) {
+        (Some(perfect_merged), Some(perfect_alignment)) => AlignmentScores::with_full_evaluation(
+            &merged,
+            perfect_merged,
+            g1,
+            g2,
+            alignment,
+            perfect_alignment,
+            monitor,
+        ),
+        _ => AlignmentScores::topological(&merged, monitor),
+    };
+    Ok((label.to_string(), scores))
+}
+
 /// Compute Jaccard similarity between two sets.
 ///
 /// Returns a value in `[0.0, 1.0]`. Two empty sets are considered identical (1.0).
@@ -337,3 +476,111 @@ pub fn angular_similarity(a: &[f64], b: &[f64]) -> f64 {
     let cosine = (dot / (mag_a * mag_b)).clamp(-1.0, 1.0);
     1.0 - cosine.acos() / std::f64::consts::FRAC_PI_2
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Link, Network};
+    use crate::worker::NoopMonitor;
+
+    fn two_node_network(a: &str, b: &str) -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new(a, b, "pp"));
+        network
+    }
+
+    fn alignment(pairs: &[(&str, &str)]) -> AlignmentMap {
+        pairs
+            .iter()
+            .map(|(g1, g2)| (crate::model::NodeId::new(*g1), crate::model::NodeId::new(*g2)))
+            .collect()
+    }
+
+    #[test]
+    fn test_sweep_returns_one_row_per_alignment_with_matching_labels() {
+        let g1 = two_node_network("A", "B");
+        let g2 = two_node_network("X", "Y");
+
+        let perfect = alignment(&[("A", "X"), ("B", "Y")]);
+        let swapped = alignment(&[("A", "Y"), ("B", "X")]);
+
+        let aligns = [("good", perfect.clone()), ("bad", swapped)];
+
+        let results = sweep(&g1, &g2, &aligns, Some(&perfect), &NoopMonitor).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "good");
+        assert_eq!(results[1].0, "bad");
+
+        // The alignment that exactly matches the perfect reference covers
+        // the single edge and is fully node-correct.
+        assert_eq!(results[0].1.ec, 1.0);
+        assert_eq!(results[0].1.nc, Some(1.0));
+
+        // The swapped alignment still covers the same undirected edge, but
+        // disagrees with the perfect reference on both node mappings.
+        assert_eq!(results[1].1.nc, Some(0.0));
+    }
+
+    #[test]
+    fn test_sweep_par_matches_sweep_on_the_same_input() {
+        let g1 = two_node_network("A", "B");
+        let g2 = two_node_network("X", "Y");
+
+        let perfect = alignment(&[("A", "X"), ("B", "Y")]);
+        let swapped = alignment(&[("A", "Y"), ("B", "X")]);
+
+        let aligns = [("good", perfect.clone()), ("bad", swapped)];
+
+        let sequential = sweep(&g1, &g2, &aligns, Some(&perfect), &NoopMonitor).unwrap();
+        let parallel = sweep_par(&g1, &g2, &aligns, Some(&perfect), &NoopMonitor).unwrap();
+
+        assert_eq!(sequential.len(), parallel.len());
+        for ((seq_label, seq_scores), (par_label, par_scores)) in sequential.iter().zip(parallel.iter()) {
+            assert_eq!(seq_label, par_label);
+            assert_eq!(seq_scores.ec, par_scores.ec);
+            assert_eq!(seq_scores.nc, par_scores.nc);
+        }
+    }
+
+    #[test]
+    fn test_compute_matches_with_full_evaluation_given_a_perfect_alignment() {
+        let g1 = two_node_network("A", "B");
+        let g2 = two_node_network("X", "Y");
+
+        let swapped = alignment(&[("A", "Y"), ("B", "X")]);
+        let perfect = alignment(&[("A", "X"), ("B", "Y")]);
+
+        let scores = AlignmentScores::compute(&g1, &g2, &swapped, Some(&perfect), &NoopMonitor).unwrap();
+
+        let merged = MergedNetwork::from_alignment(&g1, &g2, &swapped, Some(&perfect), &NoopMonitor).unwrap();
+        let perfect_merged =
+            MergedNetwork::from_alignment(&g1, &g2, &perfect, Some(&perfect), &NoopMonitor).unwrap();
+        let expected = AlignmentScores::with_full_evaluation(
+            &merged,
+            &perfect_merged,
+            &g1,
+            &g2,
+            &swapped,
+            &perfect,
+            &NoopMonitor,
+        );
+
+        assert_eq!(scores.ec, expected.ec);
+        assert_eq!(scores.nc, expected.nc);
+        assert_eq!(scores.nc, Some(0.0));
+    }
+
+    #[test]
+    fn test_compute_falls_back_to_topological_without_a_perfect_alignment() {
+        let g1 = two_node_network("A", "B");
+        let g2 = two_node_network("X", "Y");
+        let aligned = alignment(&[("A", "X"), ("B", "Y")]);
+
+        let scores = AlignmentScores::compute(&g1, &g2, &aligned, None, &NoopMonitor).unwrap();
+
+        assert_eq!(scores.ec, 1.0);
+        assert_eq!(scores.nc, None);
+        assert_eq!(scores.ngs, None);
+    }
+}
@@ -5,6 +5,7 @@
 //! - Java: `org.systemsbiology.biofabric.plugin.core.align.NetworkAlignment`
 //! - Java: `org.systemsbiology.biofabric.plugin.core.align.NetworkAlignmentBuildData`
 
+use crate::io::color::{ColorPalette, FabricColor};
 use crate::model::NodeId;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -111,6 +112,14 @@ impl EdgeType {
         }
     }
 
+    /// Parse a short code (e.g. `"P"`, `"pBp"`, `"rRr"`) back into an [`EdgeType`].
+    ///
+    /// Returns `None` for unrecognized codes. Matching is case-sensitive,
+    /// matching [`short_code`](Self::short_code)'s output exactly.
+    pub fn from_short_code(code: &str) -> Option<EdgeType> {
+        Self::all().iter().find(|ty| ty.short_code() == code).copied()
+    }
+
     /// All edge types in canonical display order.
     pub fn all() -> &'static [EdgeType] {
         &[
@@ -145,6 +154,12 @@ impl EdgeType {
                 | EdgeType::FullUnalignedGraph2
         )
     }
+
+    /// This edge type's position in [`EdgeType::all`]'s canonical display
+    /// order, used as a stable palette index for legend swatches.
+    fn legend_index(&self) -> usize {
+        Self::all().iter().position(|ty| ty == self).expect("self is always in Self::all()")
+    }
 }
 
 impl fmt::Display for EdgeType {
@@ -215,3 +230,19 @@ impl fmt::Display for MergedNodeId {
         write!(f, "{}::{}", g1, g2)
     }
 }
+
+/// Look up the legend color for an alignment relation short code (`"P"`,
+/// `"pBp"`, `"pRr"`, etc.), so a rendered legend can stay consistent with
+/// however the merged alignment layout is colored elsewhere.
+///
+/// Returns `None` for any relation string that isn't one of the seven
+/// alignment [`EdgeType`] short codes — e.g. a relation from a
+/// non-alignment network, which has no alignment-specific color.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+pub fn alignment_relation_color(relation: &str) -> Option<FabricColor> {
+    let edge_type = EdgeType::from_short_code(relation)?;
+    Some(ColorPalette::default_palette().get(edge_type.legend_index()))
+}
@@ -0,0 +1,120 @@
+//! Node centrality metrics.
+//!
+//! - [`pagerank`] — iterative PageRank score over the undirected graph
+//!
+//! ## References
+//!
+//! (none — not in the Java original)
+
+use crate::model::{Network, NodeId};
+use std::collections::HashMap;
+
+/// Compute PageRank scores over `network`'s undirected, non-shadow graph.
+///
+/// Builds an undirected adjacency list from every non-shadow link other
+/// than self-loops (a shadow link duplicates a regular link's endpoints rather than
+/// representing a distinct edge, matching [`density`](super::graph::density)'s
+/// treatment), then runs the standard power-iteration update for `iters`
+/// rounds:
+///
+/// ```text
+/// PR(v) = (1 - damping) / n + damping * sum(PR(u) / out_degree(u) for u in neighbors(v))
+/// ```
+///
+/// A node with no neighbors keeps its `(1 - damping) / n` teleportation
+/// share rather than distributing a score nowhere. The result is
+/// normalized so every score sums to `1.0`.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+pub fn pagerank(network: &Network, damping: f64, iters: usize) -> HashMap<NodeId, f64> {
+    let nodes: Vec<NodeId> = network.node_ids().cloned().collect();
+    let n = nodes.len();
+    if n == 0 {
+        return HashMap::new();
+    }
+
+    let mut adjacency: HashMap<NodeId, Vec<NodeId>> = nodes.iter().map(|id| (id.clone(), Vec::new())).collect();
+    for link in network.links() {
+        if link.is_shadow || link.source == link.target {
+            continue;
+        }
+        adjacency.get_mut(&link.source).unwrap().push(link.target.clone());
+        adjacency.get_mut(&link.target).unwrap().push(link.source.clone());
+    }
+
+    let teleport = (1.0 - damping) / n as f64;
+    let mut scores: HashMap<NodeId, f64> = nodes.iter().map(|id| (id.clone(), 1.0 / n as f64)).collect();
+
+    for _ in 0..iters {
+        let mut next: HashMap<NodeId, f64> = nodes.iter().map(|id| (id.clone(), teleport)).collect();
+        for id in &nodes {
+            let neighbors = &adjacency[id];
+            if neighbors.is_empty() {
+                continue;
+            }
+            let share = damping * scores[id] / neighbors.len() as f64;
+            for neighbor in neighbors {
+                *next.get_mut(neighbor).unwrap() += share;
+            }
+        }
+        scores = next;
+    }
+
+    let total: f64 = scores.values().sum();
+    if total > 0.0 {
+        for value in scores.values_mut() {
+            *value /= total;
+        }
+    }
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Link;
+
+    fn star_network(spokes: usize) -> Network {
+        let mut network = Network::new();
+        for i in 1..=spokes {
+            network.add_link(Link::new("hub", format!("n{i}"), "pp"));
+        }
+        network
+    }
+
+    #[test]
+    fn test_pagerank_sums_to_one() {
+        let network = star_network(10);
+        let scores = pagerank(&network, 0.85, 50);
+        let total: f64 = scores.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_pagerank_hub_beats_every_leaf_and_leaves_are_equal() {
+        // Same shape as `star-500.sif`: a single hub connected to every
+        // other node, with 500 leaves.
+        let network = star_network(500);
+        let scores = pagerank(&network, 0.85, 100);
+
+        let hub_score = scores[&NodeId::new("hub")];
+        let leaf_scores: Vec<f64> = (1..=500).map(|i| scores[&NodeId::new(format!("n{i}"))]).collect();
+
+        for &leaf_score in &leaf_scores {
+            assert!(hub_score > leaf_score, "hub ({hub_score}) should outrank every leaf ({leaf_score})");
+        }
+
+        let first = leaf_scores[0];
+        for &leaf_score in &leaf_scores {
+            assert!((leaf_score - first).abs() < 1e-9, "all leaves should score equally by symmetry");
+        }
+    }
+
+    #[test]
+    fn test_pagerank_empty_network() {
+        let network = Network::new();
+        assert!(pagerank(&network, 0.85, 10).is_empty());
+    }
+}
@@ -100,8 +100,10 @@ pub fn dfs(network: &Network, start: &NodeId) -> Vec<NodeId> {
 /// Find all connected components in the network.
 ///
 /// Returns a vector of components, where each component is a vector of node IDs.
-/// Components are sorted by size (largest first), and nodes within each
-/// component are in BFS order from the highest-degree node.
+/// Components are sorted by size descending, then by their smallest member
+/// name, so the ordering is fully deterministic even when several
+/// components share the same size. Nodes within each component are in BFS
+/// order from the highest-degree node.
 pub fn connected_components(network: &Network) -> Vec<Vec<NodeId>> {
     let mut unvisited: HashSet<NodeId> = network.node_ids().cloned().collect();
     let mut components = Vec::new();
@@ -126,8 +128,93 @@ pub fn connected_components(network: &Network) -> Vec<Vec<NodeId>> {
         components.push(component);
     }
 
-    // Sort components by size descending
-    components.sort_by(|a, b| b.len().cmp(&a.len()));
+    // Sort components by size descending, then by smallest member name, so
+    // equal-sized components still come out in a stable order regardless of
+    // HashSet iteration order above.
+    components.sort_by(|a, b| {
+        b.len().cmp(&a.len()).then_with(|| a.iter().min().cmp(&b.iter().min()))
+    });
+    components
+}
+
+/// Disjoint-set (union-find) forest with union by rank and path
+/// compression, indexed by position rather than [`NodeId`] so union/find
+/// are plain array operations.
+struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+}
+
+impl UnionFind {
+    fn new(size: usize) -> Self {
+        UnionFind {
+            parent: (0..size).collect(),
+            rank: vec![0; size],
+        }
+    }
+
+    fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return;
+        }
+        match self.rank[root_a].cmp(&self.rank[root_b]) {
+            std::cmp::Ordering::Less => self.parent[root_a] = root_b,
+            std::cmp::Ordering::Greater => self.parent[root_b] = root_a,
+            std::cmp::Ordering::Equal => {
+                self.parent[root_b] = root_a;
+                self.rank[root_a] += 1;
+            }
+        }
+    }
+}
+
+/// Find all connected components using union-find (disjoint-set) instead
+/// of per-component BFS.
+///
+/// [`connected_components`] re-traverses the graph with a fresh BFS for
+/// every component it discovers; this instead makes a single pass over
+/// every link, unioning each link's endpoints, and only then groups nodes
+/// by their root. On large, dense networks that single pass over links
+/// (with near-constant-time unions thanks to path compression and union
+/// by rank) does substantially less work than repeated graph traversal.
+///
+/// Produces the same partition of nodes into components as
+/// [`connected_components`] (verified in tests), but does not replicate
+/// its per-component BFS-from-highest-degree-node ordering: nodes within
+/// a component are sorted lexicographically instead, and components are
+/// ordered by size descending, then by their lexicographically smallest
+/// node, to keep the result deterministic.
+pub fn connected_components_union_find(network: &Network) -> Vec<Vec<NodeId>> {
+    let mut nodes: Vec<NodeId> = network.node_ids().cloned().collect();
+    nodes.sort();
+    let index: HashMap<&NodeId, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+
+    let mut uf = UnionFind::new(nodes.len());
+    for link in network.links() {
+        if let (Some(&a), Some(&b)) = (index.get(&link.source), index.get(&link.target)) {
+            uf.union(a, b);
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<NodeId>> = HashMap::new();
+    for (i, node) in nodes.iter().enumerate() {
+        let root = uf.find(i);
+        groups.entry(root).or_default().push(node.clone());
+    }
+
+    let mut components: Vec<Vec<NodeId>> = groups.into_values().collect();
+    for component in &mut components {
+        component.sort();
+    }
+    components.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a[0].cmp(&b[0])));
     components
 }
 
@@ -231,6 +318,19 @@ pub fn neighborhood(network: &Network, start: &NodeId, hops: usize) -> HashSet<N
     visited
 }
 
+/// Jaccard similarity of two nodes' first-neighbor sets: `|shared| / |union|`.
+///
+/// Returns `None` if either node is missing from `network`. Two isolated
+/// nodes (both with empty neighbor sets) are considered identical, so this
+/// returns `Some(1.0)` rather than dividing by zero.
+///
+/// This is the same computation behind
+/// [`Network::compare_nodes`](crate::model::Network::compare_nodes), exposed
+/// as a standalone function for callers that only need the score.
+pub fn jaccard_similarity(network: &Network, a: &NodeId, b: &NodeId) -> Option<f64> {
+    network.compare_nodes(a, b).map(|cmp| cmp.jaccard_similarity)
+}
+
 /// Find the node with highest degree in the network.
 ///
 /// # Returns
@@ -267,6 +367,39 @@ pub fn nodes_by_degree(network: &Network) -> Vec<(NodeId, usize)> {
     nodes
 }
 
+/// Count link endpoints per node over the network's full link set,
+/// including shadow links.
+///
+/// This matches BioFabric's "link count" column, not [`Network::degree`]'s
+/// plain incident-edge count: a self-loop has no shadow and counts as 2
+/// (both its endpoints land on the same node), while an ordinary edge
+/// contributes 1 to each of its two endpoints (and, since SIF import adds
+/// an inline shadow for every non-feedback link, a further 1 to each
+/// endpoint from that shadow). Nodes present in the network but with no
+/// incident links (including lone nodes) are included with degree 0.
+///
+/// ## References
+///
+/// - Java: `org.systemsbiology.biofabric.analysis.GraphSearcher` link-count pass
+pub fn node_degree(network: &Network) -> HashMap<NodeId, usize> {
+    let mut degrees: HashMap<NodeId, usize> = HashMap::new();
+
+    for id in network.node_ids() {
+        degrees.entry(id.clone()).or_insert(0);
+    }
+
+    for link in network.links() {
+        if link.source == link.target {
+            *degrees.entry(link.source.clone()).or_insert(0) += 2;
+        } else {
+            *degrees.entry(link.source.clone()).or_insert(0) += 1;
+            *degrees.entry(link.target.clone()).or_insert(0) += 1;
+        }
+    }
+
+    degrees
+}
+
 /// Compute a topological ordering of a directed network (Kahn's algorithm).
 ///
 /// Returns `Some(order)` if the network is a DAG, `None` if it contains a
@@ -385,7 +518,9 @@ pub fn topological_sort(network: &Network, compress: bool) -> Option<Vec<NodeId>
     }
 }
 
-/// Compute the level (longest path from any source) for each node in a DAG.
+/// Compute the level (longest path from any source) for each node in a DAG:
+/// `level(node) = max(level(parent) for parent in node's directed
+/// predecessors) + 1`, with sources at level 0.
 ///
 /// Returns `None` if the network contains a cycle. Useful for
 /// [`HierDAGLayout`](crate::layout::HierDAGLayout) level assignment.
@@ -417,6 +552,256 @@ pub fn dag_levels(network: &Network) -> Option<HashMap<NodeId, usize>> {
     Some(levels)
 }
 
+/// Compute the eccentricity (longest shortest path) from `start` via BFS,
+/// restricted to `start`'s connected component.
+///
+/// Returns `None` if `start` doesn't exist in the network.
+fn node_eccentricity(network: &Network, start: &NodeId) -> Option<usize> {
+    if !network.contains_node(start) {
+        return None;
+    }
+
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+    let mut max_depth = 0usize;
+
+    visited.insert(start.clone());
+    queue.push_back((start.clone(), 0usize));
+
+    while let Some((node_id, depth)) = queue.pop_front() {
+        max_depth = max_depth.max(depth);
+        let mut neighbors: Vec<NodeId> = network
+            .neighbors(&node_id)
+            .into_iter()
+            .filter(|n| !visited.contains(*n))
+            .cloned()
+            .collect();
+        neighbors.sort();
+
+        for neighbor in neighbors {
+            if visited.insert(neighbor.clone()) {
+                queue.push_back((neighbor, depth + 1));
+            }
+        }
+    }
+
+    Some(max_depth)
+}
+
+/// Compute the network's diameter: the longest shortest path between any
+/// two nodes in its largest connected component.
+///
+/// Runs a full BFS from every node in the largest component — `O(V * E)` —
+/// which is exact but can be slow on large networks. See
+/// [`diameter_approx`] for a sampling-based estimate.
+///
+/// Returns `None` for an empty network.
+pub fn diameter(network: &Network) -> Option<usize> {
+    let largest = connected_components(network).into_iter().next()?;
+    largest
+        .iter()
+        .filter_map(|node| node_eccentricity(network, node))
+        .max()
+}
+
+/// Estimate the network's diameter by taking the largest eccentricity seen
+/// from a sample of up to `sample_size` nodes in the largest connected
+/// component, rather than BFS-ing from every node.
+///
+/// Since eccentricity from any single node is a lower bound on the true
+/// diameter, this estimate is never larger than the exact value — it may
+/// under-estimate if the sample misses both endpoints of the longest
+/// shortest path. Nodes are sampled by taking the first `sample_size` in
+/// sorted (name) order, for determinism.
+///
+/// Returns `None` for an empty network.
+pub fn diameter_approx(network: &Network, sample_size: usize) -> Option<usize> {
+    let mut largest = connected_components(network).into_iter().next()?;
+    largest.sort();
+    largest.truncate(sample_size.max(1));
+
+    largest.iter().filter_map(|node| node_eccentricity(network, node)).max()
+}
+
+/// Compute the eccentricity (longest shortest path to any other node) of
+/// every node in the network, each restricted to its own connected
+/// component.
+///
+/// The maximum value in the returned map, over just the largest
+/// component, is the network's [`diameter`]. Nodes in smaller components
+/// still get an entry, but it only reflects distances within that
+/// smaller component, not the whole graph.
+pub fn eccentricity(network: &Network) -> HashMap<NodeId, usize> {
+    network
+        .node_ids()
+        .filter_map(|id| node_eccentricity(network, id).map(|e| (id.clone(), e)))
+        .collect()
+}
+
+/// Compute the network's density: the fraction of possible undirected
+/// edges between distinct node pairs that are actually present.
+///
+/// For a network with `n` nodes, the maximum number of undirected edges is
+/// `n * (n - 1) / 2`. Shadow links are excluded, since they duplicate a
+/// regular link's endpoints rather than representing a distinct edge.
+/// Parallel edges between the same pair (e.g. two relations between the
+/// same nodes) aren't double-counted.
+///
+/// Returns `0.0` for networks with fewer than 2 nodes.
+pub fn density(network: &Network) -> f64 {
+    let n = network.node_count();
+    if n < 2 {
+        return 0.0;
+    }
+
+    let mut pairs: HashSet<(NodeId, NodeId)> = HashSet::new();
+    for link in network.links() {
+        if link.is_shadow {
+            continue;
+        }
+        let pair = if link.source <= link.target {
+            (link.source.clone(), link.target.clone())
+        } else {
+            (link.target.clone(), link.source.clone())
+        };
+        pairs.insert(pair);
+    }
+
+    let max_pairs = (n * (n - 1)) / 2;
+    pairs.len() as f64 / max_pairs as f64
+}
+
+/// Check whether `network` contains a cycle, under the undirected
+/// interpretation of its links.
+///
+/// A non-shadow, non-explicitly-directed self-loop (a link whose source
+/// and target are the same node) counts as a cycle. Otherwise, this builds an undirected adjacency graph
+/// from every non-shadow, non-explicitly-directed link (`directed !=
+/// Some(true)`) and looks for a back edge via DFS, checking every
+/// connected component — a cycle confined to one disconnected piece of the
+/// network still makes this `true`.
+///
+/// Links with `directed == Some(true)` are excluded, since those are
+/// already covered by [`cycle::find_cycle`](super::cycle::find_cycle)'s
+/// directed-graph cycle check; combine both when a network mixes directed
+/// and undirected relations.
+///
+/// ## References
+///
+/// - Java: `org.systemsbiology.biofabric.analysis.CycleFinder`
+pub fn has_cycle(network: &Network) -> bool {
+    let mut adjacency: HashMap<NodeId, HashSet<NodeId>> = HashMap::new();
+
+    for link in network.links() {
+        if link.is_shadow || link.directed == Some(true) {
+            continue;
+        }
+        if link.source == link.target {
+            return true;
+        }
+        adjacency.entry(link.source.clone()).or_default().insert(link.target.clone());
+        adjacency.entry(link.target.clone()).or_default().insert(link.source.clone());
+    }
+
+    let mut visited: HashSet<NodeId> = HashSet::new();
+    for node in network.node_ids() {
+        if visited.contains(node) {
+            continue;
+        }
+        if dfs_undirected_cycle(node, None, &adjacency, &mut visited) {
+            return true;
+        }
+    }
+    false
+}
+
+fn dfs_undirected_cycle(
+    node: &NodeId,
+    parent: Option<&NodeId>,
+    adjacency: &HashMap<NodeId, HashSet<NodeId>>,
+    visited: &mut HashSet<NodeId>,
+) -> bool {
+    visited.insert(node.clone());
+    if let Some(neighbors) = adjacency.get(node) {
+        for neighbor in neighbors {
+            if Some(neighbor) == parent {
+                continue;
+            }
+            if visited.contains(neighbor) {
+                return true;
+            }
+            if dfs_undirected_cycle(neighbor, Some(node), adjacency, visited) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Build the undirected simple-graph adjacency (shadows and self-loops
+/// excluded, parallel relations collapsed) used by [`clustering_coefficient`].
+fn simple_adjacency(network: &Network) -> HashMap<NodeId, HashSet<NodeId>> {
+    let mut adjacency: HashMap<NodeId, HashSet<NodeId>> =
+        network.node_ids().map(|id| (id.clone(), HashSet::new())).collect();
+
+    for link in network.links() {
+        if link.is_shadow || link.source == link.target {
+            continue;
+        }
+        adjacency.entry(link.source.clone()).or_default().insert(link.target.clone());
+        adjacency.entry(link.target.clone()).or_default().insert(link.source.clone());
+    }
+
+    adjacency
+}
+
+/// Compute the local clustering coefficient of every node and the global
+/// average, on the undirected simple graph (shadows and self-loops
+/// ignored, parallel relations between the same pair collapsed).
+///
+/// A node's local coefficient is the fraction of pairs among its neighbors
+/// that are themselves connected: `2 * triangles / (k * (k - 1))` for a
+/// node of degree `k`. Nodes with fewer than 2 neighbors have coefficient
+/// `0.0` (there are no neighbor pairs to test).
+///
+/// The global average is the mean of all per-node coefficients, or `0.0`
+/// for an empty network.
+///
+/// ## References
+///
+/// - Watts & Strogatz (1998), "Collective dynamics of 'small-world' networks"
+pub fn clustering_coefficient(network: &Network) -> (HashMap<NodeId, f64>, f64) {
+    let adjacency = simple_adjacency(network);
+
+    let mut per_node: HashMap<NodeId, f64> = HashMap::new();
+    for (node, neighbors) in &adjacency {
+        let k = neighbors.len();
+        let coefficient = if k < 2 {
+            0.0
+        } else {
+            let mut triangles = 0usize;
+            let neighbor_vec: Vec<&NodeId> = neighbors.iter().collect();
+            for i in 0..neighbor_vec.len() {
+                for j in (i + 1)..neighbor_vec.len() {
+                    if adjacency[neighbor_vec[i]].contains(neighbor_vec[j]) {
+                        triangles += 1;
+                    }
+                }
+            }
+            (2 * triangles) as f64 / (k * (k - 1)) as f64
+        };
+        per_node.insert(node.clone(), coefficient);
+    }
+
+    let average = if per_node.is_empty() {
+        0.0
+    } else {
+        per_node.values().sum::<f64>() / per_node.len() as f64
+    };
+
+    (per_node, average)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -440,6 +825,141 @@ mod tests {
         assert_eq!(highest, Some(NodeId::new("B")));
     }
 
+    #[test]
+    fn test_diameter_of_linear_chain() {
+        // Same content as tests/parity/networks/sif/linear_chain.sif.
+        let network = crate::io::sif::parse_string(
+            "N1\tpp\tN2\nN2\tpp\tN3\nN3\tpp\tN4\nN4\tpp\tN5\nN5\tpp\tN6\n\
+             N6\tpp\tN7\nN7\tpp\tN8\nN8\tpp\tN9\nN9\tpp\tN10\n",
+        )
+        .unwrap();
+
+        assert_eq!(diameter(&network), Some(9));
+    }
+
+    #[test]
+    fn test_diameter_of_empty_network_is_none() {
+        let network = Network::new();
+        assert_eq!(diameter(&network), None);
+    }
+
+    #[test]
+    fn test_diameter_approx_never_exceeds_exact_diameter() {
+        let network = crate::io::sif::parse_string(
+            "N1\tpp\tN2\nN2\tpp\tN3\nN3\tpp\tN4\nN4\tpp\tN5\nN5\tpp\tN6\n\
+             N6\tpp\tN7\nN7\tpp\tN8\nN8\tpp\tN9\nN9\tpp\tN10\n",
+        )
+        .unwrap();
+
+        let exact = diameter(&network).unwrap();
+        let approx = diameter_approx(&network, 3).unwrap();
+        assert!(approx <= exact);
+    }
+
+    #[test]
+    fn test_diameter_of_star_is_two() {
+        // Same shape as tests/parity/networks/sif/star-500.sif, a hub with a
+        // number of leaves: any two leaves are two hops apart via the hub.
+        let mut network = Network::new();
+        for i in 0..500 {
+            network.add_link(Link::new("hub", format!("leaf{}", i), "r"));
+        }
+        assert_eq!(diameter(&network), Some(2));
+    }
+
+    #[test]
+    fn test_eccentricity_of_linear_chain() {
+        let network = crate::io::sif::parse_string(
+            "N1\tpp\tN2\nN2\tpp\tN3\nN3\tpp\tN4\nN4\tpp\tN5\nN5\tpp\tN6\n\
+             N6\tpp\tN7\nN7\tpp\tN8\nN8\tpp\tN9\nN9\tpp\tN10\n",
+        )
+        .unwrap();
+
+        let ecc = eccentricity(&network);
+        assert_eq!(ecc[&NodeId::new("N1")], 9);
+        assert_eq!(ecc[&NodeId::new("N10")], 9);
+        assert_eq!(ecc.values().copied().max(), Some(9));
+        assert_eq!(ecc.len(), 10);
+    }
+
+    #[test]
+    fn test_density_of_triangle_is_one() {
+        // Same content as tests/parity/networks/sif/triangle.sif.
+        let network = crate::io::sif::parse_string("A\tpp\tB\nB\tpp\tC\nA\tpp\tC\n").unwrap();
+        assert_eq!(density(&network), 1.0);
+    }
+
+    #[test]
+    fn test_density_of_single_edge_on_three_nodes() {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "r"));
+        network.add_lone_node("C");
+        // 1 edge out of a possible 3 (A-B, A-C, B-C).
+        assert!((density(&network) - 1.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clustering_coefficient_of_a_clique_is_one() {
+        let network =
+            crate::io::sif::parse_string("A\tpp\tB\nA\tpp\tC\nB\tpp\tC\n").unwrap();
+        let (per_node, average) = clustering_coefficient(&network);
+
+        for id in ["A", "B", "C"] {
+            assert_eq!(per_node[&NodeId::new(id)], 1.0);
+        }
+        assert_eq!(average, 1.0);
+    }
+
+    #[test]
+    fn test_clustering_coefficient_of_a_star_is_zero() {
+        // Hub connected to 3 leaves that aren't connected to each other.
+        let network = crate::io::sif::parse_string(
+            "Hub\tpp\tLeafA\nHub\tpp\tLeafB\nHub\tpp\tLeafC\n",
+        )
+        .unwrap();
+        let (per_node, average) = clustering_coefficient(&network);
+
+        for id in ["Hub", "LeafA", "LeafB", "LeafC"] {
+            assert_eq!(per_node[&NodeId::new(id)], 0.0);
+        }
+        assert_eq!(average, 0.0);
+    }
+
+    #[test]
+    fn test_dag_levels_diamond_takes_the_longer_path() {
+        // Top -> Left, Top -> Right, Left -> Bottom, Right -> Bottom.
+        // Bottom has two level-1 parents, so it lands at level 2, not 1.
+        let mut network = crate::io::sif::parse_string(
+            "Top\tpd\tLeft\nTop\tpd\tRight\nLeft\tpd\tBottom\nRight\tpd\tBottom\n",
+        )
+        .unwrap();
+        for link in network.links_mut() {
+            if !link.is_shadow {
+                link.directed = Some(true);
+            }
+        }
+
+        let levels = dag_levels(&network).expect("diamond is acyclic");
+        assert_eq!(levels[&NodeId::new("Top")], 0);
+        assert_eq!(levels[&NodeId::new("Left")], 1);
+        assert_eq!(levels[&NodeId::new("Right")], 1);
+        assert_eq!(levels[&NodeId::new("Bottom")], 2);
+    }
+
+    #[test]
+    fn test_connected_components_breaks_size_ties_by_smallest_member_name() {
+        // Two disjoint edges of equal size: {Z, Y} and {A, B}. Size ties
+        // must be broken by the smallest member name, not discovery order.
+        let mut network = Network::new();
+        network.add_link(Link::new("Z", "Y", "r"));
+        network.add_link(Link::new("A", "B", "r"));
+
+        let components = connected_components(&network);
+        assert_eq!(components.len(), 2);
+        assert!(components[0].contains(&NodeId::new("A")));
+        assert!(components[1].contains(&NodeId::new("Z")));
+    }
+
     // TODO: Enable tests once algorithms are implemented
     //
     // #[test]
@@ -468,4 +988,30 @@ mod tests {
     //     let components = connected_components(&network);
     //     assert_eq!(components.len(), 3);
     // }
+
+    #[test]
+    fn test_jaccard_similarity_of_triangle() {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "r"));
+        network.add_link(Link::new("B", "C", "r"));
+        network.add_link(Link::new("A", "C", "r"));
+
+        // neighbors(A) = {B, C}, neighbors(B) = {A, C}
+        // shared = {C}, union = {A, B, C} → 1/3
+        assert!(
+            (jaccard_similarity(&network, &NodeId::new("A"), &NodeId::new("B")).unwrap()
+                - 1.0 / 3.0)
+                .abs()
+                < 1e-10
+        );
+    }
+
+    #[test]
+    fn test_jaccard_similarity_missing_node_is_none() {
+        let network = create_test_network();
+        assert_eq!(
+            jaccard_similarity(&network, &NodeId::new("A"), &NodeId::new("ZZZ")),
+            None
+        );
+    }
 }
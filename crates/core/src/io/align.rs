@@ -17,7 +17,7 @@
 //!
 //! - Java: `org.systemsbiology.biofabric.plugin.core.align.NetworkAlignmentPlugIn` (alignment file loading)
 
-use super::ParseError;
+use super::{strip_bom, ParseError};
 use crate::model::NodeId;
 use indexmap::IndexMap;
 use std::io::{BufRead, BufReader, Read};
@@ -30,8 +30,8 @@ pub type AlignmentMap = IndexMap<NodeId, NodeId>;
 ///
 /// Returns a mapping from G1 node IDs to G2 node IDs.
 pub fn parse_file(path: &Path) -> Result<AlignmentMap, ParseError> {
-    let file = std::fs::File::open(path)?;
-    parse_reader(BufReader::new(file))
+    let file = std::fs::File::open(path).map_err(|e| ParseError::from(e).with_path(path))?;
+    parse_reader(BufReader::new(file)).map_err(|e| e.with_path(path))
 }
 
 /// Parse an alignment file from any reader.
@@ -40,6 +40,7 @@ pub fn parse_reader<R: Read>(reader: BufReader<R>) -> Result<AlignmentMap, Parse
 
     for (line_num, line_result) in reader.lines().enumerate() {
         let line = line_result?;
+        let line = if line_num == 0 { strip_bom(&line) } else { line.as_str() };
         let trimmed = line.trim();
 
         // Skip empty lines and comment lines
@@ -32,8 +32,8 @@ use std::path::Path;
 
 /// Load annotations from a file path.
 pub fn parse_file(path: &Path) -> Result<AnnotationSet, ParseError> {
-    let file = std::fs::File::open(path)?;
-    parse_reader(BufReader::new(file))
+    let file = std::fs::File::open(path).map_err(|e| ParseError::from(e).with_path(path))?;
+    parse_reader(BufReader::new(file)).map_err(|e| e.with_path(path))
 }
 
 /// Load annotations from any reader.
@@ -31,7 +31,7 @@
 //!
 //! - Java: `org.systemsbiology.biofabric.io.AttributeLoader`
 
-use crate::io::ParseError;
+use crate::io::{strip_bom, ParseError};
 use crate::model::NodeId;
 use std::collections::HashMap;
 use std::io::{BufRead, BufReader, Read};
@@ -100,29 +100,225 @@ impl AttributeTable {
 
 /// Load attributes from a file path.
 pub fn parse_file(path: &Path) -> Result<AttributeTable, ParseError> {
-    let file = std::fs::File::open(path)?;
-    parse_reader(BufReader::new(file))
+    let file = std::fs::File::open(path).map_err(|e| ParseError::from(e).with_path(path))?;
+    parse_reader(BufReader::new(file)).map_err(|e| e.with_path(path))
 }
 
 /// Load attributes from any reader.
 pub fn parse_reader<R: Read>(reader: BufReader<R>) -> Result<AttributeTable, ParseError> {
-    // TODO: Implement attribute loading
-    //
-    // Algorithm:
-    // 1. Read first line as header: split by tab → column_names (skip first column "node_id")
-    // 2. For each subsequent line:
-    //    a. Split by tab
-    //    b. First token = node ID
-    //    c. Remaining tokens = attribute values (zipped with column_names)
-    //    d. Insert into node_attributes map
-    // 3. Skip empty lines and comment lines (# prefix)
-    //
-    // See Java: org.systemsbiology.biofabric.io.AttributeLoader
-    //
-    todo!("Implement attribute loader - see AttributeLoader.java")
+    let mut lines = reader.lines();
+
+    let mut header = None;
+    let mut first_line = true;
+    for line in lines.by_ref() {
+        let line = line?;
+        let line = if first_line { strip_bom(&line) } else { line.as_str() };
+        first_line = false;
+        let trimmed = line.trim_end_matches(['\r', '\n']);
+        if trimmed.trim().is_empty() || trimmed.trim_start().starts_with('#') {
+            continue;
+        }
+        header = Some(trimmed.to_string());
+        break;
+    }
+    let header = header.ok_or(ParseError::UnexpectedEof)?;
+    let column_names: Vec<String> = header.split('\t').skip(1).map(|s| s.to_string()).collect();
+
+    let mut node_attributes = HashMap::new();
+    for line in lines {
+        let line = line?;
+        let trimmed = line.trim_end_matches(['\r', '\n']);
+        if trimmed.trim().is_empty() || trimmed.trim_start().starts_with('#') {
+            continue;
+        }
+        let mut fields = trimmed.split('\t');
+        let Some(node_id) = fields.next() else {
+            continue;
+        };
+        let attrs: HashMap<String, String> = column_names
+            .iter()
+            .zip(fields)
+            .map(|(name, value)| (name.clone(), value.to_string()))
+            .collect();
+        node_attributes.insert(NodeId::new(node_id), attrs);
+    }
+
+    Ok(AttributeTable {
+        node_attributes,
+        column_names,
+    })
 }
 
 /// Load attributes from a string.
 pub fn parse_string(content: &str) -> Result<AttributeTable, ParseError> {
     parse_reader(BufReader::new(content.as_bytes()))
 }
+
+// ============================================================================
+// Cytoscape-style `.na` (Node Attribute) files
+// ============================================================================
+
+/// Parse a Cytoscape-style `.na` (Node Attribute) file.
+///
+/// Format:
+/// ```text
+/// AttributeName
+/// nodeA = some description
+/// nodeB = another description
+/// ```
+///
+/// The first non-empty line is the attribute name applied to every entry
+/// below it. Each following line is `node = value` (whitespace around `=`
+/// is trimmed). Blank lines and `#`-prefixed comments are skipped.
+///
+/// Returns the attribute name and a map of node name to value.
+///
+/// ## References
+///
+/// - Java: `org.systemsbiology.biofabric.io.AttributeLoader` (`.noa`/`.na` support)
+pub fn parse_na_reader<R: Read>(
+    reader: BufReader<R>,
+) -> Result<(String, HashMap<NodeId, String>), ParseError> {
+    let mut lines = reader.lines();
+    let mut attribute_name = None;
+    let mut first_line = true;
+    for line in lines.by_ref() {
+        let line = line?;
+        let line = if first_line { strip_bom(&line) } else { line.as_str() };
+        first_line = false;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        // Java allows a trailing "(class=java.lang.String)" annotation; ignore it.
+        attribute_name = Some(
+            trimmed
+                .split_once('(')
+                .map(|(name, _)| name.trim())
+                .unwrap_or(trimmed)
+                .to_string(),
+        );
+        break;
+    }
+    let attribute_name = attribute_name.ok_or(ParseError::UnexpectedEof)?;
+
+    let mut values = HashMap::new();
+    for line in lines {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let Some((node, value)) = trimmed.split_once('=') else {
+            continue;
+        };
+        values.insert(NodeId::new(node.trim()), value.trim().to_string());
+    }
+
+    Ok((attribute_name, values))
+}
+
+/// Parse a `.na` file from a path.
+pub fn parse_na_file(path: &Path) -> Result<(String, HashMap<NodeId, String>), ParseError> {
+    let file = std::fs::File::open(path)?;
+    parse_na_reader(BufReader::new(file))
+}
+
+/// Parse a `.na` file from a string.
+pub fn parse_na_string(content: &str) -> Result<(String, HashMap<NodeId, String>), ParseError> {
+    parse_na_reader(BufReader::new(content.as_bytes()))
+}
+
+/// Apply parsed `.na` values onto a [`Network`](crate::model::Network) as node attributes.
+///
+/// Nodes named in `values` that don't already exist in the network are skipped
+/// (an `.na` file only annotates existing nodes).
+pub fn apply_na_values(
+    network: &mut crate::model::Network,
+    attribute_name: &str,
+    values: &HashMap<NodeId, String>,
+) {
+    for (node_id, value) in values {
+        network.set_node_attribute(node_id, attribute_name, value.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Link, Network};
+
+    #[test]
+    fn test_parse_na_string() {
+        let content = "description\nnodeA = first node\nnodeB = second node\n";
+        let (name, values) = parse_na_string(content).unwrap();
+
+        assert_eq!(name, "description");
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[&NodeId::new("nodeA")], "first node");
+        assert_eq!(values[&NodeId::new("nodeB")], "second node");
+    }
+
+    #[test]
+    fn test_parse_na_string_skips_comments_and_blank_lines() {
+        let content = "# comment\ndescription\n\n# another comment\nnodeA = value\n";
+        let (name, values) = parse_na_string(content).unwrap();
+
+        assert_eq!(name, "description");
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[&NodeId::new("nodeA")], "value");
+    }
+
+    #[test]
+    fn test_na_parser_tolerates_crlf_and_leading_bom() {
+        let lf = "description\nnodeA = first node\nnodeB = second node\n";
+        let messy = format!("\u{feff}{}", lf.replace('\n', "\r\n"));
+
+        let (name, values) = parse_na_string(&messy).unwrap();
+
+        assert_eq!(name, "description");
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[&NodeId::new("nodeA")], "first node");
+        assert_eq!(values[&NodeId::new("nodeB")], "second node");
+    }
+
+    #[test]
+    fn test_parse_reader_multi_column_table() {
+        let content = "node_id\tcluster\trole\nnodeA\tcluster_1\tkinase\nnodeB\tcluster_2\ttf\nnodeC\tcluster_1\tkinase\n";
+        let table = parse_string(content).unwrap();
+
+        assert_eq!(table.column_names, vec!["cluster", "role"]);
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.get(&NodeId::new("nodeA"), "cluster"), Some("cluster_1"));
+        assert_eq!(table.get(&NodeId::new("nodeA"), "role"), Some("kinase"));
+        assert_eq!(table.get(&NodeId::new("nodeB"), "cluster"), Some("cluster_2"));
+
+        let grouped = table.group_by("cluster");
+        assert_eq!(grouped[&NodeId::new("nodeA")], "cluster_1");
+        assert_eq!(grouped[&NodeId::new("nodeC")], "cluster_1");
+    }
+
+    #[test]
+    fn test_parse_reader_skips_blank_lines_and_comments() {
+        let content = "# comment\nnode_id\tcluster\n\nnodeA\tcluster_1\n# trailing comment\nnodeB\tcluster_2\n";
+        let table = parse_string(content).unwrap();
+
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.get(&NodeId::new("nodeA"), "cluster"), Some("cluster_1"));
+    }
+
+    #[test]
+    fn test_apply_na_values() {
+        let mut network = Network::new();
+        network.add_link(Link::new("nodeA", "nodeB", "r"));
+
+        let (name, values) = parse_na_string("description\nnodeA = hub\n").unwrap();
+        apply_na_values(&mut network, &name, &values);
+
+        assert_eq!(
+            network.get_node(&NodeId::new("nodeA")).unwrap().get_attribute("description"),
+            Some("hub")
+        );
+        assert!(network.get_node(&NodeId::new("nodeB")).unwrap().attributes.is_empty());
+    }
+}
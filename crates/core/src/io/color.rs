@@ -8,7 +8,9 @@
 //! - Java: `org.systemsbiology.biofabric.ui.FabricColorGenerator`
 //! - Java: `org.systemsbiology.biofabric.ui.NamedColor`
 
+use crate::model::NodeId;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// An RGBA color.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -64,6 +66,19 @@ impl FabricColor {
         }
     }
 
+    /// Parse a CSS hex string (`"#RRGGBB"` or `"#RRGGBBAA"`, leading `#`
+    /// optional), the inverse of [`to_hex`](Self::to_hex). Returns `None`
+    /// for anything else, including 3-digit shorthand.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        let channel = |i: usize| u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok();
+        match hex.len() {
+            6 => Some(Self::rgb(channel(0)?, channel(1)?, channel(2)?)),
+            8 => Some(Self::rgba(channel(0)?, channel(1)?, channel(2)?, channel(3)?)),
+            _ => None,
+        }
+    }
+
     /// Convert to `[f32; 4]` normalized to `[0.0, 1.0]` (for shaders).
     pub fn to_f32_array(&self) -> [f32; 4] {
         [
@@ -133,6 +148,29 @@ impl ColorPalette {
         Self { colors }
     }
 
+    /// Map `value` onto a blue→red color ramp, clamped to `[min, max]`.
+    ///
+    /// Used to heat-map a numeric node/link attribute (e.g. expression
+    /// level) onto the fabric instead of the cyclic gene-color palette.
+    /// `value == min` gives pure blue, `value == max` gives pure red, with
+    /// a smooth gradient through purple in between. `min == max` (or
+    /// `min > max`) returns the midpoint color rather than dividing by
+    /// zero.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn ramp(value: f64, min: f64, max: f64) -> FabricColor {
+        let t = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        let r = (t * 255.0).round() as u8;
+        let b = ((1.0 - t) * 255.0).round() as u8;
+        FabricColor::rgb(r, 0, b)
+    }
+
     /// Get a brighter variant of the given color.
     ///
     /// Mirrors `FabricColorGenerator.newColorModel()` with a light factor.
@@ -245,6 +283,63 @@ impl ColorPalette {
     }
 }
 
+/// A stable node-to-color-index mapping shared across multiple renders.
+///
+/// `color_index` on a laid-out node or link is normally derived from its
+/// row or column position, so the same node can land on a different
+/// color in two separately laid-out networks — misleading when the
+/// networks are meant to be compared side by side. Build a
+/// `ColorAssignment` once from the union of node names across every
+/// network being compared, thread it through [`DisplayOptions`] for each
+/// render, and a shared node gets the same color everywhere.
+///
+/// Indices are assigned by sorted name order, so two assignments built
+/// from the same set of names agree regardless of input order.
+///
+/// [`DisplayOptions`]: crate::io::display_options::DisplayOptions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColorAssignment {
+    indices: HashMap<NodeId, usize>,
+}
+
+impl ColorAssignment {
+    /// Build an assignment from the union of node names, in any order.
+    ///
+    /// Duplicate names collapse to a single entry. Indices are dense,
+    /// starting at 0, ordered by sorted name.
+    pub fn from_names<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut sorted: Vec<String> = names.into_iter().map(Into::into).collect();
+        sorted.sort();
+        sorted.dedup();
+        let indices = sorted
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| (NodeId::new(name), i))
+            .collect();
+        Self { indices }
+    }
+
+    /// Look up the stable color index for `node`, if it was included
+    /// when this assignment was built.
+    pub fn color_index(&self, node: &NodeId) -> Option<usize> {
+        self.indices.get(node).copied()
+    }
+
+    /// Number of distinct nodes covered by this assignment.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Whether this assignment covers no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Default gene color palette (32 colors)
 // ---------------------------------------------------------------------------
@@ -485,9 +580,60 @@ mod tests {
         assert!(dark.b < base.b);
     }
 
+    #[test]
+    fn test_ramp_endpoints_are_pure_blue_and_pure_red() {
+        assert_eq!(ColorPalette::ramp(0.0, 0.0, 10.0), FabricColor::rgb(0, 0, 255));
+        assert_eq!(ColorPalette::ramp(10.0, 0.0, 10.0), FabricColor::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_ramp_midpoint_is_even_mix() {
+        let mid = ColorPalette::ramp(5.0, 0.0, 10.0);
+        assert_eq!(mid.r, 128);
+        assert_eq!(mid.b, 128);
+    }
+
+    #[test]
+    fn test_ramp_clamps_out_of_range_values() {
+        assert_eq!(ColorPalette::ramp(-5.0, 0.0, 10.0), FabricColor::rgb(0, 0, 255));
+        assert_eq!(ColorPalette::ramp(15.0, 0.0, 10.0), FabricColor::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_from_hex_round_trips_with_to_hex() {
+        let opaque = FabricColor::rgb(0xFF, 0x66, 0x00);
+        assert_eq!(FabricColor::from_hex(&opaque.to_hex()), Some(opaque));
+
+        let translucent = FabricColor::rgba(0x12, 0x34, 0x56, 0x78);
+        assert_eq!(FabricColor::from_hex(&translucent.to_hex()), Some(translucent));
+
+        assert_eq!(FabricColor::from_hex("not-a-color"), None);
+    }
+
     #[test]
     fn test_alignment_palette_size() {
         let palette = ColorPalette::alignment_palette();
         assert_eq!(palette.len(), 12);
     }
+
+    #[test]
+    fn test_color_assignment_is_stable_regardless_of_input_order() {
+        let a = ColorAssignment::from_names(["alpha", "beta", "gamma"]);
+        let b = ColorAssignment::from_names(["gamma", "alpha", "beta"]);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.color_index(&NodeId::new("alpha")), b.color_index(&NodeId::new("alpha")));
+    }
+
+    #[test]
+    fn test_color_assignment_dedups_names() {
+        let assignment = ColorAssignment::from_names(["dup", "dup", "unique"]);
+        assert_eq!(assignment.len(), 2);
+    }
+
+    #[test]
+    fn test_color_assignment_unknown_node_returns_none() {
+        let assignment = ColorAssignment::from_names(["known"]);
+        assert_eq!(assignment.color_index(&NodeId::new("unknown")), None);
+    }
 }
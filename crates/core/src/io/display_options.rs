@@ -9,8 +9,35 @@
 //! - Java: `org.systemsbiology.biofabric.ui.FabricDisplayOptions`
 //! - Java: `org.systemsbiology.biofabric.ui.FabricDisplayOptionsManager`
 
+use crate::io::color::ColorAssignment;
 use serde::{Deserialize, Serialize};
 
+/// Default alpha for annotation bands.
+fn default_annotation_opacity() -> u8 {
+    34
+}
+
+/// Default alpha for node-zone background tints.
+fn default_node_zone_opacity() -> u8 {
+    30
+}
+
+/// Default alpha for shadow links, matching the Java renderer's fixed
+/// "ghostly" shadow transparency.
+fn default_shadow_alpha() -> u8 {
+    110
+}
+
+/// Default fade multiplier for non-selected elements.
+fn default_selection_dim() -> f32 {
+    0.25
+}
+
+/// Default spacing, in rows/columns, between background gridlines.
+fn default_grid_spacing() -> usize {
+    10
+}
+
 /// Options controlling what is drawn and how.
 ///
 /// All fields have sensible defaults for a first render. Toggle individual
@@ -33,6 +60,16 @@ pub struct DisplayOptions {
     /// structure at the "far" endpoint.
     pub show_shadows: bool,
 
+    /// Alpha (0-255) for shadow link lines, independent of the opaque
+    /// regular links they duplicate.
+    ///
+    /// Lower values make shadows fainter, which helps on dense networks
+    /// where shadow links would otherwise visually overwhelm the real
+    /// edges. `255` makes shadows fully opaque, indistinguishable by
+    /// transparency from regular links.
+    #[serde(default = "default_shadow_alpha")]
+    pub shadow_alpha: u8,
+
     // =====================================================================
     // Annotations
     // =====================================================================
@@ -46,6 +83,23 @@ pub struct DisplayOptions {
     /// Whether to show annotation labels (text inside annotation rectangles).
     pub show_annotation_labels: bool,
 
+    /// Alpha (0-255) for node/link annotation band fills.
+    ///
+    /// None of the `biofabric-render` crate's renderers (full image,
+    /// minimap, node card) draw annotation bands, so rendering does not
+    /// read this field; it is carried with the other display options.
+    #[serde(default = "default_annotation_opacity")]
+    pub annotation_opacity: u8,
+
+    /// Alpha (0-255) for node-zone background tint fills, independent of
+    /// `annotation_opacity` so the two bands can be tuned separately.
+    ///
+    /// Only relevant when [`node_zone_coloring`](Self::node_zone_coloring)
+    /// is enabled. Used as the zone tint's alpha by the full-image, minimap
+    /// and node-card renderers.
+    #[serde(default = "default_node_zone_opacity")]
+    pub node_zone_opacity: u8,
+
     // =====================================================================
     // Labels
     // =====================================================================
@@ -101,9 +155,31 @@ pub struct DisplayOptions {
     /// - Java: `FabricDisplayOptions.DO_NODE_ZONE_COLORING`
     pub node_zone_coloring: bool,
 
+    /// When [`node_zone_coloring`](Self::node_zone_coloring) is enabled,
+    /// whether each node's tint spans the full image width as a
+    /// "swimlane" rather than just its link span (`min_col..max_col`).
+    ///
+    /// Full-width zones make it easier to visually follow a node's row
+    /// across a wide layout, at the cost of tinting space the node
+    /// doesn't actually occupy.
+    #[serde(default)]
+    pub full_width_zones: bool,
+
     /// Selection highlight color.
     pub selection_color: String,
 
+    /// How much to fade non-selected nodes/links when rendering a
+    /// [`Session`](crate::io::session::Session) with a non-empty
+    /// selection, as an alpha multiplier in `[0.0, 1.0]` (0 = invisible,
+    /// 1 = no dimming at all). Selected elements are always drawn at full
+    /// strength. Has no effect when nothing is selected.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default = "default_selection_dim")]
+    pub selection_dim: f32,
+
     // =====================================================================
     // Line widths
     // =====================================================================
@@ -121,6 +197,31 @@ pub struct DisplayOptions {
     /// Line width for selected elements (typically thicker).
     pub selection_line_width: f64,
 
+    /// When `Some((min, max))`, link line thickness scales with
+    /// [`Link::weight`](crate::model::Link::weight) (stronger interactions
+    /// drawn heavier): each link's weight is normalized against the
+    /// min/max weight across the layout and mapped onto `min..=max` screen
+    /// pixels, replacing the flat [`link_line_width`].
+    ///
+    /// Defaults to `None` so existing renders keep their current uniform
+    /// thickness. Links with no weight (`Link.weight == None`), and every
+    /// link when no link in the layout carries a weight, also fall back to
+    /// [`link_line_width`] even when this is `Some`. The minimap overview
+    /// ignores this entirely — its lines are already fixed at one pixel.
+    ///
+    /// [`link_line_width`]: DisplayOptions::link_line_width
+    #[serde(default)]
+    pub weight_thickness_scale: Option<(f64, f64)>,
+
+    /// Whether to antialias line and rectangle edges when rendering.
+    ///
+    /// When `false` (the default), edges are rounded to whole pixels
+    /// before drawing, so output is deterministic and stable across
+    /// platforms. When `true`, edges that fall between pixel boundaries
+    /// blend with coverage-based alpha, smoothing thick lines and
+    /// annotation rectangle borders at the cost of that determinism.
+    pub antialias: bool,
+
     // =====================================================================
     // Overview / minimap
     // =====================================================================
@@ -181,14 +282,222 @@ pub struct DisplayOptions {
     /// the writer will emit the `shadows` attribute regardless of value.
     #[serde(default)]
     pub shadows_explicit: bool,
+
+    // =====================================================================
+    // Cross-network color consistency
+    // =====================================================================
+
+    /// A shared node-to-color mapping, for rendering several related
+    /// networks with consistent colors.
+    ///
+    /// Without this, `color_index` is assigned purely from a node's row
+    /// position in its own layout, so the same node can get a different
+    /// color in each network's render. Build one [`ColorAssignment`] from
+    /// the union of node names across all the networks being compared
+    /// and set it here on every `DisplayOptions` passed to those renders.
+    #[serde(default)]
+    pub color_assignment: Option<ColorAssignment>,
+
+    /// When `Some`, a node's color comes from running its value for this
+    /// attribute (parsed as a number) through [`ColorPalette::ramp`],
+    /// instead of the cyclic gene-color palette — useful for heat-mapping
+    /// expression levels or other numeric attributes onto the fabric.
+    ///
+    /// The attribute is looked up on [`NetworkLayout::node_attributes`],
+    /// normalized against the min/max value present across the layout.
+    /// A node missing the attribute, or whose value doesn't parse as a
+    /// number, falls back to the cyclic palette. `None` (the default)
+    /// keeps the existing cyclic-palette behavior for every node.
+    ///
+    /// [`ColorPalette::ramp`]: crate::io::color::ColorPalette::ramp
+    /// [`NetworkLayout::node_attributes`]: crate::layout::result::NetworkLayout::node_attributes
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub node_value_attribute: Option<String>,
+
+    // =====================================================================
+    // Layout style
+    // =====================================================================
+
+    /// Whether to draw the usual horizontal/vertical fabric or a radial
+    /// layout with nodes placed around a circle and links as chords.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub layout_style: LayoutStyle,
+
+    // =====================================================================
+    // Cropping
+    // =====================================================================
+
+    /// Restrict rendering to a rectangular window of rows and columns,
+    /// instead of the whole layout — for exporting a tight crop of a huge
+    /// fabric. `None` renders everything, as before.
+    ///
+    /// Only affects [`LayoutStyle::Fabric`] rendering; radial rendering
+    /// ignores it, since "row/column window" doesn't have a meaningful
+    /// analogue once nodes are placed around a circle.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub crop: Option<CropRegion>,
+
+    // =====================================================================
+    // Drain zones
+    // =====================================================================
+
+    /// Whether to overlay a contrasting tint over each node's drain-zone
+    /// column span, showing where that node "drains" its incident edges.
+    ///
+    /// Uses [`NodeLayout::plain_drain_zones`](crate::layout::result::NodeLayout::plain_drain_zones)
+    /// when `show_shadows` is `false` and
+    /// [`NodeLayout::shadow_drain_zones`](crate::layout::result::NodeLayout::shadow_drain_zones)
+    /// otherwise, falling back to
+    /// [`NetworkLayout::compute_drain_zones`](crate::layout::result::NetworkLayout::compute_drain_zones)
+    /// when a layout doesn't carry pre-computed zones.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub show_drain_zones: bool,
+
+    // =====================================================================
+    // Legend
+    // =====================================================================
+
+    /// Whether to reserve a strip alongside the render and draw a color
+    /// swatch for every distinct relation present in the layout's links
+    /// that has a known [`alignment_relation_color`](crate::alignment::alignment_relation_color)
+    /// (e.g. `"P"`, `"pBp"`, `"pRr"` on a merged alignment layout).
+    ///
+    /// A layout with no alignment relations draws no swatches and reserves
+    /// no strip space, so this is a no-op on ordinary (non-alignment)
+    /// layouts even when left on.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub draw_legend: bool,
+
+    /// Which side of the image the legend strip is drawn on, when
+    /// [`draw_legend`](Self::draw_legend) is enabled.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub legend_side: LegendSide,
+
+    // =====================================================================
+    // Grid
+    // =====================================================================
+
+    /// Whether to draw a faint background grid every
+    /// [`grid_spacing`](Self::grid_spacing) rows/columns, to help readers
+    /// locate a node's column index in big fabrics. Drawn beneath node and
+    /// link geometry, so it only shows through in empty background area.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub show_grid: bool,
+
+    /// Spacing, in rows/columns, between gridlines when
+    /// [`show_grid`](Self::show_grid) is enabled.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default = "default_grid_spacing")]
+    pub grid_spacing: usize,
+
+    // =====================================================================
+    // Arrows
+    // =====================================================================
+
+    /// Whether to draw a small arrowhead at the target end of directed
+    /// links (`LinkLayout::directed == Some(true)`, as set by
+    /// [`SetLayout`](crate::layout::SetLayout)). Undirected links are
+    /// drawn the same either way.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub show_arrows: bool,
+}
+
+/// Which side of a rendered image a legend strip is attached to.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LegendSide {
+    /// Reserve space to the right of the main image.
+    #[default]
+    Right,
+    /// Reserve space below the main image.
+    Bottom,
+}
+
+/// A rectangular window of rows and columns to render.
+///
+/// `min_row`/`max_row` and `min_col`/`max_col` are inclusive layout
+/// coordinates — the same row/column numbering as
+/// [`NodeLayout::row`](crate::layout::result::NodeLayout) and
+/// [`LinkLayout::column`](crate::layout::result::LinkLayout) — not pixels.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CropRegion {
+    /// First row to render, inclusive.
+    pub min_row: usize,
+    /// Last row to render, inclusive.
+    pub max_row: usize,
+    /// First column to render, inclusive.
+    pub min_col: usize,
+    /// Last column to render, inclusive.
+    pub max_col: usize,
+}
+
+/// How a [`NetworkLayout`](crate::layout::result::NetworkLayout) is drawn.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LayoutStyle {
+    /// The standard BioFabric layout: nodes as horizontal lines (rows),
+    /// links as vertical lines (columns).
+    #[default]
+    Fabric,
+    /// Nodes placed around a circle by row order, links drawn as chords.
+    /// See [`NetworkLayout::radial_coordinates`](crate::layout::result::NetworkLayout::radial_coordinates).
+    Radial,
 }
 
 impl Default for DisplayOptions {
     fn default() -> Self {
         Self {
             show_shadows: true,
+            shadow_alpha: default_shadow_alpha(),
             show_annotations: true,
             show_annotation_labels: true,
+            annotation_opacity: default_annotation_opacity(),
+            node_zone_opacity: default_node_zone_opacity(),
             show_node_labels: true,
             show_link_labels: false,
             label_min_zoom: 4.0,
@@ -196,15 +505,29 @@ impl Default for DisplayOptions {
             min_link_span_px: 0.5,
             background_color: "#FFFFFF".to_string(),
             node_zone_coloring: false,
+            full_width_zones: false,
             selection_color: "#FFFF00".to_string(),
+            selection_dim: default_selection_dim(),
             node_line_width: 2.0,
             link_line_width: 1.0,
             selection_line_width: 3.0,
+            weight_thickness_scale: None,
+            antialias: false,
             show_overview: true,
             node_lighter_level: 0.43,
             link_darker_level: 0.43,
             min_drain_zone: 1,
             shadows_explicit: false,
+            color_assignment: None,
+            node_value_attribute: None,
+            layout_style: LayoutStyle::Fabric,
+            crop: None,
+            show_drain_zones: false,
+            draw_legend: false,
+            legend_side: LegendSide::Right,
+            show_grid: false,
+            grid_spacing: default_grid_spacing(),
+            show_arrows: false,
         }
     }
 }
@@ -217,8 +540,11 @@ impl DisplayOptions {
     pub fn for_image_export(show_shadows: bool) -> Self {
         Self {
             show_shadows,
+            shadow_alpha: default_shadow_alpha(),
             show_annotations: true,
             show_annotation_labels: true,
+            annotation_opacity: default_annotation_opacity(),
+            node_zone_opacity: default_node_zone_opacity(),
             show_node_labels: true,
             show_link_labels: false,
             label_min_zoom: 0.0, // Always show labels in export
@@ -226,15 +552,29 @@ impl DisplayOptions {
             min_link_span_px: 0.25,
             background_color: "#FFFFFF".to_string(),
             node_zone_coloring: false,
+            full_width_zones: false,
             selection_color: "#FFFF00".to_string(),
+            selection_dim: default_selection_dim(),
             node_line_width: 2.0,
             link_line_width: 1.0,
             selection_line_width: 3.0,
+            weight_thickness_scale: None,
+            antialias: false,
             show_overview: false, // No minimap in image export
             node_lighter_level: 0.43,
             link_darker_level: 0.43,
             min_drain_zone: 1,
             shadows_explicit: false,
+            color_assignment: None,
+            node_value_attribute: None,
+            layout_style: LayoutStyle::Fabric,
+            crop: None,
+            show_drain_zones: false,
+            draw_legend: false,
+            legend_side: LegendSide::Right,
+            show_grid: false,
+            grid_spacing: default_grid_spacing(),
+            show_arrows: false,
         }
     }
 
@@ -242,8 +582,11 @@ impl DisplayOptions {
     pub fn minimal() -> Self {
         Self {
             show_shadows: false,
+            shadow_alpha: default_shadow_alpha(),
             show_annotations: false,
             show_annotation_labels: false,
+            annotation_opacity: default_annotation_opacity(),
+            node_zone_opacity: default_node_zone_opacity(),
             show_node_labels: false,
             show_link_labels: false,
             label_min_zoom: f64::MAX,
@@ -251,15 +594,29 @@ impl DisplayOptions {
             min_link_span_px: 1.0,
             background_color: "#FFFFFF".to_string(),
             node_zone_coloring: false,
+            full_width_zones: false,
             selection_color: "#FFFF00".to_string(),
+            selection_dim: default_selection_dim(),
             node_line_width: 1.0,
             link_line_width: 1.0,
             selection_line_width: 2.0,
+            weight_thickness_scale: None,
+            antialias: false,
             show_overview: false,
             node_lighter_level: 0.43,
             link_darker_level: 0.43,
             min_drain_zone: 1,
             shadows_explicit: false,
+            color_assignment: None,
+            node_value_attribute: None,
+            layout_style: LayoutStyle::Fabric,
+            crop: None,
+            show_drain_zones: false,
+            draw_legend: false,
+            legend_side: LegendSide::Right,
+            show_grid: false,
+            grid_spacing: default_grid_spacing(),
+            show_arrows: false,
         }
     }
 }
@@ -0,0 +1,99 @@
+//! DOT/Graphviz export for quick visual sanity-checks.
+//!
+//! This is an export-only format: BioFabric has no DOT parser, since DOT's
+//! layout-agnostic attribute grammar (rank, subgraph, style, ...) is far
+//! richer than anything BioFabric needs to read back in. [`write_network`]
+//! emits a plain `graph`/`digraph` with one node statement per node and one
+//! edge statement per non-shadow link, so the result can be piped straight
+//! into `dot -Tpng` for a rough look at a network before committing to a
+//! full fabric layout.
+//!
+//! ## References
+//!
+//! - DOT language: <https://graphviz.org/doc/info/lang.html>
+
+use super::ParseError;
+use crate::model::Network;
+use std::io::Write;
+
+/// Write `network` as a DOT graph.
+///
+/// The graph is emitted as `digraph` if any link has `directed == Some(true)`,
+/// and as an undirected `graph` otherwise. Shadow links are skipped. Each
+/// edge is labeled with its `relation`.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Io`] if writing to `w` fails.
+pub fn write_network<W: Write>(network: &Network, mut w: W) -> Result<(), ParseError> {
+    let directed = network.links().any(|l| l.directed == Some(true));
+    let (keyword, edge_op) = if directed {
+        ("digraph", "->")
+    } else {
+        ("graph", "--")
+    };
+
+    writeln!(w, "{} G {{", keyword)?;
+
+    for node in network.nodes() {
+        writeln!(w, "  {:?};", node.id.as_str())?;
+    }
+
+    for link in network.links() {
+        if link.is_shadow {
+            continue;
+        }
+        writeln!(
+            w,
+            "  {:?} {} {:?} [label={:?}];",
+            link.source.as_str(),
+            edge_op,
+            link.target.as_str(),
+            link.relation
+        )?;
+    }
+
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+/// Write `network` as a DOT graph to a file.
+pub fn write_file(network: &Network, path: &std::path::Path) -> Result<(), ParseError> {
+    let file = std::fs::File::create(path)?;
+    write_network(network, std::io::BufWriter::new(file))
+}
+
+/// Write `network` as a DOT graph to a string.
+pub fn write_string(network: &Network) -> Result<String, ParseError> {
+    let mut buf = Vec::new();
+    write_network(network, &mut buf)?;
+    String::from_utf8(buf).map_err(|e| ParseError::InvalidFormat {
+        line: 0,
+        message: format!("UTF-8 encoding error: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::factory::FabricFactory;
+    use std::path::Path;
+
+    #[test]
+    fn test_multi_relation_sif_emits_one_node_and_edge_statement_per_entry() {
+        let path = Path::new("../../tests/parity/networks/sif/multi_relation.sif");
+        let network = FabricFactory::load_network(path).unwrap();
+
+        let dot = write_string(&network).unwrap();
+
+        assert!(dot.starts_with("graph G {"));
+        for node in network.nodes() {
+            let needle = format!("{:?};", node.id.as_str());
+            assert!(dot.contains(&needle), "missing node statement for {}", node.id);
+        }
+
+        let edge_count = dot.lines().filter(|l| l.contains("--")).count();
+        let non_shadow_links = network.links().filter(|l| !l.is_shadow).count();
+        assert_eq!(edge_count, non_shadow_links);
+    }
+}
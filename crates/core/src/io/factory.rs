@@ -7,7 +7,7 @@
 //!
 //! - Java: `FabricFactory`, `BuildDataImpl`, `BuildExtractorImpl`
 
-use crate::io::{align, gw, json, sif, xml, ParseError};
+use crate::io::{align, dot, gw, json, pajek, sif, xml, ParseError};
 use crate::io::session::Session;
 use crate::model::Network;
 use std::path::Path;
@@ -25,6 +25,8 @@ pub enum InputFormat {
     Xml,
     /// Alignment mapping (.align)
     Align,
+    /// Pajek network (.net)
+    Pajek,
 }
 
 /// Supported output formats.
@@ -38,6 +40,8 @@ pub enum OutputFormat {
     Json,
     /// BioFabric XML session (.bif, .xml)
     Xml,
+    /// DOT/Graphviz (.dot). Write-only.
+    Dot,
 }
 
 /// Factory for parsing and writing networks and sessions.
@@ -56,6 +60,7 @@ impl FabricFactory {
     /// - `.json` → JSON
     /// - `.bif`, `.xml` → BioFabric XML session
     /// - `.align` → Alignment mapping
+    /// - `.net` → Pajek network
     pub fn detect_format(path: &Path) -> Option<InputFormat> {
         match path.extension()?.to_str()? {
             "sif" => Some(InputFormat::Sif),
@@ -63,6 +68,7 @@ impl FabricFactory {
             "json" => Some(InputFormat::Json),
             "bif" | "xml" => Some(InputFormat::Xml),
             "align" => Some(InputFormat::Align),
+            "net" => Some(InputFormat::Pajek),
             _ => None,
         }
     }
@@ -74,6 +80,7 @@ impl FabricFactory {
             "gw" => Some(OutputFormat::Gw),
             "json" => Some(OutputFormat::Json),
             "bif" | "xml" => Some(OutputFormat::Xml),
+            "dot" => Some(OutputFormat::Dot),
             _ => None,
         }
     }
@@ -83,6 +90,7 @@ impl FabricFactory {
         !matches!(format, InputFormat::Align)
     }
 
+
     // =====================================================================
     // Network loading
     // =====================================================================
@@ -111,14 +119,9 @@ impl FabricFactory {
         match format {
             InputFormat::Sif => sif::parse_file(path),
             InputFormat::Gw => gw::parse_file(path),
-            InputFormat::Json => {
-                let data = std::fs::read_to_string(path)?;
-                json::network_from_json(&data).map_err(|e| ParseError::InvalidFormat {
-                    line: 0,
-                    message: e.to_string(),
-                })
-            }
+            InputFormat::Json => json::parse_file(path),
             InputFormat::Xml => xml::read_network_only(path),
+            InputFormat::Pajek => pajek::parse_file(path),
             InputFormat::Align => Err(ParseError::InvalidFormat {
                 line: 0,
                 message: "Alignment files do not contain a full network. \
@@ -133,16 +136,12 @@ impl FabricFactory {
         match format {
             InputFormat::Sif => sif::parse_string(data),
             InputFormat::Gw => gw::parse_string(data),
-            InputFormat::Json => json::network_from_json(data).map_err(|e| {
-                ParseError::InvalidFormat {
-                    line: 0,
-                    message: e.to_string(),
-                }
-            }),
+            InputFormat::Json => json::parse_string(data),
             InputFormat::Xml => Err(ParseError::InvalidFormat {
                 line: 0,
                 message: "XML parsing from string not supported; use a file path".to_string(),
             }),
+            InputFormat::Pajek => pajek::parse_string(data),
             InputFormat::Align => Err(ParseError::InvalidFormat {
                 line: 0,
                 message: "Alignment files do not contain a full network".to_string(),
@@ -193,6 +192,7 @@ impl FabricFactory {
                 let session = Session::from_network(network.clone());
                 xml::write_session(&session, path)
             }
+            OutputFormat::Dot => dot::write_file(network, path),
         }
     }
 
@@ -215,6 +215,7 @@ impl FabricFactory {
                 message: "XML string output not supported; use write_session() with a file path"
                     .to_string(),
             }),
+            OutputFormat::Dot => dot::write_string(network),
         }
     }
 
@@ -0,0 +1,116 @@
+//! GraphML export with computed layout coordinates.
+//!
+//! This crate has no topology-only GraphML writer yet — [`write_layout`]
+//! always embeds the node positions from a computed [`NetworkLayout`] as
+//! yFiles-style `<y:Geometry>` data, so a BioFabric layout opens directly
+//! in yEd (or any other yFiles-GraphML-aware tool) with nodes already
+//! placed at their BioFabric row/column position rather than needing a
+//! fresh auto-layout pass.
+//!
+//! ## References
+//!
+//! - GraphML: <http://graphml.graphdrawing.org/>
+//! - yFiles GraphML extensions: <https://docs.yworks.com/yfileshtml/#/dguide/graphml>
+
+use super::ParseError;
+use crate::layout::result::NetworkLayout;
+use crate::model::Network;
+use std::io::Write;
+
+/// Write `network`'s nodes and links as GraphML, with each node's position
+/// taken from `layout`: `x` is the node's leftmost column, `y` is its row.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Io`] if writing to `w` fails.
+pub fn write_layout<W: Write>(
+    network: &Network,
+    layout: &NetworkLayout,
+    mut w: W,
+) -> Result<(), ParseError> {
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        w,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns" xmlns:y="http://www.yworks.com/xml/graphml">"#
+    )?;
+    writeln!(w, r#"  <key id="d0" for="node" yfiles.type="nodegraphics"/>"#)?;
+    writeln!(w, r#"  <graph id="G" edgedefault="directed">"#)?;
+
+    for (node_id, nl) in layout.iter_nodes() {
+        writeln!(w, r#"    <node id="{}">"#, escape(node_id.as_str()))?;
+        writeln!(w, r#"      <data key="d0">"#)?;
+        writeln!(
+            w,
+            r#"        <y:ShapeNode><y:Geometry x="{}" y="{}"/></y:ShapeNode>"#,
+            nl.min_col, nl.row
+        )?;
+        writeln!(w, "      </data>")?;
+        writeln!(w, "    </node>")?;
+    }
+
+    for (i, link) in network.links().enumerate() {
+        if link.is_shadow {
+            continue;
+        }
+        writeln!(
+            w,
+            r#"    <edge id="e{}" source="{}" target="{}"/>"#,
+            i,
+            escape(link.source.as_str()),
+            escape(link.target.as_str())
+        )?;
+    }
+
+    writeln!(w, "  </graph>")?;
+    writeln!(w, "</graphml>")?;
+    Ok(())
+}
+
+/// Escape the handful of characters GraphML attribute/text content can't
+/// contain literally.
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    #[test]
+    fn test_write_layout_emits_one_geometry_per_node_with_correct_row_and_column() {
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "pp"));
+        network.add_link(Link::new("hub", "leafB", "pp"));
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let mut buf = Vec::new();
+        write_layout(&network, &layout, &mut buf).unwrap();
+        let xml = String::from_utf8(buf).unwrap();
+
+        let geometry_count = xml.matches("<y:Geometry").count();
+        assert_eq!(geometry_count, layout.iter_nodes().count());
+
+        for (node_id, nl) in layout.iter_nodes() {
+            let node_marker = format!(r#"<node id="{}">"#, node_id.as_str());
+            assert!(xml.contains(&node_marker), "missing node entry for {node_id}");
+
+            let geometry = format!(r#"<y:Geometry x="{}" y="{}"/>"#, nl.min_col, nl.row);
+            assert!(
+                xml.contains(&geometry),
+                "missing geometry for {node_id} (expected x={}, y={}): {xml}",
+                nl.min_col,
+                nl.row
+            );
+        }
+    }
+}
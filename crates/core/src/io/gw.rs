@@ -35,7 +35,7 @@
 //! - LEDA GW format: <http://www.algorithmic-solutions.info/leda_manual/GW.html>
 //! - Java implementation: `org.systemsbiology.biofabric.io.GWImportLoader`
 
-use super::{ImportStats, ParseError};
+use super::{strip_bom, DuplicatePolicy, ImportStats, ParseError, ParseOptions};
 use crate::model::Network;
 use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
@@ -52,8 +52,15 @@ const GW_HEADER: &str = "LEDA.GRAPH";
 /// * `Ok(Network)` - The parsed network
 /// * `Err(ParseError)` - If the file could not be parsed
 pub fn parse_file(path: &Path) -> Result<Network, ParseError> {
-    let file = std::fs::File::open(path)?;
-    parse_reader(BufReader::new(file))
+    parse_file_with_options(path, &ParseOptions::default())
+}
+
+/// Parse a GW file from a path, with explicit [`ParseOptions`] (e.g. to
+/// keep or error on duplicate links instead of silently skipping them).
+pub fn parse_file_with_options(path: &Path, options: &ParseOptions) -> Result<Network, ParseError> {
+    let file = std::fs::File::open(path).map_err(|e| ParseError::from(e).with_path(path))?;
+    let (network, _stats) = parse_reader_with_stats_and_options(BufReader::new(file), options).map_err(|e| e.with_path(path))?;
+    Ok(network)
 }
 
 /// Parse a GW file from any reader.
@@ -79,23 +86,53 @@ pub fn parse_reader<R: Read>(reader: BufReader<R>) -> Result<Network, ParseError
 /// * `Err(ParseError)` - If the file could not be parsed
 pub fn parse_reader_with_stats<R: Read>(
     reader: BufReader<R>,
+) -> Result<(Network, ImportStats), ParseError> {
+    parse_reader_with_stats_and_options(reader, &ParseOptions::default())
+}
+
+/// Parse a GW file and return import statistics, with explicit
+/// [`ParseOptions`] controlling duplicate-link handling.
+///
+/// # Arguments
+/// * `reader` - Buffered reader for the input
+/// * `options` - Duplicate-link policy to apply
+///
+/// # Returns
+/// * `Ok((Network, ImportStats))` - The parsed network and statistics
+/// * `Err(ParseError)` - If the content could not be parsed
+pub fn parse_reader_with_stats_and_options<R: Read>(
+    reader: BufReader<R>,
+    options: &ParseOptions,
 ) -> Result<(Network, ImportStats), ParseError> {
     use crate::model::Link;
 
     let mut stats = ImportStats::new();
     let mut lines_iter = reader.lines();
     let mut line_num: usize = 0;
-
-    // Helper to read the next non-empty line
-    let next_line = |lines_iter: &mut std::io::Lines<BufReader<R>>, line_num: &mut usize| -> Result<String, ParseError> {
+    let mut source_comments: Vec<String> = Vec::new();
+
+    // Helper to read the next non-empty, non-comment line. LEDA allows
+    // `#`-prefixed comment lines anywhere between the structural lines; we
+    // skip them for parsing purposes but keep the text around so it can be
+    // round-tripped for provenance (see `NetworkMetadata::source_comments`).
+    let next_line = |lines_iter: &mut std::io::Lines<BufReader<R>>,
+                      line_num: &mut usize,
+                      source_comments: &mut Vec<String>|
+     -> Result<String, ParseError> {
         loop {
             match lines_iter.next() {
                 Some(Ok(line)) => {
                     *line_num += 1;
+                    let line = if *line_num == 1 { strip_bom(&line) } else { line.as_str() };
                     let trimmed = line.trim().to_string();
-                    if !trimmed.is_empty() {
-                        return Ok(trimmed);
+                    if trimmed.is_empty() {
+                        continue;
                     }
+                    if let Some(comment) = trimmed.strip_prefix('#') {
+                        source_comments.push(comment.trim().to_string());
+                        continue;
+                    }
+                    return Ok(trimmed);
                 }
                 Some(Err(e)) => return Err(ParseError::Io(e)),
                 None => return Err(ParseError::UnexpectedEof),
@@ -104,7 +141,7 @@ pub fn parse_reader_with_stats<R: Read>(
     };
 
     // Line 1: header
-    let header = next_line(&mut lines_iter, &mut line_num)?;
+    let header = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
     if header != GW_HEADER {
         return Err(ParseError::InvalidHeader(format!(
             "Expected '{}', got '{}'",
@@ -113,13 +150,13 @@ pub fn parse_reader_with_stats<R: Read>(
     }
 
     // Line 2: node type (skip)
-    let _node_type = next_line(&mut lines_iter, &mut line_num)?;
+    let _node_type = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
 
     // Line 3: edge type (skip)
-    let _edge_type = next_line(&mut lines_iter, &mut line_num)?;
+    let _edge_type = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
 
     // Line 4: direction flag
-    let dir_str = next_line(&mut lines_iter, &mut line_num)?;
+    let dir_str = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
     let dir_flag: i32 = dir_str.parse().map_err(|_| ParseError::InvalidFormat {
         line: line_num,
         message: format!("Invalid direction flag: {}", dir_str),
@@ -127,7 +164,7 @@ pub fn parse_reader_with_stats<R: Read>(
     let is_directed = dir_flag == -1;
 
     // Line 5: node count
-    let node_count_str = next_line(&mut lines_iter, &mut line_num)?;
+    let node_count_str = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
     let node_count: usize = node_count_str.parse().map_err(|_| ParseError::InvalidFormat {
         line: line_num,
         message: format!("Invalid node count: {}", node_count_str),
@@ -136,13 +173,13 @@ pub fn parse_reader_with_stats<R: Read>(
     // Read N node labels
     let mut node_labels: Vec<String> = Vec::with_capacity(node_count);
     for _ in 0..node_count {
-        let label_line = next_line(&mut lines_iter, &mut line_num)?;
+        let label_line = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
         let label = extract_label(&label_line).unwrap_or("").to_string();
         node_labels.push(label);
     }
 
     // Read edge count
-    let edge_count_str = next_line(&mut lines_iter, &mut line_num)?;
+    let edge_count_str = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
     let edge_count: usize = edge_count_str.parse().map_err(|_| ParseError::InvalidFormat {
         line: line_num,
         message: format!("Invalid edge count: {}", edge_count_str),
@@ -151,10 +188,11 @@ pub fn parse_reader_with_stats<R: Read>(
     // Track which nodes appear in edges
     let mut used_nodes = std::collections::HashSet::new();
     let mut links: Vec<Link> = Vec::new();
+    let mut seen_edges: std::collections::HashSet<(usize, usize, String)> = std::collections::HashSet::new();
 
     // Read M edges
     for _ in 0..edge_count {
-        let edge_line = next_line(&mut lines_iter, &mut line_num)?;
+        let edge_line = next_line(&mut lines_iter, &mut line_num, &mut source_comments)?;
         let tokens: Vec<&str> = edge_line.split_whitespace().collect();
         if tokens.len() < 4 {
             stats.bad_lines.push(edge_line);
@@ -176,15 +214,54 @@ pub fn parse_reader_with_stats<R: Read>(
         let relation = extract_label(&label_part).unwrap_or("");
         let relation = if relation.is_empty() { "default" } else { relation };
 
-        // Indices are 1-based
-        if src_idx < 1 || src_idx > node_count || tgt_idx < 1 || tgt_idx > node_count {
-            stats.bad_lines.push(edge_line);
-            continue;
+        // Indices are 1-based. A malformed file may reference an index the
+        // node section never declared; report it instead of panicking on
+        // the out-of-bounds slice index below or silently dropping the edge.
+        if src_idx < 1 || src_idx > node_count {
+            return Err(ParseError::InvalidFormat {
+                line: line_num,
+                message: format!(
+                    "Edge references out-of-range node index {} ({} nodes declared)",
+                    src_idx, node_count
+                ),
+            });
+        }
+        if tgt_idx < 1 || tgt_idx > node_count {
+            return Err(ParseError::InvalidFormat {
+                line: line_num,
+                message: format!(
+                    "Edge references out-of-range node index {} ({} nodes declared)",
+                    tgt_idx, node_count
+                ),
+            });
         }
 
         let source = &node_labels[src_idx - 1];
         let target = &node_labels[tgt_idx - 1];
 
+        let dedup_key = if is_directed {
+            (src_idx, tgt_idx, relation.to_string())
+        } else {
+            let (a, b) = if src_idx <= tgt_idx { (src_idx, tgt_idx) } else { (tgt_idx, src_idx) };
+            (a, b, relation.to_string())
+        };
+        if !seen_edges.insert(dedup_key) {
+            match options.duplicate_policy {
+                DuplicatePolicy::Skip => {
+                    stats.duplicate_links += 1;
+                    continue;
+                }
+                DuplicatePolicy::Error => {
+                    return Err(ParseError::DuplicateLink {
+                        link_source: source.clone(),
+                        relation: relation.to_string(),
+                        link_target: target.clone(),
+                    });
+                }
+                DuplicatePolicy::Keep => {}
+            }
+        }
+
         used_nodes.insert(src_idx - 1);
         used_nodes.insert(tgt_idx - 1);
 
@@ -197,12 +274,11 @@ pub fn parse_reader_with_stats<R: Read>(
         links.push(link.clone());
         stats.link_count += 1;
 
-        // Add inline shadow if not self-loop
+        // Add inline shadow if not self-loop (to_shadow() already skips
+        // directed links, since GW graphs are either all-directed or
+        // all-undirected).
         if !is_feedback {
-            if let Some(mut shadow) = link.to_shadow() {
-                if is_directed {
-                    shadow.directed = Some(true);
-                }
+            if let Some(shadow) = link.to_shadow() {
                 links.push(shadow);
                 stats.shadow_link_count += 1;
             }
@@ -223,6 +299,7 @@ pub fn parse_reader_with_stats<R: Read>(
     }
 
     network.metadata.is_directed = is_directed;
+    network.metadata.source_comments = source_comments;
 
     stats.node_count = network.node_count();
     stats.lone_node_count = network.lone_nodes().len();
@@ -338,6 +415,101 @@ mod tests {
         assert_eq!(extract_label("  |{spaced}|  "), Some("spaced"));
     }
 
+    #[test]
+    fn test_utf8_bom_and_crlf_on_header_line_are_tolerated() {
+        let content = "LEDA.GRAPH\nstring\nshort\n-2\n3\n|{A}|\n|{B}|\n|{C}|\n1\n1 2 0 |{rel}|\n";
+        let with_bom_and_crlf = format!("\u{feff}{}", content.replace('\n', "\r\n"));
+
+        let plain = parse_string(content).unwrap();
+        let messy = parse_string(&with_bom_and_crlf).unwrap();
+
+        assert_eq!(plain.node_count(), messy.node_count());
+        assert_eq!(plain.link_count(), messy.link_count());
+    }
+
+    #[test]
+    fn test_out_of_range_edge_index_is_a_parse_error() {
+        // Graph declares 3 nodes (valid indices 1..=3), but the edge
+        // references index 4 (N+1).
+        let content = "LEDA.GRAPH\nstring\nshort\n-2\n3\n|{A}|\n|{B}|\n|{C}|\n1\n1 4 0 |{rel}|\n";
+
+        let err = parse_string(content).unwrap_err();
+        match err {
+            ParseError::InvalidFormat { line, message } => {
+                assert_eq!(line, 10);
+                assert!(message.contains('4'), "message should mention the offending index: {message}");
+            }
+            other => panic!("expected ParseError::InvalidFormat, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_file_error_includes_path_and_line() {
+        let content = "LEDA.GRAPH\nstring\nshort\n-2\n3\n|{A}|\n|{B}|\n|{C}|\n1\n1 4 0 |{rel}|\n";
+        let dir = std::env::temp_dir();
+        let path = dir.join("biofabric_test_bad_graph.gw");
+        std::fs::write(&path, content).unwrap();
+
+        let err = parse_file(&path).unwrap_err();
+        let message = err.to_string();
+
+        std::fs::remove_file(&path).ok();
+
+        assert!(
+            message.contains(path.to_str().unwrap()),
+            "error should mention the file path: {message}"
+        );
+        assert!(
+            message.contains("line 10"),
+            "error should mention the failing line: {message}"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_policy_skip_drops_the_repeat_and_counts_it() {
+        let content = "LEDA.GRAPH\nstring\nshort\n-2\n2\n|{A}|\n|{B}|\n2\n1 2 0 |{pp}|\n1 2 0 |{pp}|\n";
+        let options = ParseOptions { duplicate_policy: DuplicatePolicy::Skip };
+        let (network, stats) =
+            parse_reader_with_stats_and_options(BufReader::new(content.as_bytes()), &options).unwrap();
+
+        assert_eq!(stats.link_count, 1);
+        assert_eq!(stats.duplicate_links, 1);
+        assert_eq!(network.links_slice().iter().filter(|l| !l.is_shadow).count(), 1);
+    }
+
+    #[test]
+    fn test_duplicate_policy_keep_retains_the_parallel_edge() {
+        let content = "LEDA.GRAPH\nstring\nshort\n-2\n2\n|{A}|\n|{B}|\n2\n1 2 0 |{pp}|\n1 2 0 |{pp}|\n";
+        let options = ParseOptions { duplicate_policy: DuplicatePolicy::Keep };
+        let (network, stats) =
+            parse_reader_with_stats_and_options(BufReader::new(content.as_bytes()), &options).unwrap();
+
+        assert_eq!(stats.link_count, 2);
+        assert_eq!(network.links_slice().iter().filter(|l| !l.is_shadow).count(), 2);
+    }
+
+    #[test]
+    fn test_comment_lines_are_collected_into_source_comments_and_skipped() {
+        let content = "# exported from LEDA\nLEDA.GRAPH\nstring\nshort\n-2\n# two nodes\n2\n|{A}|\n|{B}|\n1\n1 2 0 |{pp}|\n";
+        let network = parse_string(content).unwrap();
+
+        assert_eq!(
+            network.metadata.source_comments,
+            vec!["exported from LEDA".to_string(), "two nodes".to_string()]
+        );
+        assert_eq!(network.node_count(), 2);
+        assert_eq!(network.links_slice().iter().filter(|l| !l.is_shadow).count(), 1);
+    }
+
+    #[test]
+    fn test_duplicate_policy_error_fails_the_parse() {
+        let content = "LEDA.GRAPH\nstring\nshort\n-2\n2\n|{A}|\n|{B}|\n2\n1 2 0 |{pp}|\n1 2 0 |{pp}|\n";
+        let options = ParseOptions { duplicate_policy: DuplicatePolicy::Error };
+        let result = parse_reader_with_stats_and_options(BufReader::new(content.as_bytes()), &options);
+
+        assert!(matches!(result, Err(ParseError::DuplicateLink { .. })));
+    }
+
     // TODO: Add more tests once parse_string is implemented
     //
     // #[test]
@@ -1,10 +1,31 @@
 //! JSON import / export for BioFabric networks.
 //!
-//! Provides round-trip serialization of [`Network`] and [`NetworkLayout`]
-//! to JSON, suitable for saving / loading sessions and for passing data
-//! across the WASM boundary.
+//! Provides round-trip serialization of [`Network`] and [`Session`]
+//! (network + layout + display options) to JSON, suitable for saving /
+//! loading sessions and for passing data across the WASM boundary.
+//!
+//! Two JSON shapes are accepted on read:
+//!
+//! - **Network-only**: a bare serialized [`Network`], as produced by
+//!   [`network_to_json`]/[`write_network`]. This is what `biofabric
+//!   convert --format json` writes.
+//! - **Session**: a serialized [`Session`], which additionally carries a
+//!   computed [`NetworkLayout`](crate::layout::result::NetworkLayout) and
+//!   display options, as [`write_session`] produces. (`biofabric layout
+//!   --output out.json` writes a bare `NetworkLayout`, which is neither
+//!   shape.)
+//!
+//! [`parse_file`]/[`parse_reader`] accept either shape and always return
+//! just the [`Network`], matching the other format modules' signatures.
+//! Use [`read_session`]/[`read_session_reader`] to also recover the
+//! layout when present.
 
-use crate::model::Network;
+use super::session::Session;
+use super::ParseError;
+use crate::layout::result::NetworkLayout;
+use crate::model::{AnnotationSet, Network};
+use serde::Serialize;
+use std::io::{BufReader, Read, Write};
 use std::path::Path;
 
 /// Serialize a [`Network`] to a pretty-printed JSON string.
@@ -30,3 +51,327 @@ pub fn read_network(path: &Path) -> std::io::Result<Network> {
     network_from_json(&contents)
         .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
 }
+
+/// Parse a JSON file into a [`Network`], accepting either the
+/// network-only or the session JSON shape (see the module docs).
+pub fn parse_file(path: &Path) -> Result<Network, ParseError> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|e| ParseError::from(e).with_path(path))?;
+    parse_string(&contents).map_err(|e| e.with_path(path))
+}
+
+/// Parse a JSON network (or session) from any reader.
+pub fn parse_reader<R: Read>(mut reader: BufReader<R>) -> Result<Network, ParseError> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+    parse_string(&contents)
+}
+
+/// Parse a JSON network (or session) from a string.
+pub fn parse_string(data: &str) -> Result<Network, ParseError> {
+    if let Ok(network) = network_from_json(data) {
+        return Ok(network);
+    }
+    session_from_json(data)
+        .map(|session| session.network)
+        .map_err(|e| ParseError::InvalidFormat {
+            line: 0,
+            message: format!("not a valid network or session JSON: {e}"),
+        })
+}
+
+/// Serialize a [`Session`] (network + layout + display options) to a
+/// pretty-printed JSON string.
+pub fn session_to_json(session: &Session) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(session)
+}
+
+/// Deserialize a [`Session`] from a JSON string.
+pub fn session_from_json(json: &str) -> serde_json::Result<Session> {
+    serde_json::from_str(json)
+}
+
+/// Write a [`Session`] to a JSON file on disk.
+pub fn write_session(session: &Session, path: &Path) -> Result<(), ParseError> {
+    let json = session_to_json(session).map_err(|e| ParseError::InvalidFormat {
+        line: 0,
+        message: e.to_string(),
+    })?;
+    std::fs::write(path, json).map_err(|e| ParseError::from(e).with_path(path))
+}
+
+/// Write a [`Session`] as JSON to any writer.
+pub fn write_session_writer<W: Write>(session: &Session, mut writer: W) -> Result<(), ParseError> {
+    let json = session_to_json(session).map_err(|e| ParseError::InvalidFormat {
+        line: 0,
+        message: e.to_string(),
+    })?;
+    writer.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+/// Read a JSON session file, accepting either the session or the
+/// network-only JSON shape (the latter is wrapped via
+/// [`Session::from_network`]).
+pub fn read_session(path: &Path) -> Result<Session, ParseError> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|e| ParseError::from(e).with_path(path))?;
+    read_session_string(&contents).map_err(|e| e.with_path(path))
+}
+
+/// Read a JSON session from any reader, accepting either shape.
+pub fn read_session_reader<R: Read>(mut reader: BufReader<R>) -> Result<Session, ParseError> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+    read_session_string(&contents)
+}
+
+fn read_session_string(data: &str) -> Result<Session, ParseError> {
+    if let Ok(session) = session_from_json(data) {
+        return Ok(session);
+    }
+    network_from_json(data)
+        .map(Session::from_network)
+        .map_err(|e| ParseError::InvalidFormat {
+            line: 0,
+            message: format!("not a valid network or session JSON: {e}"),
+        })
+}
+
+// ==========================================================================
+// Layout export
+// ==========================================================================
+//
+// Unlike [`Network`]/[`Session`], a [`NetworkLayout`] has no corresponding
+// `parse_*` counterpart here — it's a computed, derived artifact (the
+// output of a layout algorithm), not a format anything round-trips through
+// on read. These functions exist purely to hand the computed geometry to a
+// consumer outside this crate (e.g. a web frontend), in a flatter,
+// camelCase shape more convenient to consume than `NetworkLayout`'s own
+// internal `Serialize` impl.
+
+/// One node's row and column span, for [`layout_to_json`].
+#[derive(Debug, Serialize)]
+struct LayoutNodeExport<'a> {
+    id: &'a str,
+    row: usize,
+    #[serde(rename = "minCol")]
+    min_col: usize,
+    #[serde(rename = "maxCol")]
+    max_col: usize,
+    #[serde(rename = "minColNoShadows")]
+    min_col_no_shadows: usize,
+    #[serde(rename = "maxColNoShadows")]
+    max_col_no_shadows: usize,
+    name: &'a str,
+    #[serde(rename = "colorIndex")]
+    color_index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nid: Option<usize>,
+    #[serde(rename = "plainDrainZones", skip_serializing_if = "Option::is_none")]
+    plain_drain_zones: Option<&'a Vec<(usize, usize)>>,
+    #[serde(rename = "shadowDrainZones", skip_serializing_if = "Option::is_none")]
+    shadow_drain_zones: Option<&'a Vec<(usize, usize)>>,
+}
+
+/// One link's column and endpoints, for [`layout_to_json`].
+#[derive(Debug, Serialize)]
+struct LayoutLinkExport<'a> {
+    source: &'a str,
+    target: &'a str,
+    relation: &'a str,
+    column: usize,
+    #[serde(rename = "columnNoShadows", skip_serializing_if = "Option::is_none")]
+    column_no_shadows: Option<usize>,
+    #[serde(rename = "isShadow")]
+    is_shadow: bool,
+    #[serde(rename = "colorIndex")]
+    color_index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    directed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<f64>,
+}
+
+/// The full shape written by [`layout_to_json`]/[`write_layout`].
+#[derive(Debug, Serialize)]
+struct LayoutExport<'a> {
+    nodes: Vec<LayoutNodeExport<'a>>,
+    links: Vec<LayoutLinkExport<'a>>,
+    #[serde(rename = "rowCount")]
+    row_count: usize,
+    #[serde(rename = "columnCount")]
+    column_count: usize,
+    #[serde(rename = "columnCountNoShadows")]
+    column_count_no_shadows: usize,
+    #[serde(rename = "nodeAnnotations")]
+    node_annotations: &'a AnnotationSet,
+    #[serde(rename = "linkAnnotations")]
+    link_annotations: &'a AnnotationSet,
+    #[serde(rename = "linkAnnotationsNoShadows")]
+    link_annotations_no_shadows: &'a AnnotationSet,
+}
+
+fn build_layout_export(layout: &NetworkLayout) -> LayoutExport<'_> {
+    let nodes = layout
+        .iter_nodes()
+        .map(|(id, nl)| LayoutNodeExport {
+            id: id.as_str(),
+            row: nl.row,
+            min_col: nl.min_col,
+            max_col: nl.max_col,
+            min_col_no_shadows: nl.min_col_no_shadows,
+            max_col_no_shadows: nl.max_col_no_shadows,
+            name: &nl.name,
+            color_index: nl.color_index,
+            nid: nl.nid,
+            plain_drain_zones: nl.plain_drain_zones.as_ref(),
+            shadow_drain_zones: nl.shadow_drain_zones.as_ref(),
+        })
+        .collect();
+
+    let links = layout
+        .iter_links()
+        .map(|ll| LayoutLinkExport {
+            source: ll.source.as_str(),
+            target: ll.target.as_str(),
+            relation: &ll.relation,
+            column: ll.column,
+            column_no_shadows: ll.column_no_shadows,
+            is_shadow: ll.is_shadow,
+            color_index: ll.color_index,
+            directed: ll.directed,
+            weight: ll.weight,
+        })
+        .collect();
+
+    LayoutExport {
+        nodes,
+        links,
+        row_count: layout.row_count,
+        column_count: layout.column_count,
+        column_count_no_shadows: layout.column_count_no_shadows,
+        node_annotations: &layout.node_annotations,
+        link_annotations: &layout.link_annotations,
+        link_annotations_no_shadows: &layout.link_annotations_no_shadows,
+    }
+}
+
+/// Serialize a [`NetworkLayout`]'s computed geometry (node rows/spans, link
+/// columns, drain zones, annotation bands) to a pretty-printed JSON string.
+///
+/// This is a one-way export for external consumers (e.g. a web frontend) —
+/// unlike [`network_to_json`]/[`session_to_json`], there is no matching
+/// `layout_from_json`, since nothing in this crate reads this shape back.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+pub fn layout_to_json(layout: &NetworkLayout) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(&build_layout_export(layout))
+}
+
+/// Write a [`NetworkLayout`]'s computed geometry as JSON to any writer.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+pub fn write_layout<W: Write>(layout: &NetworkLayout, mut writer: W) -> Result<(), ParseError> {
+    let json = layout_to_json(layout).map_err(|e| ParseError::InvalidFormat {
+        line: 0,
+        message: e.to_string(),
+    })?;
+    writer.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::model::{Link, Network};
+    use crate::worker::NoopMonitor;
+
+    fn sample_network() -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        network.add_link(Link::new("B", "C", "pr"));
+        network.add_lone_node("D");
+        network.generate_shadows();
+        network.metadata.name = Some("sample".to_string());
+        network.detect_directed();
+        network
+    }
+
+    /// Networks have no `PartialEq` impl, so compare round-trips by their
+    /// JSON representation (what this module is responsible for preserving
+    /// anyway).
+    fn same_network_json(a: &Network, b: &Network) -> bool {
+        network_to_json(a).unwrap() == network_to_json(b).unwrap()
+    }
+
+    #[test]
+    fn test_parse_file_round_trips_a_network_written_to_json() {
+        let network = sample_network();
+        let path = std::env::temp_dir().join("biofabric_json_roundtrip_test.json");
+        write_network(&network, &path).unwrap();
+
+        let reparsed = parse_file(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(same_network_json(&reparsed, &network));
+    }
+
+    #[test]
+    fn test_parse_string_accepts_the_session_shape_too() {
+        let network = sample_network();
+        let session = Session::from_network(network.clone());
+        let json = session_to_json(&session).unwrap();
+
+        let reparsed = parse_string(&json).unwrap();
+        assert!(same_network_json(&reparsed, &network));
+    }
+
+    #[test]
+    fn test_read_session_accepts_the_network_only_shape_too() {
+        let network = sample_network();
+        let json = network_to_json(&network).unwrap();
+
+        let session = read_session_string(&json).unwrap();
+        assert!(same_network_json(&session.network, &network));
+        assert!(session.layout.is_none());
+    }
+
+    fn tiny_layout() -> crate::layout::result::NetworkLayout {
+        let network = sample_network();
+        let algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        algo.layout(&network, &LayoutParams::default(), &NoopMonitor).unwrap()
+    }
+
+    #[test]
+    fn test_layout_to_json_round_trips_through_serde_for_tiny_layout() {
+        let layout = tiny_layout();
+        let json = layout_to_json(&layout).unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let reparsed: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string_pretty(&value).unwrap()).unwrap();
+        assert_eq!(value, reparsed);
+
+        assert_eq!(value["rowCount"], layout.row_count);
+        assert_eq!(value["columnCount"], layout.column_count);
+        assert_eq!(value["nodes"].as_array().unwrap().len(), layout.nodes.len());
+        assert_eq!(value["links"].as_array().unwrap().len(), layout.links.len());
+        assert!(value["nodes"][0]["id"].is_string());
+        assert!(value["links"][0]["column"].is_number());
+    }
+
+    #[test]
+    fn test_write_layout_matches_layout_to_json() {
+        let layout = tiny_layout();
+        let mut buf = Vec::new();
+        write_layout(&layout, &mut buf).unwrap();
+
+        assert_eq!(String::from_utf8(buf).unwrap(), layout_to_json(&layout).unwrap());
+    }
+}
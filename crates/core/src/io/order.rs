@@ -8,6 +8,9 @@
 //! - Import a node order to use as a fixed layout
 //! - Export selected nodes
 //! - Export selected links
+//! - Export a NOA (node order attribute) or EDA (edge/link order attribute)
+//!   file, the Cytoscape-compatible formats the Java version wrote for the
+//!   same purpose (see [`write_noa`]/[`write_eda`])
 //!
 //! ## File format
 //!
@@ -176,6 +179,83 @@ pub fn write_link_order_file(
     write_link_order(&mut file, layout)
 }
 
+/// Write a NOA (node order attribute) file: a `Node Row` header followed by
+/// `node = row` lines in row order.
+///
+/// ## References
+///
+/// - Java: `FileLoadFlows.exportNodeOrder()`
+pub fn write_noa<W: Write>(writer: &mut W, layout: &NetworkLayout) -> std::io::Result<()> {
+    writeln!(writer, "Node Row")?;
+
+    let mut nodes: Vec<(&NodeId, usize)> = layout.iter_nodes().map(|(id, info)| (id, info.row)).collect();
+    nodes.sort_by_key(|(_, row)| *row);
+
+    for (id, row) in nodes {
+        writeln!(writer, "{} = {}", id, row)?;
+    }
+
+    Ok(())
+}
+
+/// Write a NOA file to a file path. See [`write_noa`].
+pub fn write_noa_file(path: &Path, layout: &NetworkLayout) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write_noa(&mut file, layout)
+}
+
+/// Write an EDA (edge/link order attribute) file, with shadow links
+/// included: a `Link Column` header followed by `source (relation) target =
+/// column` lines in column order.
+///
+/// Shadow links store a flipped source/target internally (see
+/// [`Link::to_shadow`](crate::model::Link::to_shadow)); this restores the
+/// original, unflipped order and marks the relation with `shdw(...)`,
+/// matching the Java exporter's convention.
+pub fn write_eda<W: Write>(writer: &mut W, layout: &NetworkLayout) -> std::io::Result<()> {
+    writeln!(writer, "Link Column")?;
+
+    for ll in layout.iter_links() {
+        if ll.is_shadow {
+            writeln!(writer, "{} shdw({}) {} = {}", ll.target, ll.relation, ll.source, ll.column)?;
+        } else {
+            writeln!(writer, "{} ({}) {} = {}", ll.source, ll.relation, ll.target, ll.column)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write an EDA file to a file path. See [`write_eda`].
+pub fn write_eda_file(path: &Path, layout: &NetworkLayout) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write_eda(&mut file, layout)
+}
+
+/// Write an EDA file with shadow links omitted, using each link's
+/// [`column_no_shadows`](LinkLayout::column_no_shadows) column instead of
+/// its shadow-inclusive [`column`](LinkLayout::column).
+pub fn write_eda_no_shadows<W: Write>(writer: &mut W, layout: &NetworkLayout) -> std::io::Result<()> {
+    writeln!(writer, "Link Column")?;
+
+    for ll in layout.iter_links() {
+        if ll.is_shadow {
+            continue;
+        }
+        let column = ll.column_no_shadows.unwrap_or(ll.column);
+        writeln!(writer, "{} ({}) {} = {}", ll.source, ll.relation, ll.target, column)?;
+    }
+
+    Ok(())
+}
+
+/// Write an EDA file with shadow links omitted to a file path. See
+/// [`write_eda_no_shadows`].
+pub fn write_eda_no_shadows_file(path: &Path, layout: &NetworkLayout) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write_eda_no_shadows(&mut file, layout)
+}
+
 /// Write selected nodes to a writer.
 ///
 /// Writes one node name per line for each selected node, in row order.
@@ -218,3 +298,76 @@ pub fn write_selected_links<W: Write>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::model::{Link, Network};
+    use crate::worker::NoopMonitor;
+
+    fn star_layout() -> NetworkLayout {
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "pp"));
+        network.add_link(Link::new("hub", "leafB", "pp"));
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        layout_algo.layout(&network, &LayoutParams::default(), &NoopMonitor).unwrap()
+    }
+
+    #[test]
+    fn test_write_noa_matches_node_row_header_and_row_ordering() {
+        let layout = star_layout();
+        let mut out = Vec::new();
+        write_noa(&mut out, &layout).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("Node Row"));
+
+        let mut nodes: Vec<(&NodeId, usize)> = layout.iter_nodes().map(|(id, info)| (id, info.row)).collect();
+        nodes.sort_by_key(|(_, row)| *row);
+        for (id, row) in nodes {
+            assert_eq!(lines.next(), Some(format!("{} = {}", id, row).as_str()));
+        }
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_write_eda_matches_link_column_header_and_column_ordering() {
+        let layout = star_layout();
+        let mut out = Vec::new();
+        write_eda(&mut out, &layout).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("Link Column"));
+
+        for ll in layout.iter_links() {
+            let expected = if ll.is_shadow {
+                format!("{} shdw({}) {} = {}", ll.target, ll.relation, ll.source, ll.column)
+            } else {
+                format!("{} ({}) {} = {}", ll.source, ll.relation, ll.target, ll.column)
+            };
+            assert_eq!(lines.next(), Some(expected.as_str()));
+        }
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_write_eda_no_shadows_omits_shadow_links_and_uses_shadow_free_columns() {
+        let layout = star_layout();
+        let mut out = Vec::new();
+        write_eda_no_shadows(&mut out, &layout).unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("Link Column"));
+
+        for ll in layout.iter_links().filter(|ll| !ll.is_shadow) {
+            let column = ll.column_no_shadows.unwrap_or(ll.column);
+            let expected = format!("{} ({}) {} = {}", ll.source, ll.relation, ll.target, column);
+            assert_eq!(lines.next(), Some(expected.as_str()));
+        }
+        assert_eq!(lines.next(), None);
+    }
+}
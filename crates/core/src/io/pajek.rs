@@ -0,0 +1,283 @@
+//! Pajek (.net) format parser.
+//!
+//! Pajek is a network analysis tool widely used for alignment and social
+//! network benchmarks, and its `.net` format is a common interchange format
+//! for them. This module reads (but does not write) the subset of the
+//! format BioFabric cares about:
+//!
+//! ```text
+//! *Vertices 3
+//! 1 "nodeA"
+//! 2 "nodeB"
+//! 3 "nodeC"
+//! *Edges
+//! 1 2
+//! *Arcs
+//! 2 3
+//! ```
+//!
+//! ## Format Structure
+//!
+//! - `*Vertices N` header, followed by N lines of `index "label"` (index is
+//!   1-based; the label may be unquoted if it has no embedded whitespace).
+//! - Any number of `*Edges` (undirected) and `*Arcs` (directed) sections,
+//!   each followed by `source target [weight]` lines referencing vertex
+//!   indices. A line's section determines [`Link::directed`]: `*Arcs` lines
+//!   get `Some(true)`, `*Edges` lines are left `None` (undetermined),
+//!   matching how [`sif`](super::sif) leaves it for plain interactions.
+//!
+//! ## References
+//!
+//! - Pajek format: <http://mrvar.fdv.uni-lj.si/pajek/pajekman.pdf>
+
+use super::{strip_bom, ImportStats, ParseError};
+use crate::model::{Link, Network};
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Parse a Pajek file from a path.
+pub fn parse_file(path: &Path) -> Result<Network, ParseError> {
+    let file = std::fs::File::open(path).map_err(|e| ParseError::from(e).with_path(path))?;
+    parse_reader(BufReader::new(file)).map_err(|e| e.with_path(path))
+}
+
+/// Parse a Pajek file from any reader.
+pub fn parse_reader<R: Read>(reader: BufReader<R>) -> Result<Network, ParseError> {
+    let (network, _stats) = parse_reader_with_stats(reader)?;
+    Ok(network)
+}
+
+/// Parse a Pajek file and return import statistics.
+pub fn parse_reader_with_stats<R: Read>(
+    reader: BufReader<R>,
+) -> Result<(Network, ImportStats), ParseError> {
+    let mut stats = ImportStats::new();
+    let mut node_labels: Vec<String> = Vec::new();
+    let mut links: Vec<Link> = Vec::new();
+    let mut used_nodes = std::collections::HashSet::new();
+
+    #[derive(PartialEq, Eq)]
+    enum Section {
+        None,
+        Vertices { remaining: usize },
+        Edges,
+        Arcs,
+    }
+    let mut section = Section::None;
+
+    for (i, line) in reader.lines().enumerate() {
+        let line_num = i + 1;
+        let line = line.map_err(ParseError::Io)?;
+        let line = if line_num == 1 { strip_bom(&line) } else { line.as_str() };
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('%') {
+            continue;
+        }
+
+        let lower = trimmed.to_ascii_lowercase();
+        if lower.starts_with("*vertices") {
+            let count_str = trimmed[trimmed.find(' ').map_or(trimmed.len(), |p| p + 1)..].trim();
+            let count: usize = count_str.parse().map_err(|_| ParseError::InvalidFormat {
+                line: line_num,
+                message: format!("Invalid *Vertices count: '{}'", count_str),
+            })?;
+            node_labels = vec![String::new(); count];
+            section = Section::Vertices { remaining: count };
+            continue;
+        }
+        if lower.starts_with("*edges") {
+            section = Section::Edges;
+            continue;
+        }
+        if lower.starts_with("*arcs") {
+            section = Section::Arcs;
+            continue;
+        }
+        if trimmed.starts_with('*') {
+            // Unsupported section (e.g. *Partition, *Vector): skip its lines.
+            section = Section::None;
+            continue;
+        }
+
+        match &mut section {
+            Section::Vertices { remaining } => {
+                if *remaining == 0 {
+                    stats.bad_lines.push(trimmed.to_string());
+                    continue;
+                }
+                let (idx_str, rest) = trimmed.split_once(char::is_whitespace).unwrap_or((trimmed, ""));
+                let idx: usize = idx_str.parse().map_err(|_| ParseError::InvalidFormat {
+                    line: line_num,
+                    message: format!("Invalid vertex index: '{}'", idx_str),
+                })?;
+                if idx < 1 || idx > node_labels.len() {
+                    return Err(ParseError::InvalidFormat {
+                        line: line_num,
+                        message: format!(
+                            "Vertex index {} out of range (1..={} declared)",
+                            idx,
+                            node_labels.len()
+                        ),
+                    });
+                }
+                node_labels[idx - 1] = parse_label(rest.trim());
+                *remaining -= 1;
+            }
+            Section::Edges | Section::Arcs => {
+                let tokens: Vec<&str> = trimmed.split_whitespace().collect();
+                if tokens.len() < 2 {
+                    stats.bad_lines.push(trimmed.to_string());
+                    continue;
+                }
+                let src_idx: usize = tokens[0].parse().map_err(|_| ParseError::InvalidFormat {
+                    line: line_num,
+                    message: format!("Invalid source index: '{}'", tokens[0]),
+                })?;
+                let tgt_idx: usize = tokens[1].parse().map_err(|_| ParseError::InvalidFormat {
+                    line: line_num,
+                    message: format!("Invalid target index: '{}'", tokens[1]),
+                })?;
+                if src_idx < 1 || src_idx > node_labels.len() {
+                    return Err(ParseError::InvalidFormat {
+                        line: line_num,
+                        message: format!(
+                            "Edge references out-of-range node index {} ({} vertices declared)",
+                            src_idx,
+                            node_labels.len()
+                        ),
+                    });
+                }
+                if tgt_idx < 1 || tgt_idx > node_labels.len() {
+                    return Err(ParseError::InvalidFormat {
+                        line: line_num,
+                        message: format!(
+                            "Edge references out-of-range node index {} ({} vertices declared)",
+                            tgt_idx,
+                            node_labels.len()
+                        ),
+                    });
+                }
+
+                let source = &node_labels[src_idx - 1];
+                let target = &node_labels[tgt_idx - 1];
+                used_nodes.insert(src_idx - 1);
+                used_nodes.insert(tgt_idx - 1);
+
+                let mut link = Link::new(source.as_str(), target.as_str(), "pp");
+                if matches!(section, Section::Arcs) {
+                    link.directed = Some(true);
+                }
+
+                let is_feedback = link.is_feedback();
+                links.push(link.clone());
+                stats.link_count += 1;
+
+                if !is_feedback {
+                    if let Some(shadow) = link.to_shadow() {
+                        links.push(shadow);
+                        stats.shadow_link_count += 1;
+                    }
+                }
+            }
+            Section::None => {
+                // Line belongs to an unsupported section; ignore.
+            }
+        }
+    }
+
+    let mut network = Network::with_capacity(node_labels.len(), links.len());
+    for link in links {
+        network.add_link(link);
+    }
+
+    for (i, label) in node_labels.iter().enumerate() {
+        if !used_nodes.contains(&i) {
+            network.add_lone_node(label.as_str());
+        }
+    }
+
+    stats.node_count = network.node_count();
+    stats.lone_node_count = network.lone_nodes().len();
+
+    Ok((network, stats))
+}
+
+/// Parse a Pajek string directly.
+pub fn parse_string(content: &str) -> Result<Network, ParseError> {
+    parse_reader(BufReader::new(content.as_bytes()))
+}
+
+/// Strip a Pajek vertex label's surrounding quotes, if present.
+fn parse_label(s: &str) -> String {
+    let s = s.trim();
+    if let Some(inner) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        inner.to_string()
+    } else {
+        // An unquoted label ends at the first whitespace (coordinates or
+        // style attributes may follow); a quoted one may legitimately
+        // contain no trailing attributes at all.
+        s.split_whitespace().next().unwrap_or("").to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vertices_and_mixed_edges_and_arcs() {
+        let content = r#"*Vertices 3
+1 "nodeA"
+2 "nodeB"
+3 "nodeC"
+*Edges
+1 2
+*Arcs
+2 3
+"#;
+        let (network, stats) = parse_reader_with_stats(BufReader::new(content.as_bytes())).unwrap();
+
+        assert_eq!(network.node_count(), 3);
+        assert!(network.nodes().any(|n| n.id.as_str() == "nodeA"));
+        assert!(network.nodes().any(|n| n.id.as_str() == "nodeB"));
+        assert!(network.nodes().any(|n| n.id.as_str() == "nodeC"));
+
+        let non_shadow: Vec<_> = network.links().filter(|l| !l.is_shadow).collect();
+        assert_eq!(non_shadow.len(), 2);
+
+        let arc = non_shadow
+            .iter()
+            .find(|l| l.source.as_str() == "nodeB" && l.target.as_str() == "nodeC")
+            .expect("arc B->C should be present");
+        assert_eq!(arc.directed, Some(true));
+
+        let edge = non_shadow
+            .iter()
+            .find(|l| l.source.as_str() == "nodeA" && l.target.as_str() == "nodeB")
+            .expect("edge A-B should be present");
+        assert_eq!(edge.directed, None);
+
+        assert_eq!(stats.node_count, 3);
+        assert_eq!(stats.link_count, 2);
+    }
+
+    #[test]
+    fn test_unquoted_label() {
+        let content = "*Vertices 2\n1 nodeA\n2 nodeB\n*Edges\n1 2\n";
+        let network = parse_string(content).unwrap();
+        assert!(network.nodes().any(|n| n.id.as_str() == "nodeA"));
+    }
+
+    #[test]
+    fn test_out_of_range_vertex_index_is_a_parse_error() {
+        let content = "*Vertices 2\n1 \"A\"\n3 \"B\"\n*Edges\n1 2\n";
+        let err = parse_string(content).unwrap_err();
+        match err {
+            ParseError::InvalidFormat { message, .. } => {
+                assert!(message.contains('3'), "message should mention offending index: {message}");
+            }
+            other => panic!("expected ParseError::InvalidFormat, got {other:?}"),
+        }
+    }
+}
@@ -31,7 +31,8 @@
 
 use crate::alignment::scoring::AlignmentScores;
 use crate::layout::result::NetworkLayout;
-use crate::model::Network;
+use crate::model::{Network, NodeId, SelectionState};
+use crate::io::color::ColorAssignment;
 use crate::io::display_options::DisplayOptions;
 use serde::{Deserialize, Serialize};
 
@@ -70,6 +71,33 @@ pub struct Session {
     /// possible Java plug-in.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub plugin_data_lines: Vec<String>,
+
+    /// Additional `(network, layout)` pairs for a stacked comparison view
+    /// (e.g. before/after, condition-A/condition-B), rendered alongside
+    /// `network`/`layout` by `biofabric_render::render_comparison`.
+    ///
+    /// Empty for an ordinary single-network session. The BIF format has no
+    /// concept of multiple networks per file, so this is only preserved
+    /// across JSON sessions, not BIF exports.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comparison_panels: Vec<ComparisonPanel>,
+
+    /// The current node/link selection, rendered as a highlight by
+    /// `biofabric_render::render_session_to_image` — empty for an
+    /// ordinary, non-interactive session.
+    #[serde(default, skip_serializing_if = "SelectionState::is_empty")]
+    pub selection: SelectionState,
+}
+
+/// One extra `(network, layout)` pair attached to a [`Session`] for
+/// comparison rendering.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonPanel {
+    /// The panel's network.
+    pub network: Network,
+
+    /// The panel's computed layout.
+    pub layout: NetworkLayout,
 }
 
 /// Session-level metadata.
@@ -98,6 +126,8 @@ impl Session {
                 ..Default::default()
             },
             plugin_data_lines: Vec::new(),
+            comparison_panels: Vec::new(),
+            selection: SelectionState::new(),
         }
     }
 
@@ -113,6 +143,8 @@ impl Session {
                 ..Default::default()
             },
             plugin_data_lines: Vec::new(),
+            comparison_panels: Vec::new(),
+            selection: SelectionState::new(),
         }
     }
 
@@ -122,6 +154,37 @@ impl Session {
         self
     }
 
+    /// Attach a node/link selection to this session.
+    pub fn with_selection(mut self, selection: SelectionState) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Add another `(network, layout)` pair as a comparison panel, and
+    /// rebuild `display_options.color_assignment` as the union of node
+    /// names across the primary network and every panel, so the same node
+    /// is colored identically in each panel of a `render_comparison` image.
+    pub fn with_comparison_panel(mut self, network: Network, layout: NetworkLayout) -> Self {
+        self.comparison_panels.push(ComparisonPanel { network, layout });
+        self.rebuild_comparison_color_assignment();
+        self
+    }
+
+    /// Rebuild the shared [`ColorAssignment`] from the union of node names
+    /// across `network` and every comparison panel.
+    fn rebuild_comparison_color_assignment(&mut self) {
+        let names = self
+            .network
+            .node_ids()
+            .map(NodeId::as_str)
+            .chain(
+                self.comparison_panels
+                    .iter()
+                    .flat_map(|panel| panel.network.node_ids().map(NodeId::as_str)),
+            );
+        self.display_options.color_assignment = Some(ColorAssignment::from_names(names));
+    }
+
     /// Whether this session has a computed layout.
     pub fn has_layout(&self) -> bool {
         self.layout.is_some()
@@ -24,12 +24,13 @@
 
 use super::session::Session;
 use super::ParseError;
+use crate::alignment::scoring::AlignmentScores;
 use crate::layout::result::{LinkLayout, NetworkLayout, NodeLayout};
 use crate::model::{Annotation, AnnotationSet, Link, Network, Node, NodeId};
 use crate::io::color::{build_gene_colors, FabricColor, GENE_COLOR_NAMES};
 use crate::io::display_options::DisplayOptions;
 use indexmap::IndexMap;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::{BufReader, Read, Write};
 use std::path::Path;
 
@@ -58,6 +59,30 @@ const NO_BRIGHTEN_INDICES: [usize; 3] = [16, 24, 28];
 // XML Writer — Public API
 // ===========================================================================
 
+/// Options controlling which optional BIF sections a write includes.
+///
+/// The default writes a complete, Java-compatible session — nothing is
+/// omitted. Layout-diffing tools that only care about node/link topology
+/// can turn off the noisier sections so two BIFs for the same network
+/// diff cleanly even when display preferences differ.
+#[derive(Debug, Clone, Copy)]
+pub struct WriteOptions {
+    /// Whether to emit the `<displayOptions>` block.
+    pub include_display: bool,
+    /// Whether to emit the annotation sections (`<nodeAnnotations>`,
+    /// `<linkAnnotations>`, `<shadowLinkAnnotations>`).
+    pub include_annotations: bool,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            include_display: true,
+            include_annotations: true,
+        }
+    }
+}
+
 /// Write a complete session to a BioFabric XML file.
 pub fn write_session(session: &Session, path: &Path) -> Result<(), ParseError> {
     let file = std::fs::File::create(path)?;
@@ -67,8 +92,20 @@ pub fn write_session(session: &Session, path: &Path) -> Result<(), ParseError> {
 
 /// Write a session to a writer.
 pub fn write_session_writer<W: Write>(
+    session: &Session,
+    w: W,
+) -> Result<(), ParseError> {
+    write_session_writer_opts(session, w, WriteOptions::default())
+}
+
+/// Write a session to a writer, honoring `opts` to omit optional sections.
+///
+/// Node and link sections are always written in full; only the sections
+/// named by [`WriteOptions`] are conditionally skipped.
+pub fn write_session_writer_opts<W: Write>(
     session: &Session,
     mut w: W,
+    opts: WriteOptions,
 ) -> Result<(), ParseError> {
     let layout = session
         .layout
@@ -104,8 +141,7 @@ pub fn write_session_writer<W: Write>(
     let (brighter, darker) = build_color_maps(&gene_colors, display);
 
     // -- Compute drain zones --
-    let (plain_drain_zones, shadow_drain_zones) =
-        compute_drain_zones(layout, &row_to_node);
+    let (plain_drain_zones, shadow_drain_zones) = layout.compute_drain_zones();
 
     // -- Write XML --
     writeln!(w, "<BioFabric>")?;
@@ -114,7 +150,9 @@ pub fn write_session_writer<W: Write>(
     write_colors(&mut w, &brighter, &darker)?;
 
     // 2. Display options
-    write_display_options(&mut w, display)?;
+    if opts.include_display {
+        write_display_options(&mut w, display)?;
+    }
 
     // 3. Nodes (sorted by row)
     write_nodes(
@@ -135,21 +173,40 @@ pub fn write_session_writer<W: Write>(
     // Java mapping:
     //   <linkAnnotations>       → non-shadow column ranges (linkAnnots_)
     //   <shadowLinkAnnotations> → shadow column ranges (fullLinkAnnots_)
-    write_annotation_section(&mut w, "nodeAnnotations", &layout.node_annotations)?;
-    write_annotation_section(&mut w, "linkAnnotations", &layout.link_annotations_no_shadows)?;
-    write_annotation_section(
-        &mut w,
-        "shadowLinkAnnotations",
-        &layout.link_annotations,
-    )?;
+    if opts.include_annotations {
+        write_annotation_section(&mut w, "nodeAnnotations", &layout.node_annotations)?;
+        write_annotation_section(&mut w, "linkAnnotations", &layout.link_annotations_no_shadows)?;
+        write_annotation_section(
+            &mut w,
+            "shadowLinkAnnotations",
+            &layout.link_annotations,
+        )?;
+    }
 
     // 7. Plugin data
+    //
+    // Alignment scores are written using the same
+    // `NetworkAlignmentPlugIn`/`NetAlignStats`/`NetAlignMeasure` schema Java
+    // emits inside `<plugInDataSets>`, so Rust-produced alignment BIFs are
+    // self-describing to the same tooling that already reads Java's.
     writeln!(w, "  <plugInDataSets>")?;
+    if let Some(scores) = &session.alignment_scores {
+        write_alignment_scores(&mut w, scores)?;
+    }
     for line in &session.plugin_data_lines {
         writeln!(w, "{}", line)?;
     }
     writeln!(w, "  </plugInDataSets>")?;
 
+    // 8. Source comments (Rust-only extension, no Java equivalent)
+    //
+    // Carries header/comment lines from the original source file (currently
+    // only populated by the GW loader) through a BIF round-trip so the
+    // provenance isn't lost just because the network passed through BIF.
+    if !network.metadata.source_comments.is_empty() {
+        write_source_comments(&mut w, &network.metadata.source_comments)?;
+    }
+
     writeln!(w, "</BioFabric>")?;
 
     Ok(())
@@ -162,6 +219,16 @@ pub fn write_session_string(session: &Session) -> Result<String, ParseError> {
     Ok(String::from_utf8(buf).expect("XML output should be valid UTF-8"))
 }
 
+/// Write a session to a String, honoring `opts` to omit optional sections.
+///
+/// Useful for diffing two layouts' node/link topology without noise from
+/// display preferences or annotation placement.
+pub fn write_session_string_opts(session: &Session, opts: WriteOptions) -> Result<String, ParseError> {
+    let mut buf = Vec::new();
+    write_session_writer_opts(session, &mut buf, opts)?;
+    Ok(String::from_utf8(buf).expect("XML output should be valid UTF-8"))
+}
+
 // ===========================================================================
 // XML Writer — Internal helpers
 // ===========================================================================
@@ -264,6 +331,78 @@ fn build_color_maps(
     (brighter, darker)
 }
 
+/// The fully-qualified Java plugin class name Java's BioFabric uses to tag
+/// the network alignment stats block in `<plugInDataSets>`.
+const NET_ALIGN_PLUGIN_TAG: &str = "org.systemsbiology.biofabric.plugin.core.align.NetworkAlignmentPlugIn";
+
+/// Display names `NetAlignMeasure` entries use for each [`AlignmentScores`]
+/// field, in the order Java writes them. These are the same strings
+/// `crates/core/tests/analysis_tests.rs` maps to golden `.scores` property
+/// keys (e.g. "Edge Coverage" ↔ `networkAlignment.edgeCoverage`).
+const EC_NAME: &str = "Edge Coverage";
+const S3_NAME: &str = "Symmetric Substructure Score";
+const ICS_NAME: &str = "Induced Conserved Structure";
+const NC_NAME: &str = "Node Correctness";
+const NGS_NAME: &str = "Node Group Similarity";
+const LGS_NAME: &str = "Link Group Similarity";
+const JS_NAME: &str = "Jaccard Similarity";
+
+/// Write alignment scores into `<plugInDataSets>` using Java's
+/// `NetworkAlignmentPlugIn`/`NetAlignStats`/`NetAlignMeasure` schema, so a
+/// Rust-produced alignment BIF is self-describing the same way a
+/// Java-produced one is. The NC/NGS/LGS/JS measures (only meaningful when a
+/// perfect alignment was supplied) are written together or not at all,
+/// matching Java's behavior.
+fn write_alignment_scores<W: Write>(w: &mut W, scores: &AlignmentScores) -> Result<(), ParseError> {
+    writeln!(w, "    <{}>", NET_ALIGN_PLUGIN_TAG)?;
+    writeln!(w, "      <NetAlignStats>")?;
+    write_net_align_measure(w, EC_NAME, scores.ec)?;
+    write_net_align_measure(w, S3_NAME, scores.s3)?;
+    write_net_align_measure(w, ICS_NAME, scores.ics)?;
+    if let (Some(nc), Some(ngs), Some(lgs), Some(js)) = (scores.nc, scores.ngs, scores.lgs, scores.js) {
+        write_net_align_measure(w, NC_NAME, nc)?;
+        write_net_align_measure(w, NGS_NAME, ngs)?;
+        write_net_align_measure(w, LGS_NAME, lgs)?;
+        write_net_align_measure(w, JS_NAME, js)?;
+    }
+    writeln!(w, "      </NetAlignStats>")?;
+    writeln!(w, "    </{}>", NET_ALIGN_PLUGIN_TAG)?;
+    Ok(())
+}
+
+fn write_net_align_measure<W: Write>(w: &mut W, name: &str, val: f64) -> Result<(), ParseError> {
+    writeln!(w, "        <NetAlignMeasure name=\"{}\" val=\"{}\"/>", name, format_java_double(val))?;
+    Ok(())
+}
+
+/// Parse a `<NetAlignMeasure name="..." val="..."/>` line into its display
+/// name and value, returning `None` if `line` isn't one.
+fn parse_net_align_measure(line: &str) -> Option<(String, f64)> {
+    let trimmed = line.trim();
+    if !trimmed.starts_with("<NetAlignMeasure") {
+        return None;
+    }
+    let name = extract_attr(trimmed, "name")?;
+    let val = extract_attr(trimmed, "val")?.parse().ok()?;
+    Some((name, val))
+}
+
+/// Assemble the `NetAlignMeasure` entries collected from a `<NetAlignStats>`
+/// block (keyed by display name) into [`AlignmentScores`]. Returns `None`
+/// if the three always-present measures (EC/S3/ICS) aren't all there, since
+/// that means the block wasn't one this reader understands.
+fn net_align_measures_to_scores(measures: &HashMap<String, f64>) -> Option<AlignmentScores> {
+    Some(AlignmentScores {
+        ec: *measures.get(EC_NAME)?,
+        s3: *measures.get(S3_NAME)?,
+        ics: *measures.get(ICS_NAME)?,
+        nc: measures.get(NC_NAME).copied(),
+        ngs: measures.get(NGS_NAME).copied(),
+        lgs: measures.get(LGS_NAME).copied(),
+        js: measures.get(JS_NAME).copied(),
+    })
+}
+
 /// Write the `<colors>` section.
 fn write_colors<W: Write>(
     w: &mut W,
@@ -433,6 +572,21 @@ fn write_nodes<W: Write>(
             writeln!(w, "      <drainZonesShadow/>")?;
         }
 
+        if let Some(attrs) = layout.node_attributes.get(*id) {
+            if !attrs.is_empty() {
+                writeln!(w, "      <attributes>")?;
+                for (key, value) in attrs {
+                    writeln!(
+                        w,
+                        "        <attr key=\"{}\" value=\"{}\" />",
+                        xml_escape(key),
+                        xml_escape(value)
+                    )?;
+                }
+                writeln!(w, "      </attributes>")?;
+            }
+        }
+
         writeln!(w, "    </node>")?;
     }
 
@@ -581,102 +735,18 @@ fn write_annotation_section<W: Write>(
     Ok(())
 }
 
-// ===========================================================================
-// Drain Zone Computation
-// ===========================================================================
-
-/// A drain zone: contiguous column range where a node is the "main" endpoint.
-///
-/// For plain (non-shadow) drain zones: the main node is at the TOP (min row).
-/// For shadow drain zones: the main node is at the TOP for non-shadow links,
-/// and at the BOTTOM (max row) for shadow links.
-fn compute_drain_zones(
-    layout: &NetworkLayout,
-    row_to_node: &[NodeId],
-) -> (
-    HashMap<NodeId, Vec<(usize, usize)>>,
-    HashMap<NodeId, Vec<(usize, usize)>>,
-) {
-    // Plain drain zones: non-shadow links only, sorted by column_no_shadows
-    let mut plain_links: Vec<&LinkLayout> =
-        layout.links.iter().filter(|ll| !ll.is_shadow).collect();
-    plain_links.sort_by_key(|ll| ll.column_no_shadows.unwrap_or(0));
-    let plain = group_drain_zones(&plain_links, false, row_to_node);
-
-    // Shadow drain zones: ALL links, sorted by shadow column
-    let mut shadow_links: Vec<&LinkLayout> = layout.links.iter().collect();
-    shadow_links.sort_by_key(|ll| ll.column);
-    let shadow = group_drain_zones(&shadow_links, true, row_to_node);
-
-    (plain, shadow)
-}
-
-/// Group consecutive links into drain zones.
+/// Write the `<sourceComments>` section (see
+/// [`NetworkMetadata::source_comments`](crate::model::NetworkMetadata::source_comments)).
 ///
-/// The `for_shadow` flag controls which column and zone-node logic to use:
-/// - `false`: plain mode — use `column_no_shadows`, zone node = top row node
-/// - `true`: shadow mode — use `column`, zone node depends on shadow status
-fn group_drain_zones(
-    links: &[&LinkLayout],
-    for_shadow: bool,
-    row_to_node: &[NodeId],
-) -> HashMap<NodeId, Vec<(usize, usize)>> {
-    let mut result: HashMap<NodeId, Vec<(usize, usize)>> = HashMap::new();
-
-    if links.is_empty() {
-        return result;
-    }
-
-    /// Get the "zone node" for a link.
-    ///
-    /// - Non-shadow links: the node at the TOP (min row) — this is the node
-    ///   whose horizontal line the link "drains from".
-    /// - Shadow links: the node at the BOTTOM (max row).
-    fn zone_node_id<'a>(ll: &LinkLayout, row_to_node: &'a [NodeId]) -> &'a NodeId {
-        if ll.is_shadow {
-            // Shadow links: zone node at bottom row
-            let bottom = ll.source_row.max(ll.target_row);
-            &row_to_node[bottom]
-        } else {
-            // Non-shadow: zone node at top row
-            let top = ll.source_row.min(ll.target_row);
-            &row_to_node[top]
-        }
-    }
-
-    fn get_col(ll: &LinkLayout, for_shadow: bool) -> usize {
-        if for_shadow {
-            ll.column
-        } else {
-            ll.column_no_shadows.unwrap_or(0)
-        }
+/// Only called when the list is non-empty, so networks with no comments
+/// round-trip byte-for-byte identically to before this section existed.
+fn write_source_comments<W: Write>(w: &mut W, comments: &[String]) -> Result<(), ParseError> {
+    writeln!(w, "  <sourceComments>")?;
+    for comment in comments {
+        writeln!(w, "    <comment text=\"{}\" />", xml_escape(comment))?;
     }
-
-    let mut start_idx = 0;
-
-    for i in 1..=links.len() {
-        let flush = if i == links.len() {
-            true
-        } else {
-            zone_node_id(links[i], row_to_node)
-                != zone_node_id(links[start_idx], row_to_node)
-        };
-
-        if flush {
-            let end_idx = i - 1;
-            let start_col = get_col(links[start_idx], for_shadow);
-            let end_col = get_col(links[end_idx], for_shadow);
-            let node = zone_node_id(links[start_idx], row_to_node).clone();
-
-            result.entry(node).or_default().push((start_col, end_col));
-
-            if i < links.len() {
-                start_idx = i;
-            }
-        }
-    }
-
-    result
+    writeln!(w, "  </sourceComments>")?;
+    Ok(())
 }
 
 // ===========================================================================
@@ -685,9 +755,9 @@ fn group_drain_zones(
 
 /// Read a BioFabric XML session file.
 pub fn read_session(path: &Path) -> Result<Session, ParseError> {
-    let file = std::fs::File::open(path)?;
+    let file = std::fs::File::open(path).map_err(|e| ParseError::from(e).with_path(path))?;
     let reader = BufReader::new(file);
-    read_session_reader(reader)
+    read_session_reader(reader).map_err(|e| e.with_path(path))
 }
 
 /// Read a BioFabric XML session from any reader.
@@ -733,6 +803,8 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
     let mut in_node_annots = false;
     let mut in_link_annots = false;
     let mut in_shadow_link_annots = false;
+    let mut in_source_comments = false;
+    let mut source_comments: Vec<String> = Vec::new();
     let mut in_colors = false;
     let mut in_color_set = false;
 
@@ -740,10 +812,14 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
     let mut current_node: Option<ParsedNode> = None;
     let mut in_drain_zones = false;
     let mut in_drain_zones_shadow = false;
+    let mut in_attributes = false;
 
     // Plugin data capture for roundtrip fidelity
     let mut in_plugin_data = false;
     let mut plugin_data_lines: Vec<String> = Vec::new();
+    let mut alignment_scores: Option<AlignmentScores> = None;
+    let mut in_net_align_stats = false;
+    let mut net_align_measures: HashMap<String, f64> = HashMap::new();
 
     for line in xml.lines() {
         let trimmed = line.trim();
@@ -765,6 +841,27 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
             continue;
         }
         if in_plugin_data {
+            if trimmed == format!("<{}>", NET_ALIGN_PLUGIN_TAG)
+                || trimmed == format!("</{}>", NET_ALIGN_PLUGIN_TAG)
+            {
+                continue;
+            }
+            if trimmed == "<NetAlignStats>" {
+                in_net_align_stats = true;
+                net_align_measures.clear();
+                continue;
+            }
+            if trimmed == "</NetAlignStats>" {
+                in_net_align_stats = false;
+                alignment_scores = net_align_measures_to_scores(&net_align_measures);
+                continue;
+            }
+            if in_net_align_stats {
+                if let Some((name, val)) = parse_net_align_measure(trimmed) {
+                    net_align_measures.insert(name, val);
+                }
+                continue;
+            }
             // Store the line with original indentation (relative to 4-space base)
             plugin_data_lines.push(line.to_string());
             continue;
@@ -881,6 +978,7 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
                 color_index,
                 plain_drain_zones: Vec::new(),
                 shadow_drain_zones: Vec::new(),
+                attributes: BTreeMap::new(),
             };
 
             if trimmed.ends_with("/>") {
@@ -946,6 +1044,24 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
             continue;
         }
 
+        // Node attributes
+        if trimmed == "<attributes>" {
+            in_attributes = true;
+            continue;
+        }
+        if trimmed == "</attributes>" {
+            in_attributes = false;
+            continue;
+        }
+        if in_attributes && trimmed.starts_with("<attr ") {
+            let key = xml_unescape(&extract_attr(trimmed, "key").unwrap_or_default());
+            let value = xml_unescape(&extract_attr(trimmed, "value").unwrap_or_default());
+            if let Some(ref mut node) = current_node {
+                node.attributes.insert(key, value);
+            }
+            continue;
+        }
+
         // Link groups
         if trimmed.starts_with("<linkGroups ") {
             in_link_groups = true;
@@ -1057,6 +1173,20 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
             continue;
         }
 
+        // Source comments (Rust-only extension)
+        if trimmed == "<sourceComments>" {
+            in_source_comments = true;
+            continue;
+        }
+        if trimmed == "</sourceComments>" {
+            in_source_comments = false;
+            continue;
+        }
+        if in_source_comments && trimmed.starts_with("<comment ") {
+            source_comments.push(xml_unescape(&extract_attr(trimmed, "text").unwrap_or_default()));
+            continue;
+        }
+
         if (in_node_annots || in_link_annots || in_shadow_link_annots)
             && trimmed.starts_with("<annot ")
         {
@@ -1098,7 +1228,7 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
 
     // Add nodes to network in NID order (preserving insertion order)
     for pn in &sorted_nodes {
-        network.add_node(Node::new(pn.name.clone()));
+        network.add_node(Node::with_attributes(pn.name.clone(), pn.attributes.clone()));
     }
 
     // Add links to network
@@ -1117,6 +1247,22 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
     // Generate shadows (matching the way the original network was built)
     network.generate_shadows();
 
+    // Mark nodes with no incident links as lone nodes. BIF has no separate
+    // "lone node" marker of its own — a node with no `<link>` referencing
+    // its nid just never appears as a source/target — so this has to be
+    // inferred after the fact rather than parsed directly, the way the SIF
+    // and GW readers track it while reading.
+    let linked: std::collections::HashSet<NodeId> =
+        network.links().flat_map(|l| [l.source.clone(), l.target.clone()]).collect();
+    for pn in &sorted_nodes {
+        let id = NodeId::new(pn.name.as_str());
+        if !linked.contains(&id) {
+            network.add_lone_node(id);
+        }
+    }
+
+    network.metadata.source_comments = source_comments;
+
     // Build node layouts
     // Sort node_entries by row to build the layout
     let mut nodes_by_row = node_entries.clone();
@@ -1132,6 +1278,9 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
         nl.max_col_no_shadows = pn.max_col;
         nl.color_index = pn.color_index;
         nl.nid = Some(pn.nid);
+        if !pn.attributes.is_empty() {
+            layout.node_attributes.insert(NodeId::new(&pn.name), pn.attributes.clone());
+        }
         layout.nodes.insert(NodeId::new(&pn.name), nl);
     }
 
@@ -1205,9 +1354,11 @@ fn parse_bif_xml(xml: &str) -> Result<Session, ParseError> {
         network,
         layout: Some(layout),
         display_options: display,
-        alignment_scores: None,
+        alignment_scores,
         metadata: super::session::SessionMetadata::default(),
         plugin_data_lines,
+        comparison_panels: Vec::new(),
+        selection: crate::model::SelectionState::default(),
     })
 }
 
@@ -1224,6 +1375,7 @@ struct ParsedNode {
     color_index: usize,
     plain_drain_zones: Vec<(usize, usize)>,
     shadow_drain_zones: Vec<(usize, usize)>,
+    attributes: BTreeMap<String, String>,
 }
 
 /// Parsed link data from BIF XML.
@@ -1262,3 +1414,164 @@ fn xml_unescape(s: &str) -> String {
         .replace("&quot;", "\"")
         .replace("&apos;", "'")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::attribute::{apply_na_values, parse_na_string};
+    use crate::layout::{DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::worker::NoopMonitor;
+
+    #[test]
+    fn test_node_attribute_survives_bif_roundtrip_and_json_export() {
+        let mut network = Network::new();
+        network.add_link(Link::new("nodeA", "nodeB", "pp"));
+        network.add_link(Link::new("nodeB", "nodeC", "pp"));
+
+        let (attr_name, values) = parse_na_string("description\nnodeA = hub node\n").unwrap();
+        apply_na_values(&mut network, &attr_name, &values);
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(
+            layout.node_attributes[&NodeId::new("nodeA")].get("description"),
+            Some(&"hub node".to_string())
+        );
+
+        // Appears in the JSON export.
+        let json = serde_json::to_string(&layout).unwrap();
+        assert!(json.contains("hub node"));
+
+        // Survives a BIF write/read roundtrip.
+        let session = Session::with_layout(network, layout);
+        let xml = write_session_string(&session).unwrap();
+        assert!(xml.contains("hub node"));
+
+        let roundtripped = read_session_reader(BufReader::new(xml.as_bytes())).unwrap();
+        let rt_layout = roundtripped.layout.unwrap();
+        assert_eq!(
+            rt_layout.node_attributes[&NodeId::new("nodeA")].get("description"),
+            Some(&"hub node".to_string())
+        );
+        assert_eq!(
+            roundtripped
+                .network
+                .get_node(&NodeId::new("nodeA"))
+                .unwrap()
+                .get_attribute("description"),
+            Some("hub node")
+        );
+    }
+
+    #[test]
+    fn test_write_session_string_opts_can_omit_display_options() {
+        let mut network = Network::new();
+        network.add_link(Link::new("nodeA", "nodeB", "pp"));
+        network.add_link(Link::new("nodeB", "nodeC", "pp"));
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+        let session = Session::with_layout(network, layout);
+
+        let full = write_session_string(&session).unwrap();
+        assert!(full.contains("<displayOptions"));
+
+        let minimal = write_session_string_opts(
+            &session,
+            WriteOptions {
+                include_display: false,
+                include_annotations: true,
+            },
+        )
+        .unwrap();
+        assert!(!minimal.contains("<displayOptions"));
+
+        // The node/link sections are unaffected by omitting display options.
+        let extract_section = |xml: &str, tag: &str| -> String {
+            let start = xml.find(&format!("<{}", tag)).unwrap();
+            let end = xml.find(&format!("</{}>", tag)).unwrap() + tag.len() + 3;
+            xml[start..end].to_string()
+        };
+        assert_eq!(extract_section(&full, "nodes"), extract_section(&minimal, "nodes"));
+        assert_eq!(extract_section(&full, "links"), extract_section(&minimal, "links"));
+    }
+
+    #[test]
+    fn test_alignment_scores_survive_bif_roundtrip() {
+        let mut network = Network::new();
+        network.add_link(Link::new("nodeA", "nodeB", "pp"));
+        network.add_link(Link::new("nodeB", "nodeC", "pp"));
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+        let mut session = Session::with_layout(network, layout);
+        session.alignment_scores = Some(AlignmentScores {
+            ec: 0.75,
+            s3: 0.6,
+            ics: 0.8,
+            nc: Some(0.5),
+            ngs: Some(0.2),
+            lgs: Some(0.1),
+            js: Some(0.33),
+        });
+
+        let xml = write_session_string(&session).unwrap();
+        assert!(xml.contains("<org.systemsbiology.biofabric.plugin.core.align.NetworkAlignmentPlugIn>"));
+        assert!(xml.contains("<NetAlignMeasure name=\"Edge Coverage\" val=\"0.75\"/>"));
+
+        let roundtripped = read_session_reader(BufReader::new(xml.as_bytes())).unwrap();
+
+        let scores = roundtripped
+            .alignment_scores
+            .clone()
+            .expect("alignment scores should survive a BIF roundtrip");
+        assert_eq!(scores.ec, 0.75);
+        assert_eq!(scores.s3, 0.6);
+        assert_eq!(scores.ics, 0.8);
+        assert_eq!(scores.nc, Some(0.5));
+        assert_eq!(scores.ngs, Some(0.2));
+        assert_eq!(scores.lgs, Some(0.1));
+        assert_eq!(scores.js, Some(0.33));
+
+        // Writing the roundtripped session again produces identical
+        // plugin-data content, not a duplicated or drifted scores line.
+        let rewritten = write_session_string(&roundtripped).unwrap();
+        let extract_plugin_data = |xml: &str| -> String {
+            let start = xml.find("<plugInDataSets>").unwrap();
+            let end = xml.find("</plugInDataSets>").unwrap() + "</plugInDataSets>".len();
+            xml[start..end].to_string()
+        };
+        assert_eq!(extract_plugin_data(&xml), extract_plugin_data(&rewritten));
+        assert!(roundtripped.plugin_data_lines.is_empty());
+    }
+
+    #[test]
+    fn test_gw_source_comments_survive_bif_roundtrip() {
+        let content = "# exported from LEDA\nLEDA.GRAPH\nstring\nshort\n-2\n2\n|{A}|\n|{B}|\n1\n1 2 0 |{pp}|\n";
+        let network = crate::io::gw::parse_string(content).unwrap();
+        assert!(!network.metadata.source_comments.is_empty());
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+        let session = Session::with_layout(network.clone(), layout);
+
+        let xml = write_session_string(&session).unwrap();
+        assert!(xml.contains("<sourceComments>"));
+        assert!(xml.contains("exported from LEDA"));
+
+        let roundtripped = read_session_reader(BufReader::new(xml.as_bytes())).unwrap();
+        assert_eq!(
+            roundtripped.network.metadata.source_comments,
+            network.metadata.source_comments
+        );
+    }
+}
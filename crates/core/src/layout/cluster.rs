@@ -81,6 +81,20 @@ impl NodeClusterLayout {
         }
     }
 
+    /// Build a cluster layout from an attribute table, grouping nodes by the
+    /// named column instead of a single fixed attribute.
+    ///
+    /// Lets callers cluster the same `.noa` table by whichever column fits
+    /// the question at hand (pathway, compartment, experiment, ...) without
+    /// re-exporting a single-attribute file per grouping.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn from_attribute(table: &crate::io::attribute::AttributeTable, column: &str) -> Self {
+        Self::new(table.group_by(column))
+    }
+
     /// Set the cluster ordering mode.
     pub fn with_order(mut self, order: ClusterOrder) -> Self {
         self.params.cluster_order = order;
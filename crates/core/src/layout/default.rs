@@ -11,7 +11,9 @@
 
 use super::build_data::LayoutBuildData;
 use super::result::{LinkLayout, NetworkLayout, NodeLayout as NodeLayoutInfo};
-use super::traits::{EdgeLayout, LayoutError, LayoutMode, LayoutParams, LayoutResult, NodeLayout};
+use super::traits::{
+    BfsTiebreak, EdgeLayout, LayoutError, LayoutMode, LayoutParams, LayoutResult, NodeLayout,
+};
 use crate::model::{Annotation, AnnotationSet, Network, NodeId};
 use crate::worker::ProgressMonitor;
 use std::collections::HashMap;
@@ -31,12 +33,24 @@ use std::collections::HashMap;
 ///
 /// See Java implementation: `org.systemsbiology.biofabric.layouts.DefaultLayout`
 #[derive(Debug, Clone, Default)]
-pub struct DefaultNodeLayout;
+pub struct DefaultNodeLayout {
+    start_node: Option<NodeId>,
+}
 
 impl DefaultNodeLayout {
     /// Create a new default node layout.
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Anchor the BFS at `start_node` instead of the highest-degree node.
+    ///
+    /// Falls back to the usual highest-degree rule if `start_node` turns
+    /// out not to be in the network. Takes precedence over
+    /// [`LayoutParams::start_node`] when both are set.
+    pub fn with_start_node(mut self, start_node: NodeId) -> Self {
+        self.start_node = Some(start_node);
+        self
     }
 }
 
@@ -76,14 +90,25 @@ impl NodeLayout for DefaultNodeLayout {
             }
         }
 
+        // `params.weight_ordered_bfs` is accepted but has no effect here:
+        // the BFS below orders neighbors by `node_cmp` alone and never
+        // reads `Link::weight` (see the field's doc comment).
+        let _ = params.weight_ordered_bfs;
+
         // Java's NID.WithName.compareTo: compares by name (case-sensitive) first,
         // then by NID as tiebreaker. Since NodeId::Ord already does case-sensitive
         // lexicographic comparison on the name string, this matches our NodeId ordering.
         // Degree-ranked comparator: degree desc, then name asc (via NodeId::Ord).
+        // See `BfsTiebreak`'s doc comment for what each policy produces.
         let node_cmp = |a: &NodeId, b: &NodeId| -> std::cmp::Ordering {
-            let deg_a = degree_map.get(a).copied().unwrap_or(0);
-            let deg_b = degree_map.get(b).copied().unwrap_or(0);
-            deg_b.cmp(&deg_a).then_with(|| a.cmp(b))
+            match params.bfs_tiebreak {
+                BfsTiebreak::Alphabetical => a.cmp(b),
+                BfsTiebreak::JavaCompat | BfsTiebreak::DegreeThenName => {
+                    let deg_a = degree_map.get(a).copied().unwrap_or(0);
+                    let deg_b = degree_map.get(b).copied().unwrap_or(0);
+                    deg_b.cmp(&deg_a).then_with(|| a.cmp(b))
+                }
+            }
         };
 
         // Build degree-ranked list of non-lone nodes
@@ -94,11 +119,14 @@ impl NodeLayout for DefaultNodeLayout {
             .collect();
         ranked_nodes.sort_by(|a, b| node_cmp(a, b));
 
-        // Determine starting nodes
-        let start_nodes: Vec<NodeId> = if let Some(ref start) = params.start_node {
-            vec![start.clone()]
-        } else {
-            Vec::new()
+        // Determine starting nodes. `self.start_node` (set via
+        // `with_start_node`) takes precedence over `params.start_node`; a
+        // node that doesn't actually exist in the network is ignored and
+        // the usual highest-degree rule takes over instead.
+        let requested_start = self.start_node.as_ref().or(params.start_node.as_ref());
+        let start_nodes: Vec<NodeId> = match requested_start {
+            Some(start) if network.contains_node(start) => vec![start.clone()],
+            _ => Vec::new(),
         };
 
         // Helper: find next highest-degree unplaced node from the ranked list
@@ -211,6 +239,41 @@ impl DefaultEdgeLayout {
         Self
     }
 
+    /// Recompute a layout for `network`, reusing `existing_order` for the
+    /// row of every node it already contains and only recomputing column
+    /// assignments. Nodes present in `network` but not in `existing_order`
+    /// (e.g. new endpoints introduced by edges added since the last layout)
+    /// are appended as new rows, in network iteration order, after it.
+    ///
+    /// Intended for interactive editing: after calling
+    /// [`Network::add_links`] to add a handful of edges to an
+    /// already-laid-out network, this avoids the visual churn of a full
+    /// [`NetworkLayoutAlgorithm::layout`](super::NetworkLayoutAlgorithm::layout)
+    /// call, which would also recompute node order from scratch.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn relayout_with_fixed_nodes(
+        &self,
+        network: &Network,
+        existing_order: &[NodeId],
+        params: &LayoutParams,
+        monitor: &dyn ProgressMonitor,
+    ) -> LayoutResult<NetworkLayout> {
+        let mut node_order: Vec<NodeId> = existing_order.to_vec();
+        let existing: std::collections::HashSet<NodeId> = node_order.iter().cloned().collect();
+        for id in network.node_ids() {
+            if !existing.contains(id) {
+                node_order.push(id.clone());
+            }
+        }
+
+        let has_shadows = network.has_shadows();
+        let mut build_data = LayoutBuildData::new(network.clone(), node_order, has_shadows, params.layout_mode);
+        self.layout_edges(&mut build_data, params, monitor)
+    }
+
     /// Calculate link group annotations for the placed links.
     ///
     /// Ported from `DefaultEdgeLayout.calcGroupLinkAnnots()` in Java.
@@ -390,7 +453,7 @@ impl EdgeLayout for DefaultEdgeLayout {
         _params: &LayoutParams,
         _monitor: &dyn ProgressMonitor,
     ) -> LayoutResult<NetworkLayout> {
-        use super::link_group::{ColumnAssigner, LinkSortKey};
+        use super::link_group::{ColumnAssigner, LinkSortKey, LinkSortMode};
 
         let node_to_row = &build_data.node_to_row;
         let link_groups = _params.link_groups.as_ref();
@@ -449,28 +512,67 @@ impl EdgeLayout for DefaultEdgeLayout {
         //   so group comparison always ties).
         let has_groups = link_groups.is_some();
         let is_per_network = build_data.layout_mode == LayoutMode::PerNetwork;
+        let sort_mode = _params.link_sort;
+
+        // When bundling is requested, every pair of nodes gets a single
+        // ordinal (assigned in first-encountered order) that dominates the
+        // usual comparator, so all links between the same two nodes land in
+        // adjacent columns regardless of relation, group, or direction.
+        let bundle_multiedges = _params.bundle_multiedges;
+        let pair_ordinal: HashMap<(usize, usize), usize> = if bundle_multiedges {
+            let mut map = HashMap::new();
+            for (_, key) in &indexed_links {
+                let pair = (key.source_row.min(key.target_row), key.source_row.max(key.target_row));
+                let next = map.len();
+                map.entry(pair).or_insert(next);
+            }
+            map
+        } else {
+            HashMap::new()
+        };
+        let bundle_key = |key: &LinkSortKey| -> usize {
+            let pair = (key.source_row.min(key.target_row), key.source_row.max(key.target_row));
+            pair_ordinal.get(&pair).copied().unwrap_or(0)
+        };
 
         if is_per_network && has_groups {
             indexed_links.sort_by(|(_, a), (_, b)| {
-                a.group_ordinal
-                    .cmp(&b.group_ordinal)
+                bundle_key(a)
+                    .cmp(&bundle_key(b))
+                    .then(a.group_ordinal.cmp(&b.group_ordinal))
                     .then(a.anchor_row.cmp(&b.anchor_row))
                     .then(b.is_shadow.cmp(&a.is_shadow))
-                    .then(a.far_row.cmp(&b.far_row))
+                    .then(a.sort_value(sort_mode).cmp(&b.sort_value(sort_mode)))
                     .then(a.direction_ordinal.cmp(&b.direction_ordinal))
                     .then(a.relation.cmp(&b.relation))
             });
         } else if has_groups {
             // Per-node: anchor → group → shadow → far → dir → relation
             indexed_links.sort_by(|(_, a), (_, b)| {
-                a.anchor_row
-                    .cmp(&b.anchor_row)
+                bundle_key(a)
+                    .cmp(&bundle_key(b))
+                    .then(a.anchor_row.cmp(&b.anchor_row))
                     .then(a.group_ordinal.cmp(&b.group_ordinal))
                     .then(b.is_shadow.cmp(&a.is_shadow))
-                    .then(a.far_row.cmp(&b.far_row))
+                    .then(a.sort_value(sort_mode).cmp(&b.sort_value(sort_mode)))
                     .then(a.direction_ordinal.cmp(&b.direction_ordinal))
                     .then(a.relation.cmp(&b.relation))
             });
+        } else if sort_mode != LinkSortMode::FarRow {
+            // No groups, but a non-default sort mode still applies to the
+            // anchor/shadow tie-break.
+            indexed_links.sort_by(|(_, a), (_, b)| {
+                bundle_key(a)
+                    .cmp(&bundle_key(b))
+                    .then(a.anchor_row.cmp(&b.anchor_row))
+                    .then(b.is_shadow.cmp(&a.is_shadow))
+                    .then(a.group_ordinal.cmp(&b.group_ordinal))
+                    .then(a.sort_value(sort_mode).cmp(&b.sort_value(sort_mode)))
+                    .then(a.direction_ordinal.cmp(&b.direction_ordinal))
+                    .then(a.relation.cmp(&b.relation))
+            });
+        } else if bundle_multiedges {
+            indexed_links.sort_by(|(_, a), (_, b)| bundle_key(a).cmp(&bundle_key(b)).then_with(|| a.cmp(b)));
         } else {
             // Default: use existing LinkSortKey::Ord (all group_ordinals are 0)
             indexed_links.sort_by(|(_, a), (_, b)| a.cmp(b));
@@ -516,6 +618,8 @@ impl EdgeLayout for DefaultEdgeLayout {
             );
             ll.column_no_shadows = column_no_shadows;
             ll.color_index = column; // Color derived from shadow column index
+            ll.weight = link.weight;
+            ll.directed = link.directed;
 
             // Update node spans
             if let Some(src_layout) = layout.nodes.get_mut(&link.source) {
@@ -665,6 +769,7 @@ pub fn layout_from_fixed_link_order(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::link_group::LinkSortMode;
     use crate::model::Link;
 
     #[allow(dead_code)]
@@ -716,4 +821,312 @@ mod tests {
     //     assert!(result.get_node(&NodeId::new("B")).is_some());
     //     assert!(result.get_node(&NodeId::new("C")).is_some());
     // }
+
+    #[test]
+    fn test_relayout_with_fixed_nodes_keeps_original_rows_after_adding_an_edge() {
+        use super::super::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+        use crate::worker::NoopMonitor;
+
+        let triangle = crate::io::sif::parse_string("A\tpp\tB\nB\tpp\tC\nA\tpp\tC\n").unwrap();
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let original = layout_algo.layout(&triangle, &LayoutParams::default(), &NoopMonitor).unwrap();
+
+        let existing_order: Vec<NodeId> = {
+            let mut entries: Vec<_> = original.iter_nodes().collect();
+            entries.sort_by_key(|(_, nl)| nl.row);
+            entries.into_iter().map(|(id, _)| id.clone()).collect()
+        };
+
+        let mut edited = triangle.clone();
+        edited.add_links([Link::new("A", "D", "pp")]);
+
+        let edge_layout = DefaultEdgeLayout::new();
+        let relaid = edge_layout
+            .relayout_with_fixed_nodes(&edited, &existing_order, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        for id in &existing_order {
+            assert_eq!(
+                relaid.get_node(id).unwrap().row,
+                original.get_node(id).unwrap().row,
+                "node {id:?} should keep its original row after an incremental relayout"
+            );
+        }
+        // The new node is appended, not interleaved among the original rows.
+        assert_eq!(relaid.get_node(&NodeId::new("D")).unwrap().row, existing_order.len());
+    }
+
+    #[test]
+    fn test_max_nodes_truncates_to_top_degree_subnetwork() {
+        use super::super::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+        use crate::worker::NoopMonitor;
+
+        // Star network: one hub connected to 500 leaves.
+        let mut network = Network::new();
+        for i in 0..500 {
+            network.add_link(Link::new("hub", format!("leaf{i}"), "r"));
+        }
+
+        let layout = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let params = LayoutParams {
+            max_nodes: Some(100),
+            ..Default::default()
+        };
+        let result = layout.layout(&network, &params, &NoopMonitor).unwrap();
+
+        assert!(result.truncated);
+        assert_eq!(result.row_count, 100);
+        assert!(result.get_node(&NodeId::new("hub")).is_some());
+
+        // Untruncated layout leaves the flag unset.
+        let full = layout.layout(&network, &LayoutParams::default(), &NoopMonitor).unwrap();
+        assert!(!full.truncated);
+        assert_eq!(full.row_count, 501);
+    }
+
+    #[test]
+    fn test_drop_self_loops_excludes_feedback_links_and_shrinks_columns() {
+        use super::super::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+        use crate::worker::NoopMonitor;
+
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        network.add_link(Link::new("A", "A", "pp"));
+
+        let layout = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+
+        let kept = layout.layout(&network, &LayoutParams::default(), &NoopMonitor).unwrap();
+        assert_eq!(kept.links.len(), 2);
+        assert!(kept.links.iter().any(|ll| ll.source == ll.target));
+        assert_eq!(kept.column_count, 2);
+
+        let params = LayoutParams {
+            drop_self_loops: true,
+            ..Default::default()
+        };
+        let dropped = layout.layout(&network, &params, &NoopMonitor).unwrap();
+        assert_eq!(dropped.links.len(), 1);
+        assert!(!dropped.links.iter().any(|ll| ll.source == ll.target));
+        assert_eq!(dropped.column_count, 1);
+    }
+
+    #[test]
+    fn test_weight_ordered_bfs_flag_is_a_noop_without_edge_weights() {
+        use super::super::traits::LayoutParams;
+        use crate::worker::NoopMonitor;
+
+        // Triangle: A-B, B-C, A-C, none of them weighted. Turning on
+        // `weight_ordered_bfs` must produce the exact same node order as
+        // leaving it off.
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "r"));
+        network.add_link(Link::new("B", "C", "r"));
+        network.add_link(Link::new("A", "C", "r"));
+
+        let layout = DefaultNodeLayout::new();
+        let monitor = NoopMonitor;
+
+        let default_order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &monitor)
+            .unwrap();
+        let weighted_order = layout
+            .layout_nodes(
+                &network,
+                &LayoutParams {
+                    weight_ordered_bfs: true,
+                    ..Default::default()
+                },
+                &monitor,
+            )
+            .unwrap();
+
+        assert_eq!(default_order, weighted_order);
+    }
+
+    #[test]
+    fn test_bfs_tiebreak_alphabetical_orders_neighbors_by_name_and_differs_from_java_compat() {
+        use super::super::traits::{BfsTiebreak, LayoutParams};
+        use crate::worker::NoopMonitor;
+
+        // "hub" connects to both "A" (degree 1) and "Z" (degree 2, via the
+        // extra hub-independent edge to "extra"). JavaCompat visits the
+        // higher-degree neighbor ("Z") first; Alphabetical visits by name
+        // regardless of degree, so it visits "A" first.
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "A", "r"));
+        network.add_link(Link::new("hub", "Z", "r"));
+        network.add_link(Link::new("Z", "extra", "r"));
+
+        let layout = DefaultNodeLayout::new();
+        let monitor = NoopMonitor;
+        let start_node = Some(NodeId::new("hub"));
+
+        let java_compat_order = layout
+            .layout_nodes(
+                &network,
+                &LayoutParams {
+                    start_node: start_node.clone(),
+                    bfs_tiebreak: BfsTiebreak::JavaCompat,
+                    ..Default::default()
+                },
+                &monitor,
+            )
+            .unwrap();
+        assert_eq!(
+            java_compat_order,
+            vec![
+                NodeId::new("hub"),
+                NodeId::new("Z"),
+                NodeId::new("A"),
+                NodeId::new("extra"),
+            ]
+        );
+
+        let alphabetical_order = layout
+            .layout_nodes(
+                &network,
+                &LayoutParams {
+                    start_node,
+                    bfs_tiebreak: BfsTiebreak::Alphabetical,
+                    ..Default::default()
+                },
+                &monitor,
+            )
+            .unwrap();
+        assert_eq!(
+            alphabetical_order,
+            vec![
+                NodeId::new("hub"),
+                NodeId::new("A"),
+                NodeId::new("Z"),
+                NodeId::new("extra"),
+            ]
+        );
+
+        assert_ne!(java_compat_order, alphabetical_order);
+    }
+
+    #[test]
+    fn test_link_sort_mode_changes_column_order_within_group() {
+        use crate::worker::NoopMonitor;
+
+        // "hub" is the top row for all three links below, so they all land
+        // in the same (hub, "r") link group. Their far/target rows differ
+        // so FarRow vs TargetRow tie-breaking produces different orders.
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "r")); // target_row = 1
+        network.add_link(Link::new("leafB", "hub", "r")); // target_row = 0 (hub)
+        network.add_link(Link::new("hub", "leafC", "r")); // target_row = 2
+
+        let node_order = vec![
+            NodeId::new("hub"),
+            NodeId::new("leafA"),
+            NodeId::new("leafC"),
+            NodeId::new("leafB"),
+        ];
+
+        let edge_layout = DefaultEdgeLayout::new();
+
+        let far_row_params = LayoutParams {
+            link_groups: Some(vec!["r".to_string()]),
+            ..Default::default()
+        };
+        let mut build_data =
+            LayoutBuildData::new(network.clone(), node_order.clone(), false, LayoutMode::PerNode);
+        let far_row_layout = edge_layout
+            .layout_edges(&mut build_data, &far_row_params, &NoopMonitor)
+            .unwrap();
+
+        let target_row_params = LayoutParams {
+            link_groups: Some(vec!["r".to_string()]),
+            link_sort: LinkSortMode::TargetRow,
+            ..Default::default()
+        };
+        let mut build_data =
+            LayoutBuildData::new(network, node_order, false, LayoutMode::PerNode);
+        let target_row_layout = edge_layout
+            .layout_edges(&mut build_data, &target_row_params, &NoopMonitor)
+            .unwrap();
+
+        let column_of = |layout: &NetworkLayout, src: &str, tgt: &str| {
+            layout
+                .links
+                .iter()
+                .find(|l| l.source.as_str() == src && l.target.as_str() == tgt)
+                .unwrap()
+                .column
+        };
+
+        // Under FarRow, "leafB" (far_row=3, the largest) sorts last; under
+        // TargetRow (target_row=0, the smallest) it sorts first.
+        let far_row_leaf_b_col = column_of(&far_row_layout, "leafB", "hub");
+        let target_row_leaf_b_col = column_of(&target_row_layout, "leafB", "hub");
+        assert_ne!(far_row_leaf_b_col, target_row_leaf_b_col);
+    }
+
+    #[test]
+    fn test_bundle_multiedges_places_parallel_relations_in_adjacent_columns() {
+        use crate::worker::NoopMonitor;
+
+        // A-B has two parallel relations ("pp" and "pd"); A-C ("aa") is a
+        // third link anchored at A that must not land between them.
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        network.add_link(Link::new("A", "C", "aa"));
+        network.add_link(Link::new("A", "B", "pd"));
+
+        let node_order = vec![NodeId::new("A"), NodeId::new("B"), NodeId::new("C")];
+        let edge_layout = DefaultEdgeLayout::new();
+
+        let params = LayoutParams {
+            bundle_multiedges: true,
+            ..Default::default()
+        };
+        let mut build_data = LayoutBuildData::new(network, node_order, false, LayoutMode::PerNode);
+        let layout = edge_layout.layout_edges(&mut build_data, &params, &NoopMonitor).unwrap();
+
+        let column_of = |relation: &str| {
+            layout
+                .links
+                .iter()
+                .find(|l| l.relation == relation)
+                .unwrap()
+                .column
+        };
+
+        let pp_col = column_of("pp");
+        let pd_col = column_of("pd");
+        assert_eq!(pp_col.abs_diff(pd_col), 1, "bundled A-B links should land in adjacent columns");
+    }
+
+    #[test]
+    fn test_with_start_node_places_requested_node_at_row_zero() {
+        use crate::worker::NoopMonitor;
+
+        let network = create_test_network();
+        let layout = DefaultNodeLayout::new().with_start_node(NodeId::new("C"));
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order[0], NodeId::new("C"));
+    }
+
+    #[test]
+    fn test_with_start_node_falls_back_when_node_is_absent() {
+        use crate::worker::NoopMonitor;
+
+        let network = create_test_network();
+        let default_order = DefaultNodeLayout::new()
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let layout = DefaultNodeLayout::new().with_start_node(NodeId::new("NOT_IN_NETWORK"));
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order, default_order);
+    }
 }
@@ -0,0 +1,89 @@
+//! Degree-sorted layout.
+//!
+//! The simplest non-default node layout: orders every node purely by
+//! descending degree, then ascending name. No BFS, no neighbor-aware
+//! tie-breaking — just "hubs at the top." Useful as a quick baseline and
+//! for networks where users specifically want the highest-degree nodes
+//! grouped together regardless of connectivity.
+//!
+//! ## References
+//!
+//! (none — not in the Java original)
+
+use super::traits::{LayoutParams, LayoutResult, NodeLayout};
+use crate::model::{Network, NodeId};
+use crate::worker::ProgressMonitor;
+
+/// Degree-sorted node layout: descending degree, then ascending name.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DegreeSortLayout;
+
+impl DegreeSortLayout {
+    /// Create a new degree-sorted layout.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl NodeLayout for DegreeSortLayout {
+    fn layout_nodes(
+        &self,
+        network: &Network,
+        _params: &LayoutParams,
+        _monitor: &dyn ProgressMonitor,
+    ) -> LayoutResult<Vec<NodeId>> {
+        let mut nodes: Vec<NodeId> = network.node_ids().cloned().collect();
+        nodes.sort_by(|a, b| network.degree(b).cmp(&network.degree(a)).then_with(|| a.cmp(b)));
+        Ok(nodes)
+    }
+
+    fn name(&self) -> &'static str {
+        "Degree Sort"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::default::DefaultEdgeLayout;
+    use crate::layout::traits::{NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    fn star_network(spokes: usize) -> Network {
+        let mut network = Network::new();
+        for i in 1..=spokes {
+            network.add_link(Link::new("hub", format!("n{i}"), "pp"));
+        }
+        network
+    }
+
+    #[test]
+    fn test_degree_sort_places_hub_first_and_spokes_in_name_order() {
+        // Same shape as `star-500.sif` (a single hub connected to every
+        // other node), scaled down for a fast unit test.
+        let network = star_network(10);
+        let layout = DegreeSortLayout::new();
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order[0].as_str(), "hub");
+        let spokes: Vec<&str> = order[1..].iter().map(|id| id.as_str()).collect();
+        let mut sorted_spokes = spokes.clone();
+        sorted_spokes.sort_unstable();
+        assert_eq!(spokes, sorted_spokes);
+    }
+
+    #[test]
+    fn test_degree_sort_edge_layout_runs_cleanly_on_the_result() {
+        let network = star_network(10);
+        let layout = TwoPhaseLayout::new(DegreeSortLayout::new(), DefaultEdgeLayout::new());
+        let result = layout
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(result.links.len(), network.link_count());
+        assert_eq!(result.row_count, network.node_count());
+    }
+}
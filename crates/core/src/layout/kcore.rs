@@ -0,0 +1,97 @@
+//! k-core node layout.
+//!
+//! Orders nodes by descending [`core_numbers`](crate::analysis::core_numbers)
+//! — the k-core decomposition of the undirected graph — so that the densest,
+//! most mutually-interconnected group of nodes lands together at the top of
+//! the fabric, with progressively more peripheral shells following below.
+//! Ties are broken by descending degree, then ascending name.
+//!
+//! ## References
+//!
+//! (none — not in the Java original)
+
+use super::traits::{LayoutParams, LayoutResult, NodeLayout};
+use crate::analysis::core_numbers;
+use crate::model::{Network, NodeId};
+use crate::worker::ProgressMonitor;
+
+/// k-core node layout: descending core number, then descending degree,
+/// then ascending name.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KCoreLayout;
+
+impl KCoreLayout {
+    /// Create a new k-core layout.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl NodeLayout for KCoreLayout {
+    fn layout_nodes(
+        &self,
+        network: &Network,
+        _params: &LayoutParams,
+        _monitor: &dyn ProgressMonitor,
+    ) -> LayoutResult<Vec<NodeId>> {
+        let core = core_numbers(network);
+        let mut nodes: Vec<NodeId> = network.node_ids().cloned().collect();
+        nodes.sort_by(|a, b| {
+            core[a]
+                .cmp(&core[b])
+                .reverse()
+                .then_with(|| network.degree(b).cmp(&network.degree(a)))
+                .then_with(|| a.cmp(b))
+        });
+        Ok(nodes)
+    }
+
+    fn name(&self) -> &'static str {
+        "k-Core"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::default::DefaultEdgeLayout;
+    use crate::layout::traits::{NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    fn clique_with_pendant() -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        network.add_link(Link::new("A", "C", "pp"));
+        network.add_link(Link::new("B", "C", "pp"));
+        network.add_link(Link::new("C", "Pendant", "pp"));
+        network
+    }
+
+    #[test]
+    fn test_kcore_places_the_clique_before_the_pendant() {
+        let network = clique_with_pendant();
+        let layout = KCoreLayout::new();
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order.last().unwrap().as_str(), "Pendant");
+        let clique: Vec<&str> = order[..3].iter().map(|id| id.as_str()).collect();
+        let mut sorted_clique = clique.clone();
+        sorted_clique.sort_unstable();
+        assert_eq!(sorted_clique, vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn test_kcore_edge_layout_runs_cleanly_on_the_result() {
+        let network = clique_with_pendant();
+        let layout = TwoPhaseLayout::new(KCoreLayout::new(), DefaultEdgeLayout::new());
+        let result = layout
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(result.links.len(), network.link_count());
+        assert_eq!(result.row_count, network.node_count());
+    }
+}
@@ -167,6 +167,32 @@ impl LinkGroupIndex {
 // Edge sort key
 // ============================================================================
 
+/// Which field breaks ties between links anchored at the same node and
+/// group ordinal, controlling the visual "fanning" pattern within a
+/// relation block.
+///
+/// ## References
+///
+/// - Java: `DefaultFabricLinkLocater` always sorts by the far/bottom row;
+///   this enum generalizes that single choice into a user-selectable one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LinkSortMode {
+    /// Sort by the "far" row (bottom row for regular links, top row for
+    /// shadows). This matches the original Java ordering.
+    #[default]
+    FarRow,
+
+    /// Sort by the link's source row, regardless of shadow/regular status.
+    SourceRow,
+
+    /// Sort by the link's target row, regardless of shadow/regular status.
+    TargetRow,
+
+    /// Sort by the vertical span of the link (`|source_row - target_row|`),
+    /// shortest first.
+    SpanLength,
+}
+
 /// Composite sort key for a single link during edge layout.
 ///
 /// Encodes the multi-key comparator from Java's `DefaultFabricLinkLocater`:
@@ -192,6 +218,12 @@ pub struct LinkSortKey {
     /// The "far" row: bottom_row for regular links, top_row for shadows.
     pub far_row: usize,
 
+    /// The link's original source row, independent of shadow/anchor status.
+    pub source_row: usize,
+
+    /// The link's original target row, independent of shadow/anchor status.
+    pub target_row: usize,
+
     /// Directionality ordinal: undirected=0, directed-down=1, directed-up=2.
     pub direction_ordinal: u8,
 
@@ -252,10 +284,23 @@ impl LinkSortKey {
             is_shadow: link.is_shadow,
             group_ordinal,
             far_row,
+            source_row,
+            target_row,
             direction_ordinal,
             relation: link.relation.clone(),
         }
     }
+
+    /// The tie-break value selected by `mode`, used to order links within
+    /// the same anchor row and group ordinal.
+    pub fn sort_value(&self, mode: LinkSortMode) -> usize {
+        match mode {
+            LinkSortMode::FarRow => self.far_row,
+            LinkSortMode::SourceRow => self.source_row,
+            LinkSortMode::TargetRow => self.target_row,
+            LinkSortMode::SpanLength => self.source_row.abs_diff(self.target_row),
+        }
+    }
 }
 
 impl Ord for LinkSortKey {
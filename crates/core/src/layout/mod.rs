@@ -19,33 +19,51 @@
 //! | [`ControlTopLayout`] | Control nodes at top | `ControlTopLayout` |
 //! | [`SetLayout`] | Set membership (bipartite) | `SetLayout` |
 //! | [`WorldBankLayout`] | Hub-spoke grouping | `WorldBankLayout` |
+//! | [`SpectralLayout`] | Fiedler vector ordering | (none — not in the Java original) |
+//! | [`RcmLayout`] | Reverse Cuthill-McKee bandwidth minimization | (none — not in the Java original) |
+//! | [`DegreeSortLayout`] | Pure descending-degree ordering | (none — not in the Java original) |
+//! | [`KCoreLayout`] | k-core decomposition ordering | (none — not in the Java original) |
+//! | [`BarycenterLayout`] | Median-neighbor crossing-reduction sweeps | (none — not in the Java original) |
+//! | [`PageRankLayout`] | Descending PageRank score ordering | (none — not in the Java original) |
 //!
 //! ## Layout Result
 //!
 //! The [`NetworkLayout`] struct contains the computed layout. See [`result`]
 //! for the output data structures.
 
+pub mod barycenter;
 pub mod build_data;
 pub mod cluster;
 pub mod control_top;
 pub mod default;
+pub mod degree_sort;
 pub mod hierarchy;
+pub mod kcore;
 pub mod link_group;
+pub mod pagerank;
+pub mod rcm;
 pub mod result;
 pub mod set;
 pub mod similarity;
+pub mod spectral;
 pub mod traits;
 pub mod world_bank;
 
 // Re-export key types
+pub use barycenter::BarycenterLayout;
 pub use build_data::{AlignmentBuildData, LayoutBuildData};
 pub use cluster::NodeClusterLayout;
 pub use control_top::ControlTopLayout;
 pub use default::{layout_from_fixed_link_order, DefaultEdgeLayout, DefaultNodeLayout};
+pub use degree_sort::DegreeSortLayout;
 pub use hierarchy::HierDAGLayout;
-pub use link_group::{ColumnAssigner, LinkGroup, LinkGroupIndex, LinkSortKey};
-pub use result::{LinkLayout, NetworkLayout, NodeLayout as NodeLayoutInfo};
+pub use kcore::KCoreLayout;
+pub use link_group::{ColumnAssigner, LinkGroup, LinkGroupIndex, LinkSortKey, LinkSortMode};
+pub use pagerank::PageRankLayout;
+pub use rcm::RcmLayout;
+pub use result::{CacheError, LinkLayout, NetworkLayout, NodeLayout as NodeLayoutInfo};
 pub use set::SetLayout;
 pub use similarity::NodeSimilarityLayout;
+pub use spectral::SpectralLayout;
 pub use traits::{EdgeLayout, LayoutError, LayoutMode, LayoutParams, NetworkLayoutAlgorithm, NodeLayout, TwoPhaseLayout};
 pub use world_bank::WorldBankLayout;
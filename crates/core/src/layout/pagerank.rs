@@ -0,0 +1,100 @@
+//! PageRank-ordered layout.
+//!
+//! Orders nodes by descending PageRank score (ties broken by ascending
+//! name), using [`analysis::centrality::pagerank`](crate::analysis::centrality::pagerank)
+//! as the node-ordering seed. Unlike [`DegreeSortLayout`](super::DegreeSortLayout),
+//! this accounts for a neighbor's own importance, not just raw degree.
+//!
+//! ## References
+//!
+//! (none — not in the Java original)
+
+use super::traits::{LayoutParams, LayoutResult, NodeLayout};
+use crate::analysis::centrality::pagerank;
+use crate::model::{Network, NodeId};
+use crate::worker::ProgressMonitor;
+
+/// Standard PageRank damping factor.
+const DEFAULT_DAMPING: f64 = 0.85;
+
+/// Number of power-iteration rounds run before ordering nodes.
+const DEFAULT_ITERS: usize = 100;
+
+/// PageRank-sorted node layout: descending PageRank score, then ascending
+/// name.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PageRankLayout;
+
+impl PageRankLayout {
+    /// Create a new PageRank-sorted layout.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl NodeLayout for PageRankLayout {
+    fn layout_nodes(
+        &self,
+        network: &Network,
+        _params: &LayoutParams,
+        _monitor: &dyn ProgressMonitor,
+    ) -> LayoutResult<Vec<NodeId>> {
+        let scores = pagerank(network, DEFAULT_DAMPING, DEFAULT_ITERS);
+        let mut nodes: Vec<NodeId> = network.node_ids().cloned().collect();
+        nodes.sort_by(|a, b| {
+            scores[b]
+                .partial_cmp(&scores[a])
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.cmp(b))
+        });
+        Ok(nodes)
+    }
+
+    fn name(&self) -> &'static str {
+        "PageRank"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::default::DefaultEdgeLayout;
+    use crate::layout::traits::{NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    fn star_network(spokes: usize) -> Network {
+        let mut network = Network::new();
+        for i in 1..=spokes {
+            network.add_link(Link::new("hub", format!("n{i}"), "pp"));
+        }
+        network
+    }
+
+    #[test]
+    fn test_pagerank_layout_places_hub_first_and_spokes_in_name_order() {
+        let network = star_network(10);
+        let layout = PageRankLayout::new();
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order[0].as_str(), "hub");
+        let spokes: Vec<&str> = order[1..].iter().map(|id| id.as_str()).collect();
+        let mut sorted_spokes = spokes.clone();
+        sorted_spokes.sort_unstable();
+        assert_eq!(spokes, sorted_spokes);
+    }
+
+    #[test]
+    fn test_pagerank_layout_edge_layout_runs_cleanly_on_the_result() {
+        let network = star_network(10);
+        let layout = TwoPhaseLayout::new(PageRankLayout::new(), DefaultEdgeLayout::new());
+        let result = layout
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(result.links.len(), network.link_count());
+        assert_eq!(result.row_count, network.node_count());
+    }
+}
@@ -0,0 +1,226 @@
+//! Reverse Cuthill–McKee (RCM) bandwidth-minimizing layout.
+//!
+//! Orders nodes so that connected nodes end up close together in the
+//! layout's row order, which minimizes the "bandwidth" (the maximum
+//! row-distance spanned by any link) — a classic sparse-matrix reordering
+//! technique that also makes for a tight, readable fabric.
+//!
+//! ## Algorithm
+//!
+//! Standard Cuthill–McKee, reversed:
+//!
+//! 1. For each connected component, pick the lowest-degree node (ties
+//!    broken by name) as the starting node.
+//! 2. Breadth-first traverse from it, but instead of visiting a level at a
+//!    time, process one queued node at a time and enqueue its unvisited
+//!    neighbors sorted by ascending degree (ties broken by name).
+//! 3. Concatenate components in the order they're first reached (again by
+//!    lowest-degree unvisited node), then reverse the whole sequence — the
+//!    "reverse" in reverse Cuthill–McKee, which empirically tends to
+//!    produce a smaller bandwidth than the un-reversed order.
+//!
+//! Nodes with no non-shadow edges can't be placed by this method and are
+//! appended at the end, sorted by name — matching the "lone nodes" handling
+//! used by the other node layouts in this module.
+//!
+//! ## References
+//!
+//! - Cuthill, E.; McKee, J. (1969). "Reducing the bandwidth of sparse
+//!   symmetric matrices."
+//! - George, A.; Liu, J. W. H. (1981). "Computer Solution of Large Sparse
+//!   Positive Definite Systems."
+
+use super::traits::{LayoutParams, LayoutResult, NodeLayout};
+use crate::model::{Network, NodeId};
+use crate::worker::ProgressMonitor;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Reverse Cuthill–McKee bandwidth-minimizing node layout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RcmLayout;
+
+impl RcmLayout {
+    /// Create a new RCM layout.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl NodeLayout for RcmLayout {
+    fn layout_nodes(
+        &self,
+        network: &Network,
+        _params: &LayoutParams,
+        _monitor: &dyn ProgressMonitor,
+    ) -> LayoutResult<Vec<NodeId>> {
+        // Unique, non-shadow, non-self-loop adjacency as a simple graph.
+        let mut neighbors: HashMap<NodeId, BTreeSet<NodeId>> = HashMap::new();
+        for link in network.links() {
+            if link.is_shadow || link.source == link.target {
+                continue;
+            }
+            neighbors
+                .entry(link.source.clone())
+                .or_default()
+                .insert(link.target.clone());
+            neighbors
+                .entry(link.target.clone())
+                .or_default()
+                .insert(link.source.clone());
+        }
+
+        let degree = |id: &NodeId| -> usize { neighbors.get(id).map(BTreeSet::len).unwrap_or(0) };
+        let by_degree_then_name = |a: &NodeId, b: &NodeId| degree(a).cmp(&degree(b)).then_with(|| a.cmp(b));
+
+        let mut connected: BTreeSet<NodeId> = BTreeSet::new();
+        for (node, nbrs) in &neighbors {
+            if !nbrs.is_empty() {
+                connected.insert(node.clone());
+            }
+        }
+
+        let mut visited: HashSet<NodeId> = HashSet::new();
+        let mut cm_order: Vec<NodeId> = Vec::with_capacity(connected.len());
+
+        // Lowest-degree unvisited node starts each successive component.
+        while let Some(start) = connected
+            .iter()
+            .filter(|id| !visited.contains(*id))
+            .min_by(|a, b| by_degree_then_name(a, b))
+            .cloned()
+        {
+            let mut queue: VecDeque<NodeId> = VecDeque::new();
+            queue.push_back(start.clone());
+            visited.insert(start);
+
+            while let Some(node) = queue.pop_front() {
+                cm_order.push(node.clone());
+
+                let mut unvisited_neighbors: Vec<NodeId> = neighbors
+                    .get(&node)
+                    .into_iter()
+                    .flatten()
+                    .filter(|nb| !visited.contains(*nb))
+                    .cloned()
+                    .collect();
+                unvisited_neighbors.sort_by(|a, b| by_degree_then_name(a, b));
+
+                for nb in unvisited_neighbors {
+                    visited.insert(nb.clone());
+                    queue.push_back(nb);
+                }
+            }
+        }
+
+        cm_order.reverse();
+        let mut result = cm_order;
+
+        // Nodes with no qualifying edges (lone nodes, or nodes connected
+        // only via shadow/self-loop links) can't be placed by RCM; append
+        // them in name order.
+        let placed: HashSet<NodeId> = result.iter().cloned().collect();
+        let mut remaining: BTreeSet<NodeId> = BTreeSet::new();
+        for id in network.node_ids() {
+            if !placed.contains(id) {
+                remaining.insert(id.clone());
+            }
+        }
+        result.extend(remaining);
+
+        Ok(result)
+    }
+
+    fn name(&self) -> &'static str {
+        "Reverse Cuthill-McKee"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::default::DefaultNodeLayout;
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    fn are_adjacent(network: &Network, a: &NodeId, b: &NodeId) -> bool {
+        network
+            .links()
+            .any(|l| !l.is_shadow && ((&l.source == a && &l.target == b) || (&l.source == b && &l.target == a)))
+    }
+
+    #[test]
+    fn test_rcm_reproduces_chain_order_on_linear_chain() {
+        // Same content as tests/parity/networks/sif/linear_chain.sif.
+        let mut network = Network::new();
+        for i in 1..10 {
+            network.add_link(Link::new(format!("N{i}"), format!("N{}", i + 1), "pp"));
+        }
+
+        let layout = RcmLayout::new();
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order.len(), 10);
+        // RCM on a path reproduces the chain itself (possibly reversed,
+        // depending on which endpoint it starts from) — every consecutive
+        // pair in the output must be an edge of the chain.
+        for pair in order.windows(2) {
+            assert!(
+                are_adjacent(&network, &pair[0], &pair[1]),
+                "expected consecutive chain nodes, got {:?} next to {:?} in order {:?}",
+                pair[0],
+                pair[1],
+                order
+            );
+        }
+    }
+
+    fn max_row_span(network: &Network, order: &[NodeId]) -> usize {
+        let row: HashMap<&NodeId, usize> = order.iter().enumerate().map(|(i, id)| (id, i)).collect();
+        network
+            .links()
+            .filter(|l| !l.is_shadow)
+            .map(|l| row[&l.source].abs_diff(row[&l.target]))
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_rcm_reduces_bandwidth_versus_default_bfs_on_a_bridged_grid() {
+        // A 4x4 grid graph, with node names shuffled so they carry no
+        // hint of adjacency — stress-tests that RCM's bandwidth reduction
+        // comes from structure, not from alphabetical luck.
+        let label = |r: usize, c: usize| -> String {
+            let shuffled = (r * 7 + c * 13) % 16;
+            format!("n{shuffled:02}")
+        };
+
+        let mut network = Network::new();
+        for r in 0..4 {
+            for c in 0..4 {
+                if c + 1 < 4 {
+                    network.add_link(Link::new(label(r, c), label(r, c + 1), "pp"));
+                }
+                if r + 1 < 4 {
+                    network.add_link(Link::new(label(r, c), label(r + 1, c), "pp"));
+                }
+            }
+        }
+
+        let rcm_order = RcmLayout::new()
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+        let default_order = DefaultNodeLayout::new()
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let rcm_span = max_row_span(&network, &rcm_order);
+        let default_span = max_row_span(&network, &default_order);
+
+        assert!(
+            rcm_span < default_span,
+            "expected RCM bandwidth ({rcm_span}) to be smaller than the default BFS layout's ({default_span})"
+        );
+    }
+}
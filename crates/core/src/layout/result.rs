@@ -22,10 +22,30 @@
 //! - Java: `org.systemsbiology.biofabric.model.BioFabricNetwork.LinkInfo` (dual column storage)
 //! - Java: `org.systemsbiology.biofabric.model.BioFabricNetwork.NodeInfo` (dual span storage)
 
-use crate::model::{AnnotationSet, Network, NodeId};
+use crate::layout::traits::LayoutError;
+use crate::model::{Annotation, AnnotationSet, Link, Network, NetworkDiff, NodeId};
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeSet, HashMap, HashSet};
+use thiserror::Error;
+
+/// Errors that can occur saving or loading a [`NetworkLayout::save_cache`]
+/// file.
+#[derive(Error, Debug)]
+pub enum CacheError {
+    /// IO error reading or writing the cache file.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The cache file could not be encoded.
+    #[error("Failed to encode layout cache: {0}")]
+    Encode(#[from] bincode::error::EncodeError),
+
+    /// The cache file could not be decoded (wrong format, truncated, or
+    /// from an incompatible version).
+    #[error("Failed to decode layout cache: {0}")]
+    Decode(#[from] bincode::error::DecodeError),
+}
 
 /// Complete layout information for a network.
 ///
@@ -104,6 +124,70 @@ pub struct NetworkLayout {
     /// - Java: `BioFabricNetwork.NodeInfo.getCluster()`
     #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
     pub cluster_assignments: std::collections::HashMap<NodeId, String>,
+
+    /// Set when [`LayoutParams::max_nodes`](super::traits::LayoutParams::max_nodes)
+    /// caused the network to be reduced to a top-degree subnetwork before layout.
+    #[serde(default)]
+    pub truncated: bool,
+
+    /// Per-node metadata attributes (e.g., loaded from a `.na` file or GraphML
+    /// `<data>` elements), carried through layout for BIF/JSON export and
+    /// renderer tooltips.
+    ///
+    /// ## References
+    ///
+    /// - Java: `AttributeLoader` populates node attributes from column-delimited files
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub node_attributes: HashMap<NodeId, std::collections::BTreeMap<String, String>>,
+}
+
+// `NetworkLayout`'s `Serialize`/`Deserialize` derive uses `skip_serializing_if`
+// to keep JSON/BIF output compact, which is fundamentally incompatible with
+// bincode's non-self-describing encoding (a skipped field desyncs every
+// field read after it). So `Encode`/`Decode` are implemented by hand here
+// instead of derived, always writing every field, with `nodes` converted
+// to/from an order-preserving `Vec` since `IndexMap` has no bincode impl.
+impl bincode::Encode for NetworkLayout {
+    fn encode<E: bincode::enc::Encoder>(&self, encoder: &mut E) -> Result<(), bincode::error::EncodeError> {
+        let nodes: Vec<(&NodeId, &NodeLayout)> = self.nodes.iter().collect();
+        bincode::Encode::encode(&nodes, encoder)?;
+        bincode::Encode::encode(&self.links, encoder)?;
+        bincode::Encode::encode(&self.row_count, encoder)?;
+        bincode::Encode::encode(&self.column_count, encoder)?;
+        bincode::Encode::encode(&self.column_count_no_shadows, encoder)?;
+        bincode::Encode::encode(&self.node_annotations, encoder)?;
+        bincode::Encode::encode(&self.link_annotations, encoder)?;
+        bincode::Encode::encode(&self.link_annotations_no_shadows, encoder)?;
+        bincode::Encode::encode(&self.link_group_order, encoder)?;
+        bincode::Encode::encode(&self.layout_mode_text, encoder)?;
+        bincode::Encode::encode(&self.link_group_annots, encoder)?;
+        bincode::Encode::encode(&self.cluster_assignments, encoder)?;
+        bincode::Encode::encode(&self.truncated, encoder)?;
+        bincode::Encode::encode(&self.node_attributes, encoder)?;
+        Ok(())
+    }
+}
+
+impl<Context> bincode::Decode<Context> for NetworkLayout {
+    fn decode<D: bincode::de::Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, bincode::error::DecodeError> {
+        let nodes: Vec<(NodeId, NodeLayout)> = bincode::Decode::decode(decoder)?;
+        Ok(Self {
+            nodes: nodes.into_iter().collect(),
+            links: bincode::Decode::decode(decoder)?,
+            row_count: bincode::Decode::decode(decoder)?,
+            column_count: bincode::Decode::decode(decoder)?,
+            column_count_no_shadows: bincode::Decode::decode(decoder)?,
+            node_annotations: bincode::Decode::decode(decoder)?,
+            link_annotations: bincode::Decode::decode(decoder)?,
+            link_annotations_no_shadows: bincode::Decode::decode(decoder)?,
+            link_group_order: bincode::Decode::decode(decoder)?,
+            layout_mode_text: bincode::Decode::decode(decoder)?,
+            link_group_annots: bincode::Decode::decode(decoder)?,
+            cluster_assignments: bincode::Decode::decode(decoder)?,
+            truncated: bincode::Decode::decode(decoder)?,
+            node_attributes: bincode::Decode::decode(decoder)?,
+        })
+    }
 }
 
 impl NetworkLayout {
@@ -122,6 +206,8 @@ impl NetworkLayout {
             layout_mode_text: String::new(),
             link_group_annots: String::new(),
             cluster_assignments: std::collections::HashMap::new(),
+            truncated: false,
+            node_attributes: HashMap::new(),
         }
     }
 
@@ -140,6 +226,8 @@ impl NetworkLayout {
             layout_mode_text: String::new(),
             link_group_annots: String::new(),
             cluster_assignments: std::collections::HashMap::new(),
+            truncated: false,
+            node_attributes: HashMap::new(),
         }
     }
 
@@ -163,6 +251,137 @@ impl NetworkLayout {
         self.links.iter_mut()
     }
 
+    /// Build link annotations coloring the columns `diff`'s added and
+    /// removed links occupy in `self`, so a layout of the union of the two
+    /// networks `diff` was computed from can visualize what changed between
+    /// them. Added links are colored green (`#22AA3380`), removed links
+    /// red (`#CC332280`).
+    ///
+    /// A link from `diff` that has no matching column in `self` (e.g.
+    /// `self` wasn't laid out from the union of the same two networks) is
+    /// silently skipped.
+    ///
+    /// This only builds the [`AnnotationSet`]; merge it into
+    /// [`link_annotations`](Self::link_annotations) (or
+    /// `link_annotations_no_shadows`) yourself if you want it carried
+    /// through a saved session — `biofabric-render` does not currently
+    /// draw link annotations at all, so this is exposed for external
+    /// tooling and future renderer support rather than immediate visual
+    /// output.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn diff_annotations(&self, diff: &NetworkDiff) -> AnnotationSet {
+        const ADDED_COLOR: &str = "#22AA3380";
+        const REMOVED_COLOR: &str = "#CC332280";
+
+        let mut set = AnnotationSet::new();
+        for (links, name, color) in [
+            (&diff.added_links, "added", ADDED_COLOR),
+            (&diff.removed_links, "removed", REMOVED_COLOR),
+        ] {
+            for link in links {
+                if let Some(column) = self.find_link_column(link) {
+                    set.add(Annotation::new(name, column, column, 0, color));
+                }
+            }
+        }
+        set
+    }
+
+    /// Find the no-shadow column of the link layout matching `link` by
+    /// endpoints and relation (order-insensitive unless `link` is
+    /// directed), for [`diff_annotations`](Self::diff_annotations).
+    fn find_link_column(&self, link: &Link) -> Option<usize> {
+        self.links
+            .iter()
+            .find(|ll| {
+                !ll.is_shadow
+                    && ll.relation == link.relation
+                    && ((ll.source == link.source && ll.target == link.target)
+                        || (link.directed != Some(true)
+                            && ll.source == link.target
+                            && ll.target == link.source))
+            })
+            .and_then(|ll| ll.column_no_shadows.or(Some(ll.column)))
+    }
+
+    /// Map each node's row onto a unit circle, for a radial/circular
+    /// rendering alternative to the usual horizontal-line fabric layout.
+    ///
+    /// Row `0` lands at angle `0` (point `(1.0, 0.0)`), and successive rows
+    /// proceed counter-clockwise in equal steps of `2*PI / row_count`, so
+    /// the whole node order wraps exactly once around the circle. Links
+    /// then render as chords between their endpoints' points.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn radial_coordinates(&self) -> Vec<(NodeId, f64, f64)> {
+        let n = self.row_count.max(1) as f64;
+        self.nodes
+            .iter()
+            .map(|(id, nl)| {
+                let angle = std::f64::consts::TAU * nl.row as f64 / n;
+                (id.clone(), angle.cos(), angle.sin())
+            })
+            .collect()
+    }
+
+    /// Group this layout's links into drain zones: contiguous column ranges
+    /// where a node is the "main" endpoint its edges drain into.
+    ///
+    /// Returns `(plain_drain_zones, shadow_drain_zones)`, each mapping a
+    /// node to its list of `(start_column, end_column)` ranges. For plain
+    /// (non-shadow) zones, the main node of a link is the one at the TOP
+    /// (min row); for shadow zones, it's the TOP node for non-shadow links
+    /// and the BOTTOM (max row) node for shadow links.
+    ///
+    /// This is the shared computation behind the BIF XML writer's
+    /// `<drainZones>`/`<drainZonesShadow>` sections and the renderer's
+    /// drain-zone highlight overlay — callers that already have
+    /// [`NodeLayout::plain_drain_zones`]/[`NodeLayout::shadow_drain_zones`]
+    /// pre-computed (e.g. from submodel extraction) should prefer those,
+    /// falling back to this method otherwise.
+    pub fn compute_drain_zones(&self) -> (HashMap<NodeId, Vec<(usize, usize)>>, HashMap<NodeId, Vec<(usize, usize)>>) {
+        let row_to_node: Vec<NodeId> = {
+            let mut entries: Vec<_> = self.iter_nodes().collect();
+            entries.sort_by_key(|(_, nl)| nl.row);
+            entries.into_iter().map(|(id, _)| id.clone()).collect()
+        };
+
+        let mut plain_links: Vec<&LinkLayout> = self.links.iter().filter(|ll| !ll.is_shadow).collect();
+        plain_links.sort_by_key(|ll| ll.column_no_shadows.unwrap_or(0));
+        let plain = group_drain_zones(&plain_links, false, &row_to_node);
+
+        let mut shadow_links: Vec<&LinkLayout> = self.links.iter().collect();
+        shadow_links.sort_by_key(|ll| ll.column);
+        let shadow = group_drain_zones(&shadow_links, true, &row_to_node);
+
+        (plain, shadow)
+    }
+
+    // =========================================================================
+    // Layout caching
+    // =========================================================================
+
+    /// Serialize this layout to `path` using `bincode`, so a later run can
+    /// skip recomputing it (e.g. `NodeSimilarityLayout`'s resort passes on a
+    /// large network).
+    pub fn save_cache(&self, path: impl AsRef<std::path::Path>) -> Result<(), CacheError> {
+        let bytes = bincode::encode_to_vec(self, bincode::config::standard())?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+
+    /// Load a layout previously written by [`save_cache`](Self::save_cache).
+    pub fn load_cache(path: impl AsRef<std::path::Path>) -> Result<Self, CacheError> {
+        let bytes = std::fs::read(path)?;
+        let (layout, _) = bincode::decode_from_slice(&bytes, bincode::config::standard())?;
+        Ok(layout)
+    }
+
     // =========================================================================
     // Submodel extraction
     // =========================================================================
@@ -414,6 +633,340 @@ impl NetworkLayout {
 
         (sub_network, new_layout)
     }
+
+    // =========================================================================
+    // Building from externally computed coordinates
+    // =========================================================================
+
+    /// Build a layout directly from externally computed row/column assignments.
+    ///
+    /// This is the most general escape hatch for users who already have row
+    /// and column positions for every node and link (e.g. from a prototype
+    /// laid out by another tool) and want to render them through BioFabric
+    /// without running any [`NodeLayout`](super::traits::NodeLayout) /
+    /// [`EdgeLayout`](super::traits::EdgeLayout) algorithm at all.
+    ///
+    /// `cols` is keyed by [`Link`] rather than a lighter identifier: `Link`
+    /// already implements `Eq`/`Hash` and uniquely identifies an edge (including
+    /// telling a link apart from its shadow copy via `is_shadow`).
+    ///
+    /// Only the shadow-displayed column (`LinkLayout::column`) is populated
+    /// from `cols`; there's no separate no-shadow coordinate set to derive
+    /// `column_no_shadows` from, so `column_count_no_shadows` stays `0` and
+    /// [`NodeLayout::has_edges_no_shadows`] is `false` for every node.
+    /// Callers who also need the no-shadow view should lay out a copy of
+    /// `network` with [`Network::strip_shadows`] called first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LayoutError::Internal`] if `rows` is missing an entry for
+    /// one of `network`'s nodes, if `cols` is missing an entry for one of
+    /// its links, or if the assembled layout fails [`NetworkLayout::validate`].
+    pub fn from_coordinates(
+        network: &Network,
+        rows: &HashMap<NodeId, usize>,
+        cols: &HashMap<Link, usize>,
+    ) -> Result<NetworkLayout, LayoutError> {
+        let mut layout =
+            NetworkLayout::with_capacity(network.node_count(), network.links_slice().len());
+
+        for node_id in network.node_ids() {
+            let row = *rows.get(node_id).ok_or_else(|| {
+                LayoutError::Internal(format!("from_coordinates: no row given for node {node_id:?}"))
+            })?;
+            layout
+                .nodes
+                .insert(node_id.clone(), NodeLayout::new(row, node_id.as_str()));
+        }
+
+        for link in network.links() {
+            let column = *cols.get(link).ok_or_else(|| {
+                LayoutError::Internal(format!(
+                    "from_coordinates: no column given for link {:?}->{:?} ({})",
+                    link.source, link.target, link.relation
+                ))
+            })?;
+
+            let mut ll = LinkLayout::new(
+                column,
+                link.source.clone(),
+                link.target.clone(),
+                rows[&link.source],
+                rows[&link.target],
+                link.relation.clone(),
+                link.is_shadow,
+            );
+            ll.directed = link.directed;
+
+            if let Some(nl) = layout.nodes.get_mut(&link.source) {
+                nl.update_span(column);
+            }
+            if let Some(nl) = layout.nodes.get_mut(&link.target) {
+                nl.update_span(column);
+            }
+
+            layout.links.push(ll);
+        }
+
+        layout.row_count = layout.nodes.values().map(|n| n.row).max().map_or(0, |m| m + 1);
+        layout.column_count = layout.links.iter().map(|l| l.column).max().map_or(0, |m| m + 1);
+
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    // =========================================================================
+    // Validation
+    // =========================================================================
+
+    /// Check internal consistency of this layout.
+    ///
+    /// Layout consumers (renderers, exporters) assume the invariants below
+    /// hold; a corrupted or hand-edited layout (e.g. loaded from a malformed
+    /// BIF file) can silently violate them, which otherwise only shows up
+    /// downstream as a clamped dimension or an out-of-bounds panic. Catching
+    /// it here gives a clear, actionable error instead.
+    ///
+    /// Checks performed:
+    /// - `row_count` is at least one past every node's row
+    /// - `column_count` / `column_count_no_shadows` are at least one past
+    ///   every link's (shadow / non-shadow) column
+    /// - every link's `source` and `target` refer to a node present in `nodes`
+    /// - every node's column span (`min_col..=max_col` and the `_no_shadows`
+    ///   variant, when the node has edges) falls within the layout's overall
+    ///   column range
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LayoutError::Internal`] describing the first inconsistency
+    /// found.
+    pub fn validate(&self) -> Result<(), LayoutError> {
+        for (id, node) in self.iter_nodes() {
+            if node.row >= self.row_count {
+                return Err(LayoutError::Internal(format!(
+                    "node {:?} has row {} but row_count is {}",
+                    id, node.row, self.row_count
+                )));
+            }
+            if node.has_edges() && node.max_col >= self.column_count {
+                return Err(LayoutError::Internal(format!(
+                    "node {:?} has max_col {} but column_count is {}",
+                    id, node.max_col, self.column_count
+                )));
+            }
+            if node.has_edges_no_shadows() && node.max_col_no_shadows >= self.column_count_no_shadows
+            {
+                return Err(LayoutError::Internal(format!(
+                    "node {:?} has max_col_no_shadows {} but column_count_no_shadows is {}",
+                    id, node.max_col_no_shadows, self.column_count_no_shadows
+                )));
+            }
+        }
+
+        for link in self.iter_links() {
+            if !self.nodes.contains_key(&link.source) {
+                return Err(LayoutError::Internal(format!(
+                    "link at column {} references missing source node {:?}",
+                    link.column, link.source
+                )));
+            }
+            if !self.nodes.contains_key(&link.target) {
+                return Err(LayoutError::Internal(format!(
+                    "link at column {} references missing target node {:?}",
+                    link.column, link.target
+                )));
+            }
+            if link.column >= self.column_count {
+                return Err(LayoutError::Internal(format!(
+                    "link {:?}->{:?} has column {} but column_count is {}",
+                    link.source, link.target, link.column, self.column_count
+                )));
+            }
+            if let Some(col) = link.column_no_shadows {
+                if col >= self.column_count_no_shadows {
+                    return Err(LayoutError::Internal(format!(
+                        "link {:?}->{:?} has column_no_shadows {} but column_count_no_shadows is {}",
+                        link.source, link.target, col, self.column_count_no_shadows
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Refine node row order with a barycenter heuristic sweep followed by
+    /// a transpose (adjacent-swap) local search, reducing total link
+    /// row-span (`sum of |bottom_row - top_row|` across links) as a proxy
+    /// for visual clutter — a short link is easier to follow than one that
+    /// zig-zags across the whole fabric.
+    ///
+    /// This only reassigns `row` on each node and the row caches on each
+    /// [`LinkLayout`]; column assignment, shadow structure, and which node
+    /// is connected to which are all unchanged. A no-op if there are fewer
+    /// than two rows, or if `node_annotations`/`link_annotations` are
+    /// non-empty — the row ranges those record would no longer line up
+    /// with anything after a reorder, and reconciling them is out of scope
+    /// for this generic post-process.
+    ///
+    /// Gated behind [`LayoutParams::refine`](super::traits::LayoutParams::refine)
+    /// in [`TwoPhaseLayout::layout`](super::traits::TwoPhaseLayout::layout);
+    /// can also be called directly on any layout.
+    pub fn minimize_crossings(&mut self) {
+        const SWEEPS: usize = 4;
+
+        if self.row_count < 2
+            || !self.node_annotations.is_empty()
+            || !self.link_annotations.is_empty()
+        {
+            return;
+        }
+
+        // Treat each node's original row as a stable id, and track its
+        // current position under `position[id]`. Neighbor lists are built
+        // once from the (unchanging) link endpoints.
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); self.row_count];
+        for link in &self.links {
+            if link.source_row == link.target_row {
+                continue;
+            }
+            neighbors[link.source_row].push(link.target_row);
+            neighbors[link.target_row].push(link.source_row);
+        }
+
+        let total_span = |position: &[usize]| -> u64 {
+            self.links
+                .iter()
+                .map(|l| {
+                    let a = position[l.source_row] as i64;
+                    let b = position[l.target_row] as i64;
+                    (a - b).unsigned_abs()
+                })
+                .sum()
+        };
+
+        let mut position: Vec<usize> = (0..self.row_count).collect();
+        let mut best_position = position.clone();
+        let mut best_span = total_span(&position);
+
+        for _ in 0..SWEEPS {
+            // Barycenter: each node moves toward the average position of
+            // its neighbors. Nodes with no neighbors keep their current
+            // position so they don't all collapse to zero.
+            let mut order: Vec<usize> = (0..self.row_count).collect();
+            order.sort_by(|&a, &b| {
+                let key = |id: usize| -> f64 {
+                    if neighbors[id].is_empty() {
+                        position[id] as f64
+                    } else {
+                        let sum: usize = neighbors[id].iter().map(|&n| position[n]).sum();
+                        sum as f64 / neighbors[id].len() as f64
+                    }
+                };
+                key(a)
+                    .partial_cmp(&key(b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| position[a].cmp(&position[b]))
+            });
+
+            let mut next_position = vec![0usize; self.row_count];
+            for (new_pos, id) in order.into_iter().enumerate() {
+                next_position[id] = new_pos;
+            }
+            position = next_position;
+
+            let span = total_span(&position);
+            if span < best_span {
+                best_span = span;
+                best_position = position.clone();
+            }
+        }
+
+        // Barycenter sweeps alone can settle into a 2-cycle (a symmetric
+        // structure like a plain chain can oscillate between an order and
+        // its mirror without ever improving) — so finish with a transpose
+        // pass: repeatedly try swapping adjacent rows, keeping the swap
+        // only when it strictly shortens total span. This can only
+        // improve on `best_position`, never worsen it.
+        let mut order: Vec<usize> = (0..self.row_count).collect();
+        order.sort_by_key(|&id| best_position[id]);
+        let mut improved = true;
+        let mut passes = 0;
+        while improved && passes < self.row_count {
+            improved = false;
+            passes += 1;
+            for i in 0..order.len().saturating_sub(1) {
+                order.swap(i, i + 1);
+                let mut candidate = vec![0usize; self.row_count];
+                for (pos, &id) in order.iter().enumerate() {
+                    candidate[id] = pos;
+                }
+                let span = total_span(&candidate);
+                if span < best_span {
+                    best_span = span;
+                    best_position = candidate;
+                    improved = true;
+                } else {
+                    order.swap(i, i + 1);
+                }
+            }
+        }
+
+        for node in self.nodes.values_mut() {
+            node.row = best_position[node.row];
+        }
+        for link in &mut self.links {
+            link.source_row = best_position[link.source_row];
+            link.target_row = best_position[link.target_row];
+        }
+    }
+
+    /// Count pairs of link verticals that visually cross.
+    ///
+    /// Two non-shadow links in different columns cross when their row
+    /// spans interleave rather than being nested or disjoint — i.e. one
+    /// link's top row falls strictly inside the other's span while its
+    /// bottom row does not (and vice versa). This is a fabric-geometry
+    /// adaptation of the standard two-layer crossing count, useful for
+    /// comparing layout algorithms (or tuning passes like
+    /// [`minimize_crossings`]) on the same network.
+    ///
+    /// Shadow links are excluded since they duplicate a non-shadow link's
+    /// span and would double-count every crossing it participates in.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    ///
+    /// [`minimize_crossings`]: NetworkLayout::minimize_crossings
+    pub fn crossing_count(&self) -> usize {
+        let spans: Vec<(usize, usize)> =
+            self.links.iter().filter(|l| !l.is_shadow).map(|l| (l.top_row(), l.bottom_row())).collect();
+        count_interleaving_spans(&spans)
+    }
+}
+
+/// Count pairs of `(start, end)` row spans that interleave — one span's
+/// start falls strictly inside the other while its end does not, rather
+/// than the spans being nested or disjoint. Shared by
+/// [`NetworkLayout::crossing_count`] and
+/// [`barycenter`](super::barycenter)'s layout-agnostic crossing estimate,
+/// since both need the same span-interleaving test, just applied to
+/// differently-sourced spans (a built layout's rows vs. a candidate node
+/// order).
+pub(crate) fn count_interleaving_spans(spans: &[(usize, usize)]) -> usize {
+    let mut count = 0usize;
+    for i in 0..spans.len() {
+        for j in (i + 1)..spans.len() {
+            let (a0, a1) = spans[i];
+            let (b0, b1) = spans[j];
+            let interleaves = (a0 < b0 && b0 < a1 && a1 < b1) || (b0 < a0 && a0 < b1 && b1 < a1);
+            if interleaves {
+                count += 1;
+            }
+        }
+    }
+    count
 }
 
 impl Default for NetworkLayout {
@@ -433,7 +986,7 @@ impl Default for NetworkLayout {
 /// Two spans are stored so shadow toggle is O(1):
 /// - `(min_col, max_col)` — span when shadow links are displayed
 /// - `(min_col_no_shadows, max_col_no_shadows)` — span when shadows are hidden
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
 pub struct NodeLayout {
     /// The row this node is assigned to (y-coordinate).
     pub row: usize,
@@ -559,7 +1112,7 @@ impl NodeLayout {
 /// ## References
 ///
 /// - Java: `BioFabricNetwork.LinkInfo.shadowColumn_` / `noShadowColumn_`
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
 pub struct LinkLayout {
     /// Column when shadow links are displayed.
     pub column: usize,
@@ -596,6 +1149,18 @@ pub struct LinkLayout {
     /// `None` means use the default (false for standard BioFabric).
     /// Set to `Some(true)` by SetLayout.
     pub directed: Option<bool>,
+
+    /// Copied from [`Link::weight`](crate::model::Link::weight) by
+    /// [`DefaultEdgeLayout`](super::DefaultEdgeLayout). `None` when the
+    /// source link has no weight, or for layout algorithms that build
+    /// [`LinkLayout`] without going through a [`Link`](crate::model::Link)
+    /// (e.g. EDA-driven reconstruction).
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
 }
 
 impl LinkLayout {
@@ -620,6 +1185,7 @@ impl LinkLayout {
             is_shadow,
             color_index: 0,
             directed: None,
+            weight: None,
         }
     }
 
@@ -639,6 +1205,67 @@ impl LinkLayout {
     }
 }
 
+/// Group consecutive links into drain zones.
+///
+/// The `for_shadow` flag controls which column and zone-node logic to use:
+/// - `false`: plain mode — use `column_no_shadows`, zone node = top row node
+/// - `true`: shadow mode — use `column`, zone node depends on shadow status
+fn group_drain_zones(links: &[&LinkLayout], for_shadow: bool, row_to_node: &[NodeId]) -> HashMap<NodeId, Vec<(usize, usize)>> {
+    let mut result: HashMap<NodeId, Vec<(usize, usize)>> = HashMap::new();
+
+    if links.is_empty() {
+        return result;
+    }
+
+    /// Get the "zone node" for a link.
+    ///
+    /// - Non-shadow links: the node at the TOP (min row) — this is the node
+    ///   whose horizontal line the link "drains from".
+    /// - Shadow links: the node at the BOTTOM (max row).
+    fn zone_node_id<'a>(ll: &LinkLayout, row_to_node: &'a [NodeId]) -> &'a NodeId {
+        if ll.is_shadow {
+            let bottom = ll.source_row.max(ll.target_row);
+            &row_to_node[bottom]
+        } else {
+            let top = ll.source_row.min(ll.target_row);
+            &row_to_node[top]
+        }
+    }
+
+    fn get_col(ll: &LinkLayout, for_shadow: bool) -> usize {
+        if for_shadow {
+            ll.column
+        } else {
+            ll.column_no_shadows.unwrap_or(0)
+        }
+    }
+
+    let mut start_idx = 0;
+
+    for i in 1..=links.len() {
+        let flush = if i == links.len() {
+            true
+        } else {
+            zone_node_id(links[i], row_to_node) != zone_node_id(links[start_idx], row_to_node)
+        };
+
+        if flush {
+            let end_idx = i - 1;
+            let start_col = get_col(links[start_idx], for_shadow);
+            let end_col = get_col(links[end_idx], for_shadow);
+            let node = zone_node_id(links[start_idx], row_to_node).clone();
+
+            result.entry(node).or_default().push((start_col, end_col));
+
+            if i < links.len() {
+                start_idx = i;
+            }
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -689,6 +1316,48 @@ mod tests {
         assert!(link.column_no_shadows.is_none()); // Not yet set
     }
 
+    #[test]
+    fn test_cache_round_trip_preserves_node_and_link_order() {
+        let mut layout = NetworkLayout::new();
+        let mut a = NodeLayout::new(0, "A");
+        a.update_span(0);
+        a.update_span(2);
+        a.nid = Some(7);
+        a.plain_drain_zones = Some(vec![(0, 1)]);
+        layout.nodes.insert(NodeId::new("A"), a);
+        layout.nodes.insert(NodeId::new("B"), NodeLayout::new(1, "B"));
+        layout.links.push(LinkLayout::new(
+            0,
+            NodeId::new("A"),
+            NodeId::new("B"),
+            0,
+            1,
+            "pp",
+            false,
+        ));
+        layout.row_count = 2;
+        layout.column_count = 1;
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache_path = dir.path().join("layout.cache");
+        layout.save_cache(&cache_path).unwrap();
+        let reloaded = NetworkLayout::load_cache(&cache_path).unwrap();
+
+        let mut expected = Vec::new();
+        crate::io::order::write_node_order(&mut expected, &layout).unwrap();
+        let mut actual = Vec::new();
+        crate::io::order::write_node_order(&mut actual, &reloaded).unwrap();
+        assert_eq!(expected, actual);
+
+        assert_eq!(reloaded.nodes[&NodeId::new("A")].nid, Some(7));
+        assert_eq!(
+            reloaded.nodes[&NodeId::new("A")].plain_drain_zones,
+            Some(vec![(0, 1)])
+        );
+        assert_eq!(reloaded.row_count, 2);
+        assert_eq!(reloaded.links.len(), 1);
+    }
+
     #[test]
     fn test_shadow_link_no_shadow_column() {
         let link = LinkLayout::new(
@@ -706,6 +1375,228 @@ mod tests {
         assert!(link.is_shadow);
     }
 
+    #[test]
+    fn test_minimize_crossings_reduces_total_link_span_on_scrambled_chain() {
+        // A chain A-B-C-D-E whose row order has been scrambled so that
+        // every link has a large row-span, even though the "obvious" order
+        // (rows following the chain) would give every link a span of 1.
+        let ids: Vec<NodeId> = ["A", "B", "C", "D", "E"].iter().map(|n| NodeId::new(*n)).collect();
+        let scrambled_rows = [0usize, 4, 1, 3, 2]; // A, B, C, D, E rows
+
+        let mut layout = NetworkLayout::new();
+        layout.row_count = 5;
+        layout.column_count = 4;
+        layout.column_count_no_shadows = 4;
+        for (i, id) in ids.iter().enumerate() {
+            layout.nodes.insert(id.clone(), NodeLayout::new(scrambled_rows[i], id.to_string()));
+        }
+        for (col, (a, b)) in ids.iter().zip(ids.iter().skip(1)).enumerate() {
+            let row_a = scrambled_rows[ids.iter().position(|n| n == a).unwrap()];
+            let row_b = scrambled_rows[ids.iter().position(|n| n == b).unwrap()];
+            layout
+                .links
+                .push(LinkLayout::new(col, a.clone(), b.clone(), row_a, row_b, "pp", false));
+        }
+
+        let span_sum = |layout: &NetworkLayout| -> u64 {
+            layout
+                .iter_links()
+                .map(|l| (l.top_row(), l.bottom_row()))
+                .map(|(t, b)| (b - t) as u64)
+                .sum()
+        };
+        let before = span_sum(&layout);
+        assert_eq!(before, 4 + 3 + 2 + 1);
+
+        layout.minimize_crossings();
+
+        let after = span_sum(&layout);
+        assert!(after < before, "expected refinement to reduce total span: before={} after={}", before, after);
+
+        // Row assignment is still a valid permutation of 0..row_count.
+        let mut rows: Vec<usize> = layout.iter_nodes().map(|(_, n)| n.row).collect();
+        rows.sort_unstable();
+        assert_eq!(rows, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_minimize_crossings_is_a_noop_when_annotations_present() {
+        let mut layout = NetworkLayout::new();
+        let a = NodeId::new("A");
+        let b = NodeId::new("B");
+        layout.nodes.insert(a.clone(), NodeLayout::new(1, "A"));
+        layout.nodes.insert(b.clone(), NodeLayout::new(0, "B"));
+        layout.row_count = 2;
+        layout.links.push(LinkLayout::new(0, a, b, 1, 0, "pp", false));
+        layout.node_annotations.add(Annotation::new("group", 0, 1, 0, "#FF660080"));
+
+        let before: Vec<usize> = layout.iter_nodes().map(|(_, n)| n.row).collect();
+        layout.minimize_crossings();
+        let after: Vec<usize> = layout.iter_nodes().map(|(_, n)| n.row).collect();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn test_crossing_count_is_higher_for_a_scrambled_order_than_the_default() {
+        // Two independent chains, A-B and C-D, laid out in two different
+        // row orders on the same four nodes and two links:
+        //
+        // Default (chain-following) order: A=0, B=1, C=2, D=3 — the two
+        // links (rows 0..1 and 2..3) don't overlap at all, so no crossing.
+        //
+        // Scrambled order: A=0, C=1, B=2, D=3 — now A-B spans rows 0..2
+        // and C-D spans rows 1..3, which overlap, so the two verticals
+        // (in different columns) must cross.
+        let ids: Vec<NodeId> = ["A", "B", "C", "D"].iter().map(|n| NodeId::new(*n)).collect();
+
+        let build = |rows: [usize; 4]| -> NetworkLayout {
+            let mut layout = NetworkLayout::new();
+            layout.row_count = 4;
+            layout.column_count = 2;
+            layout.column_count_no_shadows = 2;
+            for (id, row) in ids.iter().zip(rows) {
+                layout.nodes.insert(id.clone(), NodeLayout::new(row, id.to_string()));
+            }
+            layout.links.push(LinkLayout::new(
+                0,
+                ids[0].clone(),
+                ids[1].clone(),
+                rows[0],
+                rows[1],
+                "pp",
+                false,
+            ));
+            layout.links.push(LinkLayout::new(
+                1,
+                ids[2].clone(),
+                ids[3].clone(),
+                rows[2],
+                rows[3],
+                "pp",
+                false,
+            ));
+            layout
+        };
+
+        let default_order = build([0, 1, 2, 3]);
+        let scrambled_order = build([0, 2, 1, 3]);
+
+        assert_eq!(default_order.crossing_count(), 0);
+        assert_eq!(scrambled_order.crossing_count(), 1);
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_layout() {
+        let mut layout = NetworkLayout::new();
+        let a = NodeId::new("A");
+        let b = NodeId::new("B");
+
+        let mut node_a = NodeLayout::new(0, "A");
+        node_a.update_span(0);
+        let mut node_b = NodeLayout::new(1, "B");
+        node_b.update_span(0);
+
+        layout.nodes.insert(a.clone(), node_a);
+        layout.nodes.insert(b.clone(), node_b);
+        layout.row_count = 2;
+        layout.column_count = 1;
+
+        let mut link = LinkLayout::new(0, a, b, 0, 1, "rel", false);
+        link.column_no_shadows = Some(0);
+        layout.links.push(link);
+        layout.column_count_no_shadows = 1;
+
+        assert!(layout.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_link_referencing_missing_node() {
+        let mut layout = NetworkLayout::new();
+        let a = NodeId::new("A");
+
+        let mut node_a = NodeLayout::new(0, "A");
+        node_a.update_span(0);
+        layout.nodes.insert(a.clone(), node_a);
+        layout.row_count = 1;
+        layout.column_count = 1;
+        layout.column_count_no_shadows = 1;
+
+        // Corrupt the layout: link to a node that was never added.
+        let ghost = NodeId::new("GHOST");
+        let mut link = LinkLayout::new(0, a, ghost, 0, 0, "rel", false);
+        link.column_no_shadows = Some(0);
+        layout.links.push(link);
+
+        let err = layout.validate().unwrap_err();
+        let message = err.to_string();
+        assert!(
+            message.contains("GHOST"),
+            "error should name the missing node: {message}"
+        );
+    }
+
+    #[test]
+    fn test_from_coordinates_reproduces_existing_layout_rows_and_columns() {
+        use crate::io::sif::parse_string;
+        use crate::layout::default::{DefaultEdgeLayout, DefaultNodeLayout};
+        use crate::layout::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+        use crate::worker::NoopMonitor;
+
+        let network = parse_string("A\tpp\tB\nB\tpp\tC\nC\tpp\tA\n").unwrap();
+        let two_phase = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let params = LayoutParams::default();
+        let layout = two_phase.layout(&network, &params, &NoopMonitor).unwrap();
+
+        let rows: HashMap<NodeId, usize> = layout
+            .iter_nodes()
+            .map(|(id, nl)| (id.clone(), nl.row))
+            .collect();
+        let cols: HashMap<Link, usize> = layout
+            .iter_links()
+            .map(|ll| {
+                (
+                    Link {
+                        source: ll.source.clone(),
+                        target: ll.target.clone(),
+                        relation: ll.relation.clone(),
+                        directed: ll.directed,
+                        is_shadow: ll.is_shadow,
+                        weight: None,
+                    },
+                    ll.column,
+                )
+            })
+            .collect();
+
+        let rebuilt = NetworkLayout::from_coordinates(&network, &rows, &cols).unwrap();
+
+        for (id, nl) in layout.iter_nodes() {
+            assert_eq!(rebuilt.get_node(id).unwrap().row, nl.row);
+        }
+        let rebuilt_cols: BTreeSet<(NodeId, NodeId, usize)> = rebuilt
+            .iter_links()
+            .map(|ll| (ll.source.clone(), ll.target.clone(), ll.column))
+            .collect();
+        let original_cols: BTreeSet<(NodeId, NodeId, usize)> = layout
+            .iter_links()
+            .map(|ll| (ll.source.clone(), ll.target.clone(), ll.column))
+            .collect();
+        assert_eq!(rebuilt_cols, original_cols);
+    }
+
+    #[test]
+    fn test_from_coordinates_rejects_missing_row() {
+        let network = Network::new();
+        let mut network = network;
+        network.add_link(Link::new("A", "B", "pp"));
+
+        let rows: HashMap<NodeId, usize> = [(NodeId::new("A"), 0)].into_iter().collect();
+        let cols: HashMap<Link, usize> = [(Link::new("A", "B", "pp"), 0)].into_iter().collect();
+
+        let err = NetworkLayout::from_coordinates(&network, &rows, &cols).unwrap_err();
+        assert!(err.to_string().contains('B'));
+    }
+
     #[test]
     fn test_network_layout_default() {
         let layout = NetworkLayout::new();
@@ -716,4 +1607,55 @@ mod tests {
         assert!(layout.link_annotations.is_empty());
         assert!(layout.link_annotations_no_shadows.is_empty());
     }
+
+    #[test]
+    fn test_radial_coordinates_lie_on_the_unit_circle() {
+        let mut layout = NetworkLayout::new();
+        layout.row_count = 4;
+        for (row, name) in ["A", "B", "C", "D"].into_iter().enumerate() {
+            layout.nodes.insert(NodeId::new(name), NodeLayout::new(row, name));
+        }
+
+        let points = layout.radial_coordinates();
+        assert_eq!(points.len(), 4);
+        for (_, x, y) in &points {
+            let radius = (x * x + y * y).sqrt();
+            assert!((radius - 1.0).abs() < 1e-10, "point ({x}, {y}) is not on the unit circle");
+        }
+
+        // Row 0 sits at angle 0.
+        let (_, x0, y0) = points.iter().find(|(id, _, _)| id.as_str() == "A").unwrap();
+        assert!((x0 - 1.0).abs() < 1e-10);
+        assert!(y0.abs() < 1e-10);
+
+        // Row 2 of 4 is halfway around the circle.
+        let (_, x2, y2) = points.iter().find(|(id, _, _)| id.as_str() == "C").unwrap();
+        assert!((x2 + 1.0).abs() < 1e-10);
+        assert!(y2.abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_diff_annotations_colors_added_and_removed_columns() {
+        use crate::layout::traits::{LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout};
+        use crate::layout::{DefaultEdgeLayout, DefaultNodeLayout};
+        use crate::worker::NoopMonitor;
+
+        let mut before = Network::new();
+        before.add_link(Link::new("A", "B", "pp"));
+
+        let mut after = Network::new();
+        after.add_link(Link::new("A", "C", "pp"));
+
+        let diff = before.diff(&after);
+        let merged = before.union(&after);
+
+        let two_phase = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = two_phase.layout(&merged, &LayoutParams::default(), &NoopMonitor).unwrap();
+
+        let annotations = layout.diff_annotations(&diff);
+        assert_eq!(annotations.len(), 2);
+        let names: Vec<&str> = annotations.iter().map(|a| a.name.as_str()).collect();
+        assert!(names.contains(&"added"));
+        assert!(names.contains(&"removed"));
+    }
 }
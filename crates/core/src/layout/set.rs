@@ -77,6 +77,27 @@ impl SetLayout {
         self
     }
 
+    /// Flip which side of each edge is treated as the "set" and which is
+    /// the "member", swapping [`SetSemantics::BelongsTo`] and
+    /// [`SetSemantics::Contains`].
+    ///
+    /// Useful when a network has already been loaded with one semantics
+    /// and the user wants to see sets-as-members/members-as-sets swapped,
+    /// without re-running the whole load/layout pipeline manually with the
+    /// opposite `--algorithm set` semantics.
+    pub fn transpose(&self) -> Self {
+        let semantics = match self.config.semantics {
+            SetSemantics::BelongsTo => SetSemantics::Contains,
+            SetSemantics::Contains => SetSemantics::BelongsTo,
+        };
+        Self {
+            config: SetLayoutParams {
+                semantics,
+                membership_relation: self.config.membership_relation.clone(),
+            },
+        }
+    }
+
     /// Extract set→members mapping from the network.
     ///
     /// Returns `(elems_per_set, sets_per_elem)` where:
@@ -445,3 +466,49 @@ impl NodeLayout for SetLayout {
         "Set Membership"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    fn sample_network() -> Network {
+        // BelongsTo reading: source (member) -> target (set).
+        let mut network = Network::new();
+        network.add_link(Link::new("geneA", "setX", "pp"));
+        network.add_link(Link::new("geneB", "setX", "pp"));
+        network.add_link(Link::new("geneB", "setY", "pp"));
+        network.add_link(Link::new("geneC", "setY", "pp"));
+        network
+    }
+
+    #[test]
+    fn test_transpose_flips_semantics() {
+        let layout = SetLayout::new().with_semantics(SetSemantics::BelongsTo);
+        assert_eq!(layout.transpose().config.semantics, SetSemantics::Contains);
+        assert_eq!(
+            layout.transpose().transpose().config.semantics,
+            SetSemantics::BelongsTo
+        );
+    }
+
+    #[test]
+    fn test_transposed_belongs_to_layout_equals_fresh_contains_layout() {
+        let network = sample_network();
+
+        let belongs_to = SetLayout::new().with_semantics(SetSemantics::BelongsTo);
+        let transposed_layout = belongs_to
+            .transpose()
+            .full_layout(&network, &NoopMonitor)
+            .unwrap();
+
+        let fresh_contains = SetLayout::new().with_semantics(SetSemantics::Contains);
+        let fresh_layout = fresh_contains.full_layout(&network, &NoopMonitor).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&transposed_layout).unwrap(),
+            serde_json::to_string(&fresh_layout).unwrap()
+        );
+    }
+}
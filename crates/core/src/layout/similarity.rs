@@ -15,9 +15,9 @@
 //! - Java: `org.systemsbiology.biofabric.layouts.NodeSimilarityLayout`
 //! - Jaccard index: <https://en.wikipedia.org/wiki/Jaccard_index>
 
-use super::traits::{LayoutParams, LayoutResult, NodeLayout};
+use super::traits::{LayoutError, LayoutParams, LayoutResult, NodeLayout};
 use crate::model::{Network, NodeId};
-use crate::worker::ProgressMonitor;
+use crate::worker::{LoopReporter, ProgressMonitor};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 // ============================================================================
@@ -122,7 +122,7 @@ impl NodeLayout for NodeSimilarityLayout {
         //         of row indices [0..n).
         let result_order = match self.mode {
             SimilarityMode::Resort => {
-                self.do_resort_layout(&conn_vecs, default_order.len())
+                self.do_resort_layout(&conn_vecs, default_order.len(), monitor)?
             }
             SimilarityMode::Clustered => {
                 self.do_clustered_layout(network, &default_order, &conn_vecs)
@@ -187,14 +187,20 @@ impl NodeSimilarityLayout {
     /// Resort layout: iteratively improve node ordering by grouping similar
     /// connection shapes together.
     ///
+    /// Reports one progress tick per pass (phase `"Resorting nodes"`) and
+    /// checks `monitor` for cancellation between passes, since a full run
+    /// over `self.pass_count` passes on a large network (e.g. AThaliana)
+    /// can take minutes.
+    ///
     /// Ported from Java `doReorderLayout()`.
     fn do_resort_layout(
         &self,
         conn_vecs: &BTreeMap<usize, BTreeSet<usize>>,
         num_rows: usize,
-    ) -> Vec<usize> {
+        monitor: &dyn ProgressMonitor,
+    ) -> LayoutResult<Vec<usize>> {
         if num_rows == 0 {
-            return Vec::new();
+            return Ok(Vec::new());
         }
 
         // Initial ordering: [0, 1, 2, ..., n-1]
@@ -205,8 +211,17 @@ impl NodeSimilarityLayout {
         let mut prep = setup_for_resort(conn_vecs, &ordered, &mut rankings);
         let mut last_rank = *rankings.values().last().unwrap();
 
+        let mut pass_reporter = LoopReporter::new(
+            self.pass_count as u64,
+            self.pass_count.min(20) as u64,
+            monitor,
+            0.0,
+            1.0,
+            "Resorting nodes",
+        );
+
         for _pass in 0..self.pass_count {
-            let next_ordered = resort_pass(&prep);
+            let next_ordered = resort_pass(&prep, monitor)?;
             prep = setup_for_resort(conn_vecs, &next_ordered, &mut rankings);
             let now_rank = *rankings.values().last().unwrap();
 
@@ -219,9 +234,12 @@ impl NodeSimilarityLayout {
             }
             ordered = next_ordered;
             last_rank = now_rank;
+
+            pass_reporter.tick().map_err(|_| LayoutError::Cancelled)?;
         }
+        pass_reporter.finish();
 
-        ordered
+        Ok(ordered)
     }
 }
 
@@ -612,10 +630,14 @@ fn setup_for_resort(
 
 /// Perform a single resort pass.
 ///
+/// Reports per-node placement progress via `monitor` (phase `"Resort
+/// pass"`) and returns [`LayoutError::Cancelled`] if the monitor requests
+/// cancellation partway through.
+///
 /// Ported from Java `resort()`.
-fn resort_pass(prep: &ClusterPrep) -> Vec<usize> {
+fn resort_pass(prep: &ClusterPrep, monitor: &dyn ProgressMonitor) -> LayoutResult<Vec<usize>> {
     if prep.num_rows == 0 {
-        return Vec::new();
+        return Ok(Vec::new());
     }
 
     // Pre-compute curve averages to avoid recomputing them in every delta call.
@@ -637,6 +659,9 @@ fn resort_pass(prep: &ClusterPrep) -> Vec<usize> {
     let mut base_idx = first;
     let mut fill_slot = 1usize;
 
+    let mut node_reporter =
+        LoopReporter::new(prep.num_rows as u64, 20, monitor, 0.0, 1.0, "Resort pass");
+
     while !still_avail.is_empty() {
         let &start_check = still_avail.iter().next().unwrap();
 
@@ -673,7 +698,10 @@ fn resort_pass(prep: &ClusterPrep) -> Vec<usize> {
             results.insert(start_check, fill_slot);
         }
         fill_slot += 1;
+
+        node_reporter.tick().map_err(|_| LayoutError::Cancelled)?;
     }
+    node_reporter.finish();
 
     // Convert: for each position in original order, look up its value in old_to_new,
     // then find that value's mapped slot in results.
@@ -684,7 +712,7 @@ fn resort_pass(prep: &ClusterPrep) -> Vec<usize> {
         retval.push(mapped_row);
     }
 
-    retval
+    Ok(retval)
 }
 
 // ============================================================================
@@ -1255,4 +1283,48 @@ mod tests {
         assert_eq!(curve.get(&3), Some(&2.0));
         assert_eq!(curve.get(&5), Some(&1.0));
     }
+
+    /// Monitor that just counts how many times it was asked to report
+    /// progress, so tests can confirm `do_resort_layout`/`resort_pass`
+    /// actually call through to it rather than silently ignoring it.
+    #[derive(Default)]
+    struct CountingMonitor {
+        calls: std::sync::atomic::AtomicU64,
+    }
+
+    impl ProgressMonitor for CountingMonitor {
+        fn set_total(&self, _total: u64) {}
+
+        fn update(&self, _done: u64) -> bool {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            true
+        }
+
+        fn update_with_phase(&self, _done: u64, _phase: &str) -> bool {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            true
+        }
+
+        fn keep_going(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_resort_layout_reports_progress() {
+        use crate::model::Link;
+
+        let mut network = Network::new();
+        for i in 0..30 {
+            network.add_link(Link::new(format!("N{}", i), format!("N{}", i + 1), "pp"));
+        }
+
+        let monitor = CountingMonitor::default();
+        let layout = NodeSimilarityLayout::resort();
+        layout
+            .layout_nodes(&network, &LayoutParams::default(), &monitor)
+            .unwrap();
+
+        assert!(monitor.calls.load(std::sync::atomic::Ordering::SeqCst) > 0);
+    }
 }
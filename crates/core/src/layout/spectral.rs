@@ -0,0 +1,310 @@
+//! Spectral (Fiedler vector) node ordering layout.
+//!
+//! Orders nodes by their component in the Fiedler vector — the eigenvector
+//! for the graph Laplacian's second-smallest eigenvalue — a classic
+//! spectral ordering that tends to place structurally related nodes near
+//! each other. For example, two clusters joined by a single bridge edge end
+//! up grouped at opposite ends of the ordering.
+//!
+//! ## Algorithm
+//!
+//! 1. Build the unweighted Laplacian `L = D - A` over the simple graph
+//!    formed from non-shadow, non-self-loop links.
+//! 2. Power-iterate on `L` to estimate its largest eigenvalue `lambda_max`.
+//! 3. Power-iterate on the shifted matrix `M = lambda_max * I - L`,
+//!    deflating out the all-ones vector (`L`'s eigenvector for its known
+//!    zero eigenvalue) at every step, to isolate the Fiedler vector.
+//! 4. Sort nodes by their Fiedler vector component (ties broken by name).
+//!
+//! Nodes with no non-shadow edges can't be placed by this method and are
+//! appended at the end, sorted by name — matching the "lone nodes" handling
+//! used by the other node layouts in this module.
+//!
+//! Note that the Fiedler vector (the *second*-smallest eigenvalue) orders
+//! nodes by position along the graph's "longest" structural axis — it
+//! cleanly separates two clusters joined by a narrow bridge, but it does
+//! *not* generally recover a graph's bipartite 2-coloring: e.g. on a
+//! 2-regular bipartite graph (a simple cycle, such as
+//! `tests/parity/networks/sif/bipartite.sif`), the Fiedler eigenspace is
+//! degenerate and reflects position around the cycle rather than side
+//! membership. A bipartite split corresponds instead to the *largest*
+//! Laplacian eigenvalue for regular bipartite graphs, which this layout
+//! doesn't target.
+//!
+//! This uses dense power iteration rather than a sparse Lanczos solver,
+//! which is adequate for the network sizes BioFabric typically visualizes
+//! but doesn't scale to very large graphs.
+//!
+//! ## References
+//!
+//! - Fiedler, M. (1973). "Algebraic connectivity of graphs." Czechoslovak
+//!   Mathematical Journal.
+//! - <https://en.wikipedia.org/wiki/Algebraic_connectivity>
+
+use super::traits::{LayoutParams, LayoutResult, NodeLayout};
+use crate::model::{Network, NodeId};
+use crate::worker::ProgressMonitor;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Maximum power-iteration steps per eigenvector estimate.
+const MAX_ITERATIONS: usize = 500;
+
+/// Stop iterating once successive vectors differ by less than this (L1).
+const CONVERGENCE_TOLERANCE: f64 = 1e-10;
+
+/// Spectral (Fiedler vector) node layout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpectralLayout;
+
+impl SpectralLayout {
+    /// Create a new spectral layout.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Power-iterate `matrix` (`n x n`, row-major, symmetric) starting from
+    /// `start`, optionally deflating out the unit vector `deflate` at every
+    /// step so the iteration converges to the dominant eigenvector
+    /// orthogonal to it.
+    fn power_iterate(matrix: &[Vec<f64>], start: Vec<f64>, deflate: Option<&[f64]>) -> Vec<f64> {
+        let n = matrix.len();
+        let mut v = start;
+        normalize(&mut v);
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut next = vec![0.0; n];
+            for (i, row) in matrix.iter().enumerate() {
+                next[i] = row.iter().zip(&v).map(|(m, vj)| m * vj).sum();
+            }
+            if let Some(d) = deflate {
+                let proj: f64 = next.iter().zip(d).map(|(a, b)| a * b).sum();
+                for (ni, di) in next.iter_mut().zip(d) {
+                    *ni -= proj * di;
+                }
+            }
+            if normalize(&mut next) < f64::EPSILON {
+                // No component left outside the deflated subspace.
+                break;
+            }
+            let delta: f64 = v.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+            v = next;
+            if delta < CONVERGENCE_TOLERANCE {
+                break;
+            }
+        }
+        v
+    }
+}
+
+/// Normalize `v` to unit length in place, returning the original norm.
+fn normalize(v: &mut [f64]) -> f64 {
+    let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm > f64::EPSILON {
+        for x in v.iter_mut() {
+            *x /= norm;
+        }
+    }
+    norm
+}
+
+impl NodeLayout for SpectralLayout {
+    fn layout_nodes(
+        &self,
+        network: &Network,
+        _params: &LayoutParams,
+        _monitor: &dyn ProgressMonitor,
+    ) -> LayoutResult<Vec<NodeId>> {
+        // Unique, non-shadow, non-self-loop edges, as an undirected simple
+        // graph (parallel edges collapse to one for Laplacian purposes).
+        let mut edge_set: BTreeSet<(NodeId, NodeId)> = BTreeSet::new();
+        for link in network.links() {
+            if link.is_shadow || link.source == link.target {
+                continue;
+            }
+            let pair = if link.source <= link.target {
+                (link.source.clone(), link.target.clone())
+            } else {
+                (link.target.clone(), link.source.clone())
+            };
+            edge_set.insert(pair);
+        }
+
+        let mut connected: BTreeSet<NodeId> = BTreeSet::new();
+        for (a, b) in &edge_set {
+            connected.insert(a.clone());
+            connected.insert(b.clone());
+        }
+
+        let nodes: Vec<NodeId> = connected.into_iter().collect();
+        let n = nodes.len();
+
+        let mut result: Vec<NodeId> = if n < 2 {
+            nodes
+        } else {
+            let index: BTreeMap<NodeId, usize> =
+                nodes.iter().cloned().enumerate().map(|(i, id)| (id, i)).collect();
+
+            let mut laplacian = vec![vec![0.0; n]; n];
+            for (a, b) in &edge_set {
+                let i = index[a];
+                let j = index[b];
+                laplacian[i][j] -= 1.0;
+                laplacian[j][i] -= 1.0;
+                laplacian[i][i] += 1.0;
+                laplacian[j][j] += 1.0;
+            }
+
+            // Deterministic starting vector (no RNG dependency needed just
+            // for a seed): alternating signs keep it from being
+            // accidentally orthogonal to a symmetric dominant eigenvector.
+            let start: Vec<f64> = (0..n)
+                .map(|i| if i % 2 == 0 { (i + 1) as f64 } else { -((i + 1) as f64) })
+                .collect();
+
+            let dominant = Self::power_iterate(&laplacian, start.clone(), None);
+            let lambda_max: f64 = {
+                let mut lv = vec![0.0; n];
+                for (i, row) in laplacian.iter().enumerate() {
+                    lv[i] = row.iter().zip(&dominant).map(|(m, vj)| m * vj).sum();
+                }
+                dominant.iter().zip(&lv).map(|(a, b)| a * b).sum()
+            };
+
+            // M = lambda_max * I - L: smallest eigenvalue of L (0, with the
+            // all-ones eigenvector) becomes M's largest, so M's dominant
+            // eigenvector orthogonal to all-ones is L's Fiedler vector.
+            let mut shifted = vec![vec![0.0; n]; n];
+            for i in 0..n {
+                for j in 0..n {
+                    shifted[i][j] = -laplacian[i][j];
+                }
+                shifted[i][i] += lambda_max;
+            }
+
+            let ones = vec![1.0 / (n as f64).sqrt(); n];
+            let fiedler = Self::power_iterate(&shifted, start, Some(&ones));
+
+            let mut by_component: Vec<(NodeId, f64)> =
+                nodes.into_iter().zip(fiedler).collect();
+            by_component.sort_by(|a, b| {
+                a.1.partial_cmp(&b.1)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| a.0.cmp(&b.0))
+            });
+            by_component.into_iter().map(|(id, _)| id).collect()
+        };
+
+        // Nodes with no qualifying edges (lone nodes, or nodes connected
+        // only via shadow/self-loop links) can't be placed spectrally;
+        // append them in name order.
+        let placed: BTreeSet<NodeId> = result.iter().cloned().collect();
+        let mut remaining: BTreeSet<NodeId> = BTreeSet::new();
+        for id in network.node_ids() {
+            if !placed.contains(id) {
+                remaining.insert(id.clone());
+            }
+        }
+        result.extend(remaining);
+
+        Ok(result)
+    }
+
+    fn name(&self) -> &'static str {
+        "Spectral (Fiedler)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    fn bipartite_network() -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("L1", "R1", "pp"));
+        network.add_link(Link::new("L1", "R2", "pp"));
+        network.add_link(Link::new("L2", "R1", "pp"));
+        network.add_link(Link::new("L2", "R3", "pp"));
+        network.add_link(Link::new("L3", "R2", "pp"));
+        network.add_link(Link::new("L3", "R3", "pp"));
+        network
+    }
+
+    #[test]
+    fn test_spectral_layout_covers_every_node_of_bipartite_sif() {
+        // `bipartite.sif` (tests/parity/networks/sif/bipartite.sif) is a
+        // 2-regular bipartite graph, i.e. a 6-cycle: its Fiedler eigenspace
+        // is degenerate and orders nodes by position around the cycle
+        // rather than by bipartite side (see the module doc). This is a
+        // basic sanity check that the layout still runs and places every
+        // node exactly once.
+        let network = bipartite_network();
+        let layout = SpectralLayout::new();
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let mut sorted_order: Vec<&str> = order.iter().map(|id| id.as_str()).collect();
+        sorted_order.sort_unstable();
+        assert_eq!(sorted_order, vec!["L1", "L2", "L3", "R1", "R2", "R3"]);
+    }
+
+    #[test]
+    fn test_spectral_layout_separates_two_clusters_joined_by_a_bridge() {
+        // Two triangles joined by a single bridge edge — the textbook case
+        // where the Fiedler vector cleanly separates the two sides of the
+        // graph's narrowest cut.
+        let mut network = Network::new();
+        network.add_link(Link::new("a1", "a2", "pp"));
+        network.add_link(Link::new("a2", "a3", "pp"));
+        network.add_link(Link::new("a1", "a3", "pp"));
+        network.add_link(Link::new("b1", "b2", "pp"));
+        network.add_link(Link::new("b2", "b3", "pp"));
+        network.add_link(Link::new("b1", "b3", "pp"));
+        network.add_link(Link::new("a1", "b1", "pp"));
+
+        let layout = SpectralLayout::new();
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order.len(), 6);
+
+        let positions: BTreeMap<&str, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.as_str(), i))
+            .collect();
+
+        let a_positions: Vec<usize> = ["a1", "a2", "a3"].iter().map(|n| positions[n]).collect();
+        let b_positions: Vec<usize> = ["b1", "b2", "b3"].iter().map(|n| positions[n]).collect();
+
+        let a_max = *a_positions.iter().max().unwrap();
+        let a_min = *a_positions.iter().min().unwrap();
+        let b_max = *b_positions.iter().max().unwrap();
+        let b_min = *b_positions.iter().min().unwrap();
+
+        // The two clusters occupy disjoint, non-interleaved ranges — one
+        // entirely before the other — regardless of which comes first
+        // (the Fiedler vector's sign is arbitrary).
+        assert!(
+            a_max < b_min || b_max < a_min,
+            "expected the two clusters in separate blocks, got order {:?}",
+            order
+        );
+    }
+
+    #[test]
+    fn test_spectral_layout_places_lone_nodes_last() {
+        let mut network = bipartite_network();
+        network.add_lone_node("isolated");
+
+        let layout = SpectralLayout::new();
+        let order = layout
+            .layout_nodes(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        assert_eq!(order.last().unwrap().as_str(), "isolated");
+    }
+}
@@ -4,6 +4,7 @@
 //! can be implemented by implementing these traits.
 
 use super::build_data::LayoutBuildData;
+use super::link_group::LinkSortMode;
 use super::result::NetworkLayout;
 use crate::model::{Network, NodeId};
 use crate::worker::{CancelledError, ProgressMonitor};
@@ -56,6 +57,37 @@ pub enum LayoutMode {
     PerNetwork,
 }
 
+/// Tie-breaking / ordering policy used by [`DefaultNodeLayout`](crate::layout::DefaultNodeLayout)'s
+/// BFS traversal, both for picking the next unplaced neighbor to visit and
+/// for ranking candidate starting nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BfsTiebreak {
+    /// Degree descending, then name ascending — matches the Java reference
+    /// implementation's `NID.WithName.compareTo` tiebreak.
+    ///
+    /// This is the default, and the only option the parity test suite
+    /// should use, since it's required for byte-for-byte agreement with
+    /// the Java golden layouts.
+    #[default]
+    JavaCompat,
+
+    /// Name ascending, ignoring degree entirely.
+    ///
+    /// Produces a more intuitive, alphabetically-predictable traversal for
+    /// users who don't need Java parity, at the cost of no longer favoring
+    /// densely-connected neighbors first.
+    Alphabetical,
+
+    /// Degree descending, then name ascending.
+    ///
+    /// Currently produces the same order as [`JavaCompat`](Self::JavaCompat)
+    /// in this port — both reduce to the same comparator — but is kept as a
+    /// distinct, explicitly-named option for callers who want "visit
+    /// highest-degree neighbors first" ordering without implying they rely
+    /// on Java byte parity.
+    DegreeThenName,
+}
+
 /// Parameters that can be passed to layout algorithms.
 #[derive(Debug, Clone, Default)]
 pub struct LayoutParams {
@@ -116,6 +148,90 @@ pub struct LayoutParams {
     ///
     /// - Java: `HierDAGLayout.pointUp_`
     pub point_up: Option<bool>,
+
+    /// Which field breaks ties between links anchored at the same node and
+    /// group ordinal, controlling the "fanning" pattern within a relation
+    /// block. Defaults to [`LinkSortMode::FarRow`] (the original ordering).
+    pub link_sort: LinkSortMode,
+
+    /// Cap on the number of nodes laid out, for fast approximate previews.
+    ///
+    /// When `Some(n)` and the network has more than `n` nodes, only the
+    /// top-`n` highest-degree nodes and their induced subnetwork are laid
+    /// out. [`NetworkLayout::truncated`](super::result::NetworkLayout::truncated)
+    /// is set to `true` when this happens.
+    pub max_nodes: Option<usize>,
+
+    /// Accepted by [`DefaultNodeLayout`](crate::layout::DefaultNodeLayout)
+    /// but has no effect on its output: the BFS does not read
+    /// [`Link::weight`](crate::model::Link::weight) and expands neighbors
+    /// in the [`bfs_tiebreak`](Self::bfs_tiebreak) order whether this is
+    /// `true` or `false`.
+    ///
+    /// Defaults to `false`.
+    pub weight_ordered_bfs: bool,
+
+    /// Tie-breaking policy for [`DefaultNodeLayout`](crate::layout::DefaultNodeLayout)'s
+    /// BFS traversal. Defaults to [`BfsTiebreak::JavaCompat`], which the
+    /// parity test suite relies on for byte-for-byte agreement with the
+    /// Java golden layouts.
+    pub bfs_tiebreak: BfsTiebreak,
+
+    /// When `true`, [`DefaultEdgeLayout`](crate::layout::DefaultEdgeLayout)
+    /// places every link between the same pair of nodes in adjacent
+    /// columns, overriding the usual relation/group/direction ordering for
+    /// that purpose. Useful when parallel relations (e.g. `pp` and `pd`
+    /// between the same two proteins) would otherwise be scattered across
+    /// the layout.
+    ///
+    /// Defaults to `false`, preserving the existing column ordering.
+    ///
+    /// This only affects column assignment. Drawing bundled links as a
+    /// single thicker line with a relation-count label is a rendering
+    /// concern and not yet implemented — `biofabric-render` still draws
+    /// one line per link.
+    pub bundle_multiedges: bool,
+
+    /// When `true`, [`TwoPhaseLayout::layout`] runs
+    /// [`NetworkLayout::minimize_crossings`] after edge layout, nudging the
+    /// node row order toward a lower total link row-span without changing
+    /// column assignment or which node is connected to which.
+    ///
+    /// Defaults to `false`, since the refinement pass is a generic
+    /// post-process any [`NodeLayout`] can opt into rather than a property
+    /// of a specific layout algorithm.
+    pub refine: bool,
+
+    /// When `true`, alignment layouts (`AlignmentNodeLayout`,
+    /// [`NodeGroupMap`](crate::alignment::groups::NodeGroupMap)) break ties
+    /// using sorted collections instead of `HashSet`/`HashMap` iteration
+    /// order wherever that order would otherwise leak into the output,
+    /// making repeated runs on the same input byte-identical.
+    ///
+    /// Defaults to `false`, which keeps the existing (partially
+    /// nondeterministic) ordering the Java golden files were captured
+    /// against — this is why the alignment parity tests compare NOA/EDA
+    /// output as unordered sets rather than byte-for-byte.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub stable_ordering: bool,
+
+    /// When `true`, self-loops (feedback links) are removed from the
+    /// network before layout, via [`Network::remove_self_loops`], so they
+    /// contribute to neither a node's degree nor the fabric's column count.
+    ///
+    /// Defaults to `false`: a self-loop still gets a column, just one whose
+    /// vertical link spans zero rows (source and target are the same row),
+    /// which is the layout's existing behavior for feedback links.
+    ///
+    /// [`Network::remove_self_loops`]: crate::model::Network::remove_self_loops
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub drop_self_loops: bool,
 }
 
 /// Trait for node layout algorithms.
@@ -240,6 +356,21 @@ where
         params: &LayoutParams,
         monitor: &dyn ProgressMonitor,
     ) -> LayoutResult<NetworkLayout> {
+        // 0. Apply max_nodes cap: reduce to the top-degree induced subnetwork
+        // for a fast approximate preview of huge networks.
+        let (network, truncated) = match params.max_nodes {
+            Some(max_nodes) if network.node_count() > max_nodes => {
+                (top_degree_subnetwork(network, max_nodes), true)
+            }
+            _ => (network.clone(), false),
+        };
+        let network = if params.drop_self_loops {
+            network.remove_self_loops()
+        } else {
+            network
+        };
+        let network = &network;
+
         // 1. Run node_layout to get node_order
         let node_order = self.node_layout.layout_nodes(network, params, monitor)?;
 
@@ -253,9 +384,24 @@ where
         );
 
         // 3. Call edge_layout
-        let layout = self.edge_layout.layout_edges(&mut build_data, params, monitor)?;
+        let mut layout = self.edge_layout.layout_edges(&mut build_data, params, monitor)?;
 
-        // 4. Return NetworkLayout
+        // 4. Carry node metadata attributes through to the layout result
+        for id in layout.nodes.keys().cloned().collect::<Vec<_>>() {
+            if let Some(node) = network.get_node(&id) {
+                if node.has_attributes() {
+                    layout.node_attributes.insert(id, node.attributes.clone());
+                }
+            }
+        }
+
+        // 5. Optionally refine row order to reduce total link row-span
+        if params.refine {
+            layout.minimize_crossings();
+        }
+
+        // 6. Return NetworkLayout
+        layout.truncated = truncated;
         Ok(layout)
     }
 
@@ -263,3 +409,21 @@ where
         "Two-Phase Layout"
     }
 }
+
+/// Build the induced subnetwork of the `max_nodes` highest-degree nodes.
+///
+/// Used by [`TwoPhaseLayout::layout`] when [`LayoutParams::max_nodes`] caps
+/// the size of the network to lay out. Ties are broken by [`NodeId`] order
+/// for determinism.
+fn top_degree_subnetwork(network: &Network, max_nodes: usize) -> Network {
+    let mut by_degree: Vec<&NodeId> = network.node_ids().collect();
+    by_degree.sort_by(|a, b| {
+        network
+            .degree(b)
+            .cmp(&network.degree(a))
+            .then_with(|| a.cmp(b))
+    });
+    let top: std::collections::HashSet<NodeId> =
+        by_degree.into_iter().take(max_nodes).cloned().collect();
+    network.extract_subnetwork(&top)
+}
@@ -18,8 +18,9 @@
 //!
 //! - Java: `org.systemsbiology.biofabric.layouts.WorldBankLayout`
 
+use super::result::NetworkLayout;
 use super::traits::{LayoutParams, LayoutResult, NodeLayout};
-use crate::model::{Network, NodeId};
+use crate::model::{Annotation, Network, NodeId};
 use crate::worker::ProgressMonitor;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
@@ -192,4 +193,111 @@ impl WorldBankLayout {
         }
         result
     }
+
+    /// Install node annotations marking each hub's spoke block.
+    ///
+    /// Re-derives the hub/satellite grouping from `network` using the same
+    /// rule as `layout_nodes` (a node with exactly one unique neighbor is a
+    /// satellite of that neighbor), then marks each hub's row together with
+    /// its satellites' rows as a single "Hub: <name>" annotation range.
+    /// `layout_nodes` places a hub immediately followed by its satellites,
+    /// so this range is always contiguous.
+    pub fn install_node_annotations(network: &Network, layout: &mut NetworkLayout) {
+        let mut node_to_neighbor: HashMap<NodeId, HashSet<NodeId>> = HashMap::new();
+        for link in network.links() {
+            node_to_neighbor
+                .entry(link.source.clone())
+                .or_default()
+                .insert(link.target.clone());
+            node_to_neighbor
+                .entry(link.target.clone())
+                .or_default()
+                .insert(link.source.clone());
+        }
+
+        let mut one_neighbor: HashMap<NodeId, HashSet<NodeId>> = HashMap::new();
+        for (node, neighbors) in &node_to_neighbor {
+            if neighbors.len() == 1 {
+                let hub = neighbors.iter().next().unwrap().clone();
+                one_neighbor.entry(hub).or_default().insert(node.clone());
+            }
+        }
+
+        // Order hubs by their row so annotations come out in display order.
+        let mut hubs: Vec<&NodeId> = one_neighbor.keys().collect();
+        hubs.sort_by_key(|hub| layout.get_node(hub).map(|nl| nl.row).unwrap_or(usize::MAX));
+
+        for hub in hubs {
+            let satellites = &one_neighbor[hub];
+            let mut rows: Vec<usize> = satellites
+                .iter()
+                .chain(std::iter::once(hub))
+                .filter_map(|id| layout.get_node(id).map(|nl| nl.row))
+                .collect();
+            if rows.is_empty() {
+                continue;
+            }
+            rows.sort_unstable();
+            let start = rows[0];
+            let end = *rows.last().unwrap();
+            layout.node_annotations.add(Annotation::new(
+                format!("Hub: {}", hub.as_str()),
+                start,
+                end,
+                0,
+                String::new(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::{DefaultEdgeLayout, NetworkLayoutAlgorithm, TwoPhaseLayout};
+    use crate::model::Link;
+    use crate::worker::NoopMonitor;
+
+    #[test]
+    fn test_install_node_annotations_covers_each_hubs_contiguous_spoke_block() {
+        // Two hubs, each with several satellites, plus an edge connecting
+        // the hubs directly so they aren't satellites of each other.
+        let mut network = Network::new();
+        network.add_link(Link::new("hubA", "hubB", "pp"));
+        network.add_link(Link::new("hubA", "spokeA1", "pp"));
+        network.add_link(Link::new("hubA", "spokeA2", "pp"));
+        network.add_link(Link::new("hubA", "spokeA3", "pp"));
+        network.add_link(Link::new("hubB", "spokeB1", "pp"));
+        network.add_link(Link::new("hubB", "spokeB2", "pp"));
+
+        let layout_algo = TwoPhaseLayout::new(WorldBankLayout::new(), DefaultEdgeLayout::new());
+        let mut layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        WorldBankLayout::install_node_annotations(&network, &mut layout);
+
+        assert_eq!(layout.node_annotations.len(), 2);
+
+        let row_of = |name: &str| layout.get_node(&NodeId::new(name)).unwrap().row;
+        let hub_a_rows = [row_of("hubA"), row_of("spokeA1"), row_of("spokeA2"), row_of("spokeA3")];
+        let hub_b_rows = [row_of("hubB"), row_of("spokeB1"), row_of("spokeB2")];
+
+        for annot in layout.node_annotations.iter() {
+            let rows: &[usize] = if annot.name.contains("hubA") {
+                &hub_a_rows
+            } else {
+                assert!(annot.name.contains("hubB"));
+                &hub_b_rows
+            };
+            let expected_start = *rows.iter().min().unwrap();
+            let expected_end = *rows.iter().max().unwrap();
+
+            // The block is contiguous: its span covers exactly as many rows
+            // as the hub has members, no gaps.
+            assert_eq!(annot.start, expected_start);
+            assert_eq!(annot.end, expected_end);
+            assert_eq!(annot.end - annot.start + 1, rows.len());
+        }
+    }
 }
@@ -16,5 +16,5 @@ pub mod worker;
 // Re-export commonly used types at crate root
 pub use error::{BioFabricError, Result};
 pub use io::session::Session;
-pub use model::{Annotation, AnnotationSet, Link, Network, NetworkMetadata, Node, NodeComparison, NodeId, SelectionState};
+pub use model::{Annotation, AnnotationSet, Link, Network, NetworkDiff, NetworkMetadata, Node, NodeComparison, NodeId, SelectionState};
 pub use worker::{CancelledError, LoopReporter, NoopMonitor, ProgressMonitor};
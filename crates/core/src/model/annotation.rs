@@ -13,7 +13,7 @@
 use serde::{Deserialize, Serialize};
 
 /// A single annotation — a named, colored range of rows or columns.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
 pub struct Annotation {
     /// Human-readable label for this annotation.
     pub name: String,
@@ -60,7 +60,7 @@ impl Annotation {
 /// An ordered collection of annotations (either all-node or all-link).
 ///
 /// Corresponds to `AnnotationSetImpl` in the Java implementation.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
 pub struct AnnotationSet {
     annotations: Vec<Annotation>,
 }
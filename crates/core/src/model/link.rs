@@ -17,7 +17,14 @@ use std::fmt;
 /// BioFabric uses "shadow links" to show edges twice - once at each endpoint's
 /// natural position. This helps reveal local structure. A link and its shadow
 /// share the same source, target, and relation but `is_shadow` differs.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// ## Weight
+///
+/// `weight` is not part of equality/hashing (an `f64` can't implement
+/// `Eq`/`Hash`), so two links that otherwise match are still considered the
+/// same link regardless of weight — matching how `directed`/`is_shadow`
+/// define identity but display-only attributes don't.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Link {
     /// Source node ID.
     pub source: NodeId,
@@ -38,6 +45,37 @@ pub struct Link {
     /// Shadow links are duplicates that appear at the "other end" of an edge
     /// to improve visualization of local structure.
     pub is_shadow: bool,
+
+    /// Optional edge weight, e.g. parsed from a trailing numeric SIF token
+    /// (`A pp B 0.73`). `None` when the source format carries no weight.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+}
+
+impl PartialEq for Link {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+            && self.target == other.target
+            && self.relation == other.relation
+            && self.directed == other.directed
+            && self.is_shadow == other.is_shadow
+    }
+}
+
+impl Eq for Link {}
+
+impl std::hash::Hash for Link {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.source.hash(state);
+        self.target.hash(state);
+        self.relation.hash(state);
+        self.directed.hash(state);
+        self.is_shadow.hash(state);
+    }
 }
 
 impl Link {
@@ -53,6 +91,7 @@ impl Link {
             relation: relation.into(),
             directed: None,
             is_shadow: false,
+            weight: None,
         }
     }
 
@@ -69,6 +108,7 @@ impl Link {
             relation: relation.into(),
             directed: None,
             is_shadow,
+            weight: None,
         }
     }
 
@@ -92,20 +132,26 @@ impl Link {
             relation: self.relation.clone(),
             directed: self.directed,
             is_shadow: self.is_shadow,
+            weight: self.weight,
         }
     }
 
     /// Create the shadow version of this link.
     ///
     /// Returns `None` for feedback links (self-loops), which have no
-    /// meaningful shadow. The caller (`Network::generate_shadows`) already
-    /// skips feedback links, but this API makes the invariant explicit.
+    /// meaningful shadow, and for explicitly directed links (`directed ==
+    /// Some(true)`), since a directed edge only makes sense drawn once, at
+    /// its source row. Undirected (`Some(false)`) and unresolved (`None`)
+    /// links still get a shadow. The caller (`Network::generate_shadows`)
+    /// already skips feedback links, but this API makes both invariants
+    /// explicit.
     ///
     /// ## References
     ///
-    /// - Java: `BioFabricNetwork.processLinks_()` skips feedback links
+    /// - Java: `BioFabricNetwork.processLinks_()` skips feedback and
+    ///   directed links when building shadow links
     pub fn to_shadow(&self) -> Option<Self> {
-        if self.is_feedback() {
+        if self.is_feedback() || self.directed == Some(true) {
             None
         } else {
             let mut shadow = self.flipped();
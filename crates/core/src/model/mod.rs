@@ -15,6 +15,6 @@ pub mod selection;
 
 pub use annotation::{Annotation, AnnotationSet};
 pub use link::Link;
-pub use network::{Network, NetworkMetadata, NodeComparison};
+pub use network::{Network, NetworkDiff, NetworkMetadata, NodeComparison};
 pub use node::{Node, NodeId};
 pub use selection::SelectionState;
@@ -6,7 +6,7 @@
 use super::{Link, Node, NodeId};
 use indexmap::{IndexMap, IndexSet};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Adjacency index for fast node-to-link lookup.
 ///
@@ -56,6 +56,18 @@ pub struct NetworkMetadata {
 
     /// Optional description / source annotation.
     pub description: Option<String>,
+
+    /// Header/comment lines carried by the original source file, if any.
+    ///
+    /// Populated by parsers for formats that support free-text comments
+    /// (currently the GW loader, which preserves `#`-prefixed lines) and
+    /// persisted through a BIF round-trip for provenance tracking.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    #[serde(default)]
+    pub source_comments: Vec<String>,
 }
 
 /// A network (graph) containing nodes and links.
@@ -184,6 +196,22 @@ impl Network {
         self.links.push(link);
     }
 
+    /// Add several links at once.
+    ///
+    /// Equivalent to calling [`Network::add_link`] for each link, but
+    /// convenient when editing a network incrementally (e.g. before calling
+    /// [`DefaultEdgeLayout::relayout_with_fixed_nodes`](crate::layout::DefaultEdgeLayout::relayout_with_fixed_nodes)
+    /// to recompute only the affected columns).
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn add_links(&mut self, links: impl IntoIterator<Item = Link>) {
+        for link in links {
+            self.add_link(link);
+        }
+    }
+
     /// Get the number of links.
     pub fn link_count(&self) -> usize {
         self.links.len()
@@ -346,6 +374,25 @@ impl Network {
         count
     }
 
+    /// Remove all shadow links, restoring the non-shadow link set.
+    ///
+    /// Pairs with [`Network::generate_shadows`] so shadow presence can be
+    /// toggled without reparsing the source file — e.g. `strip_shadows()`
+    /// followed by `generate_shadows()` regenerates a clean, duplicate-free
+    /// shadow set even if shadows had previously been generated more than
+    /// once.
+    ///
+    /// Returns the number of shadow links removed.
+    pub fn strip_shadows(&mut self) -> usize {
+        let before = self.links.len();
+        self.links.retain(|link| !link.is_shadow);
+        let removed = before - self.links.len();
+        if removed > 0 {
+            self.invalidate_adjacency();
+        }
+        removed
+    }
+
     /// Check whether shadow links have already been generated.
     ///
     /// Returns `true` if at least one link has `is_shadow == true`.
@@ -363,6 +410,103 @@ impl Network {
         self.links.iter().filter(|l| !l.is_shadow).count()
     }
 
+    /// Build a degree-capped copy of this network, for a readable
+    /// approximation of hub-dominated ("hairball") graphs.
+    ///
+    /// For each node whose degree exceeds `max_degree`, only its
+    /// `max_degree` edges are kept; the rest are dropped. Kept edges are the
+    /// highest-[`weight`](Link::weight) ones (unweighted edges rank below
+    /// every weighted one); ties are broken lexicographically by the
+    /// neighbor's node ID, then by relation, for deterministic output.
+    ///
+    /// An edge is dropped if it's cut from *either* endpoint's kept set, so
+    /// the result is a true cap: no node in the returned network has degree
+    /// greater than `max_degree`.
+    pub fn cap_degree(&self, max_degree: usize) -> Network {
+        let mut keep = vec![true; self.links.len()];
+
+        for node_id in self.node_ids() {
+            let mut incident: Vec<usize> = self
+                .links
+                .iter()
+                .enumerate()
+                .filter(|(_, link)| &link.source == node_id || &link.target == node_id)
+                .map(|(i, _)| i)
+                .collect();
+
+            if incident.len() <= max_degree {
+                continue;
+            }
+
+            incident.sort_by(|&a, &b| {
+                let link_a = &self.links[a];
+                let link_b = &self.links[b];
+                let other_a = if &link_a.source == node_id {
+                    &link_a.target
+                } else {
+                    &link_a.source
+                };
+                let other_b = if &link_b.source == node_id {
+                    &link_b.target
+                } else {
+                    &link_b.source
+                };
+                let weight_a = link_a.weight.unwrap_or(f64::NEG_INFINITY);
+                let weight_b = link_b.weight.unwrap_or(f64::NEG_INFINITY);
+                weight_b
+                    .total_cmp(&weight_a)
+                    .then_with(|| other_a.cmp(other_b))
+                    .then_with(|| link_a.relation.cmp(&link_b.relation))
+            });
+
+            for &idx in &incident[max_degree..] {
+                keep[idx] = false;
+            }
+        }
+
+        let mut capped = Network::with_capacity(self.nodes.len(), self.links.len());
+        for node in self.nodes.values() {
+            capped.add_node(node.clone());
+        }
+        for (i, link) in self.links.iter().enumerate() {
+            if keep[i] {
+                capped.links.push(link.clone());
+            }
+        }
+        for lone in &self.lone_nodes {
+            capped.add_lone_node(lone.clone());
+        }
+        capped.metadata = self.metadata.clone();
+        capped
+    }
+
+    /// Build a copy of this network with every self-loop (feedback) link
+    /// removed, for callers who want a node's degree and the fabric's
+    /// column count to reflect only its edges to other nodes.
+    ///
+    /// A self-loop's vertical link currently spans zero rows (source and
+    /// target are the same row), so it still gets a column but draws as a
+    /// single point rather than a visible vertical span; dropping it here
+    /// is the only way to exclude it from that column count entirely.
+    ///
+    /// See [`LayoutParams::drop_self_loops`](crate::layout::traits::LayoutParams::drop_self_loops).
+    pub fn remove_self_loops(&self) -> Network {
+        let mut trimmed = Network::with_capacity(self.nodes.len(), self.links.len());
+        for node in self.nodes.values() {
+            trimmed.add_node(node.clone());
+        }
+        for link in &self.links {
+            if !link.is_feedback() {
+                trimmed.links.push(link.clone());
+            }
+        }
+        for lone in &self.lone_nodes {
+            trimmed.add_lone_node(lone.clone());
+        }
+        trimmed.metadata = self.metadata.clone();
+        trimmed
+    }
+
     // =========================================================================
     // Adjacency index (stub)
     // =========================================================================
@@ -552,6 +696,68 @@ impl Network {
         result
     }
 
+    /// Get the first neighbors (1-hop) of a single node, sorted by name.
+    ///
+    /// Unlike [`first_neighbors`](Self::first_neighbors) (which expands a
+    /// *set* of seeds and keeps the seeds in the result), this excludes
+    /// `node` itself — including when `node` has a self-loop, which would
+    /// otherwise make [`neighbors`](Self::neighbors) report it as its own
+    /// neighbor.
+    pub fn first_neighbors_of(&self, node: &NodeId) -> Vec<NodeId> {
+        let mut result: Vec<NodeId> =
+            self.neighbors(node).into_iter().filter(|&n| n != node).cloned().collect();
+        result.sort();
+        result
+    }
+
+    /// Hop-count distance from `from` to every node reachable from it, via
+    /// BFS over the undirected neighbor graph (shadow links included,
+    /// since they duplicate an existing edge rather than add a new one).
+    ///
+    /// `from` itself is included at distance `0`. Nodes not reachable from
+    /// `from` are absent from the map; if `from` doesn't exist in the
+    /// network, the map is empty.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn bfs_distances(&self, from: &NodeId) -> HashMap<NodeId, usize> {
+        let mut distances = HashMap::new();
+        if !self.contains_node(from) {
+            return distances;
+        }
+
+        let mut queue = std::collections::VecDeque::new();
+        distances.insert(from.clone(), 0);
+        queue.push_back(from.clone());
+
+        while let Some(node_id) = queue.pop_front() {
+            let depth = distances[&node_id];
+            for neighbor in self.neighbors(&node_id) {
+                if !distances.contains_key(neighbor) {
+                    distances.insert(neighbor.clone(), depth + 1);
+                    queue.push_back(neighbor.clone());
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Hop-count distance between `a` and `b` over the undirected neighbor
+    /// graph, or `None` if either node is missing from the network or
+    /// they're in different connected components.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn shortest_path_len(&self, a: &NodeId, b: &NodeId) -> Option<usize> {
+        if a == b {
+            return self.contains_node(a).then_some(0);
+        }
+        self.bfs_distances(a).get(b).copied()
+    }
+
     // =========================================================================
     // Subnetwork extraction
     // =========================================================================
@@ -577,6 +783,24 @@ impl Network {
             }
         }
 
+        // A node that ended up with no incident edge in the extracted
+        // subgraph is a lone node here, even if it wasn't one in `self` —
+        // its only neighbor(s) may simply have fallen outside `node_ids`.
+        let connected: HashSet<&NodeId> = sub
+            .links
+            .iter()
+            .flat_map(|l| [&l.source, &l.target])
+            .collect();
+        let newly_lone: Vec<NodeId> = sub
+            .nodes
+            .keys()
+            .filter(|id| !connected.contains(id))
+            .cloned()
+            .collect();
+        for id in newly_lone {
+            sub.lone_nodes.insert(id);
+        }
+
         // Propagate relevant metadata
         sub.metadata = self.metadata.clone();
         sub.metadata.name = self.metadata.name.as_ref().map(|n| format!("{} (subnetwork)", n));
@@ -675,6 +899,219 @@ impl Network {
             jaccard_similarity: jaccard,
         })
     }
+
+    /// Pairwise Jaccard similarity of `nodes`' neighborhoods, generalizing
+    /// [`compare_nodes`](Self::compare_nodes) from a pair to an arbitrary
+    /// set — useful for clustering or rendering a similarity heatmap.
+    ///
+    /// Returns a square matrix where entry `[i][j]` is the Jaccard
+    /// similarity of `nodes[i]` and `nodes[j]`'s neighbor sets. The
+    /// diagonal is always `1.0`, and the matrix is symmetric. Nodes not
+    /// present in the network are treated as having an empty neighborhood
+    /// rather than causing an error, matching `neighbors`'s own behavior
+    /// for unknown nodes.
+    pub fn neighborhood_similarity_matrix(&self, nodes: &[NodeId]) -> Vec<Vec<f64>> {
+        let neighbor_sets: Vec<HashSet<NodeId>> = nodes
+            .iter()
+            .map(|n| self.neighbors(n).into_iter().cloned().collect())
+            .collect();
+
+        neighbor_sets
+            .iter()
+            .map(|a| {
+                neighbor_sets
+                    .iter()
+                    .map(|b| {
+                        let union_size = a.union(b).count();
+                        if union_size == 0 {
+                            if a.is_empty() && b.is_empty() { 1.0 } else { 0.0 }
+                        } else {
+                            a.intersection(b).count() as f64 / union_size as f64
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Normalize a link to the key [`deduplicate_links`](Self::deduplicate_links)
+    /// and [`union`](Self::union)/[`intersection`](Self::intersection) use to
+    /// decide link identity: source, target, and relation, with source/target
+    /// swapped to a canonical order for undirected links so `A-B` and `B-A`
+    /// compare equal.
+    fn link_identity(link: &Link) -> (NodeId, NodeId, String) {
+        if link.directed != Some(true) && link.source > link.target {
+            (link.target.clone(), link.source.clone(), link.relation.clone())
+        } else {
+            (link.source.clone(), link.target.clone(), link.relation.clone())
+        }
+    }
+
+    /// Build the union of `self` and `other`: every node from both, and
+    /// every distinct link from both, where two links are the same link if
+    /// they share a [`link_identity`](Self::link_identity) (same source,
+    /// target, and relation, order-insensitive for undirected links).
+    ///
+    /// When both networks have a link with the same identity, the copy from
+    /// `self` is kept (so its `weight`/`directed` details win). Shadow
+    /// links are ignored on input (a link and its own shadow share a
+    /// canonical identity and would otherwise collide as "duplicates") and
+    /// regenerated on the result via [`generate_shadows`](Self::generate_shadows),
+    /// so a union of two shadowed networks is itself shadowed. Lone nodes
+    /// from both sides are preserved as lone nodes unless an added link
+    /// gives them an edge.
+    ///
+    /// This supports comparing two experimental conditions before aligning
+    /// them, without requiring the caller to hand-merge two SIF files.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn union(&self, other: &Network) -> Network {
+        let mut merged = Network::with_capacity(
+            self.nodes.len() + other.nodes.len(),
+            self.links.len() + other.links.len(),
+        );
+
+        for node in self.nodes.values().chain(other.nodes.values()) {
+            merged.add_node(node.clone());
+        }
+
+        let mut seen = HashSet::new();
+        for link in self.links.iter().chain(other.links.iter()).filter(|l| !l.is_shadow) {
+            if seen.insert(Self::link_identity(link)) {
+                merged.add_link(link.clone());
+            }
+        }
+
+        for lone in self.lone_nodes.iter().chain(other.lone_nodes.iter()) {
+            if !merged.links.iter().any(|l| &l.source == lone || &l.target == lone) {
+                merged.add_lone_node(lone.clone());
+            }
+        }
+
+        if self.has_shadows() || other.has_shadows() {
+            merged.generate_shadows();
+        }
+        merged
+    }
+
+    /// Build the intersection of `self` and `other`: only links present in
+    /// both (matched by [`link_identity`](Self::link_identity)), and only
+    /// the nodes those shared links touch. Lone nodes are never part of the
+    /// result, since a lone node by definition has no link to intersect on.
+    ///
+    /// The kept copy of each shared link is `self`'s. Shadow links are
+    /// ignored on input for the same reason as in [`union`](Self::union)
+    /// and regenerated on the result via
+    /// [`generate_shadows`](Self::generate_shadows).
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn intersection(&self, other: &Network) -> Network {
+        let other_identities: HashSet<(NodeId, NodeId, String)> =
+            other.links.iter().filter(|l| !l.is_shadow).map(Self::link_identity).collect();
+
+        let mut shared = Network::with_capacity(0, self.links.len().min(other.links.len()));
+        let mut seen = HashSet::new();
+        for link in self.links.iter().filter(|l| !l.is_shadow) {
+            let identity = Self::link_identity(link);
+            if other_identities.contains(&identity) && seen.insert(identity) {
+                shared.add_link(link.clone());
+            }
+        }
+
+        if self.has_shadows() || other.has_shadows() {
+            shared.generate_shadows();
+        }
+        shared
+    }
+
+    /// Compare `self` ("before") against `other` ("after"): which links and
+    /// nodes `other` has that `self` doesn't, and which `self` has that
+    /// `other` doesn't.
+    ///
+    /// Links are matched by [`link_identity`](Self::link_identity) (same
+    /// source, target, and relation, order-insensitive for undirected
+    /// links), so a link that was merely re-laid-out doesn't count as
+    /// changed. Useful for comparing two experimental conditions, or two
+    /// revisions of the same network, before deciding whether an alignment
+    /// between them is worth running.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn diff(&self, other: &Network) -> NetworkDiff {
+        let self_identities: HashSet<(NodeId, NodeId, String)> =
+            self.links.iter().map(Self::link_identity).collect();
+        let other_identities: HashSet<(NodeId, NodeId, String)> =
+            other.links.iter().map(Self::link_identity).collect();
+
+        let mut added_links = Vec::new();
+        let mut seen = HashSet::new();
+        for link in &other.links {
+            let identity = Self::link_identity(link);
+            if !self_identities.contains(&identity) && seen.insert(identity) {
+                added_links.push(link.clone());
+            }
+        }
+
+        let mut removed_links = Vec::new();
+        seen.clear();
+        for link in &self.links {
+            let identity = Self::link_identity(link);
+            if !other_identities.contains(&identity) && seen.insert(identity) {
+                removed_links.push(link.clone());
+            }
+        }
+
+        let added_nodes: Vec<NodeId> = other
+            .node_ids()
+            .filter(|id| !self.contains_node(id))
+            .cloned()
+            .collect();
+        let removed_nodes: Vec<NodeId> = self
+            .node_ids()
+            .filter(|id| !other.contains_node(id))
+            .cloned()
+            .collect();
+
+        NetworkDiff {
+            added_links,
+            removed_links,
+            added_nodes,
+            removed_nodes,
+        }
+    }
+}
+
+/// Result of [`Network::diff`]: what changed going from one network
+/// ("before") to another ("after").
+///
+/// ## References
+///
+/// (none — not in the Java original)
+#[derive(Debug, Clone, Default)]
+pub struct NetworkDiff {
+    /// Links present in "after" but not "before".
+    pub added_links: Vec<Link>,
+    /// Links present in "before" but not "after".
+    pub removed_links: Vec<Link>,
+    /// Nodes present in "after" but not "before".
+    pub added_nodes: Vec<NodeId>,
+    /// Nodes present in "before" but not "after".
+    pub removed_nodes: Vec<NodeId>,
+}
+
+impl NetworkDiff {
+    /// Whether anything changed at all.
+    pub fn is_empty(&self) -> bool {
+        self.added_links.is_empty()
+            && self.removed_links.is_empty()
+            && self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+    }
 }
 
 /// Result of comparing the neighborhoods of two nodes.
@@ -752,6 +1189,15 @@ mod tests {
         assert!(neighbors.contains(&NodeId::new("C")));
     }
 
+    #[test]
+    fn test_first_neighbors_of_excludes_self_loop() {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "A", "r"));
+        network.add_link(Link::new("A", "B", "r"));
+
+        assert_eq!(network.first_neighbors_of(&NodeId::new("A")), vec![NodeId::new("B")]);
+    }
+
     #[test]
     fn test_lone_nodes() {
         let mut network = Network::new();
@@ -807,6 +1253,26 @@ mod tests {
         assert_eq!(network.link_count(), 3);
     }
 
+    #[test]
+    fn test_strip_shadows_then_generate_restores_original_link_set() {
+        // Same content as tests/parity/networks/sif/triangle.sif.
+        let mut network = crate::io::sif::parse_string("A\tpp\tB\nB\tpp\tC\nA\tpp\tC\n").unwrap();
+        assert!(network.has_shadows());
+
+        let original: std::collections::HashSet<Link> = network.links().cloned().collect();
+
+        let removed = network.strip_shadows();
+        assert_eq!(removed, 3);
+        assert!(!network.has_shadows());
+        assert_eq!(network.regular_link_count(), 3);
+
+        let regenerated = network.generate_shadows();
+        assert_eq!(regenerated, 3);
+
+        let restored: std::collections::HashSet<Link> = network.links().cloned().collect();
+        assert_eq!(restored, original);
+    }
+
     #[test]
     fn test_json_roundtrip() {
         let mut network = Network::new();
@@ -819,4 +1285,212 @@ mod tests {
         assert_eq!(restored.node_count(), network.node_count());
         assert_eq!(restored.link_count(), network.link_count());
     }
+
+    #[test]
+    fn test_cap_degree_limits_hub_to_max_degree() {
+        let mut network = Network::new();
+        for i in 0..500 {
+            network.add_link(Link::new("hub", format!("leaf{i}"), "pp"));
+        }
+        assert_eq!(network.degree(&NodeId::new("hub")), 500);
+
+        let capped = network.cap_degree(50);
+
+        assert_eq!(capped.degree(&NodeId::new("hub")), 50);
+        assert_eq!(capped.link_count(), 50);
+        // Every node is preserved even if it lost its only edge.
+        assert_eq!(capped.node_count(), network.node_count());
+    }
+
+    #[test]
+    fn test_cap_degree_leaves_low_degree_nodes_untouched() {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "r1"));
+        network.add_link(Link::new("A", "C", "r2"));
+
+        let capped = network.cap_degree(5);
+
+        assert_eq!(capped.link_count(), network.link_count());
+    }
+
+    #[test]
+    fn test_neighborhood_similarity_matrix_diagonal_and_symmetry() {
+        // Same content as tests/parity/networks/sif/triangle.sif.
+        let network = crate::io::sif::parse_string("A\tpp\tB\nB\tpp\tC\nA\tpp\tC\n").unwrap();
+        let nodes = vec![NodeId::new("A"), NodeId::new("B"), NodeId::new("C")];
+
+        let matrix = network.neighborhood_similarity_matrix(&nodes);
+
+        assert_eq!(matrix.len(), 3);
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(row.len(), 3);
+            assert_eq!(row[i], 1.0);
+        }
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                assert_eq!(value, matrix[j][i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shortest_path_len_end_to_end_on_a_linear_chain() {
+        // Same content as tests/parity/networks/sif/linear_chain.sif: ten
+        // nodes N1..N10 in a single chain, nine hops apart end to end.
+        let sif = "N1\tpp\tN2\nN2\tpp\tN3\nN3\tpp\tN4\nN4\tpp\tN5\nN5\tpp\tN6\n\
+                   N6\tpp\tN7\nN7\tpp\tN8\nN8\tpp\tN9\nN9\tpp\tN10\n";
+        let network = crate::io::sif::parse_string(sif).unwrap();
+
+        assert_eq!(
+            network.shortest_path_len(&NodeId::new("N1"), &NodeId::new("N10")),
+            Some(9)
+        );
+        assert_eq!(
+            network.shortest_path_len(&NodeId::new("N5"), &NodeId::new("N5")),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_shortest_path_len_is_none_across_disconnected_components() {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "r"));
+        network.add_link(Link::new("C", "D", "r"));
+
+        assert_eq!(network.shortest_path_len(&NodeId::new("A"), &NodeId::new("D")), None);
+        assert_eq!(network.shortest_path_len(&NodeId::new("A"), &NodeId::new("Z")), None);
+    }
+
+    #[test]
+    fn test_bfs_distances_from_a_linear_chain_start() {
+        let sif = "N1\tpp\tN2\nN2\tpp\tN3\nN3\tpp\tN4\n";
+        let network = crate::io::sif::parse_string(sif).unwrap();
+
+        let distances = network.bfs_distances(&NodeId::new("N1"));
+        assert_eq!(distances[&NodeId::new("N1")], 0);
+        assert_eq!(distances[&NodeId::new("N2")], 1);
+        assert_eq!(distances[&NodeId::new("N3")], 2);
+        assert_eq!(distances[&NodeId::new("N4")], 3);
+    }
+
+    /// Triangle A-B-C, sharing edge A-B with a second triangle A-B-D.
+    fn triangle(third: &str) -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        network.add_link(Link::new("B", third, "pp"));
+        network.add_link(Link::new(third, "A", "pp"));
+        network
+    }
+
+    #[test]
+    fn test_union_of_two_overlapping_triangles_keeps_every_distinct_link() {
+        let abc = triangle("C");
+        let abd = triangle("D");
+
+        let merged = abc.union(&abd);
+
+        assert_eq!(merged.node_count(), 4); // A, B, C, D
+        assert_eq!(merged.link_count(), 5); // A-B shared once, plus B-C/C-A, B-D/D-A
+        assert!(merged.contains_node(&NodeId::new("C")));
+        assert!(merged.contains_node(&NodeId::new("D")));
+    }
+
+    #[test]
+    fn test_union_is_order_insensitive_for_undirected_links() {
+        let mut flipped = Network::new();
+        flipped.add_link(Link::new("B", "A", "pp")); // reverse of triangle()'s A-B
+
+        let abc = triangle("C");
+        let merged = abc.union(&flipped);
+
+        // B-A and A-B are the same undirected link, so no new link is added.
+        assert_eq!(merged.link_count(), 3);
+    }
+
+    #[test]
+    fn test_intersection_of_two_overlapping_triangles_keeps_only_the_shared_edge() {
+        let abc = triangle("C");
+        let abd = triangle("D");
+
+        let shared = abc.intersection(&abd);
+
+        assert_eq!(shared.link_count(), 1);
+        assert!(shared.links().any(|l| (l.source == NodeId::new("A") && l.target == NodeId::new("B"))
+            || (l.source == NodeId::new("B") && l.target == NodeId::new("A"))));
+        assert_eq!(shared.node_count(), 2); // only A and B, touched by the shared edge
+        assert!(!shared.contains_node(&NodeId::new("C")));
+        assert!(!shared.contains_node(&NodeId::new("D")));
+    }
+
+    #[test]
+    fn test_extract_subnetwork_marks_newly_isolated_nodes_as_lone() {
+        // Path A-B-C: extracting {A, B} keeps the A-B edge, but extracting
+        // just {A, C} (not adjacent) should leave both as lone nodes in
+        // the induced subgraph, even though neither was lone in `network`.
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        network.add_link(Link::new("B", "C", "pp"));
+
+        let ac: HashSet<NodeId> = [NodeId::new("A"), NodeId::new("C")].into_iter().collect();
+        let sub = network.extract_subnetwork(&ac);
+
+        assert_eq!(sub.node_count(), 2);
+        assert_eq!(sub.link_count(), 0);
+        assert!(sub.lone_nodes().contains(&NodeId::new("A")));
+        assert!(sub.lone_nodes().contains(&NodeId::new("C")));
+
+        let ab: HashSet<NodeId> = [NodeId::new("A"), NodeId::new("B")].into_iter().collect();
+        let sub_ab = network.extract_subnetwork(&ab);
+        assert!(sub_ab.lone_nodes().is_empty());
+    }
+
+    #[test]
+    fn test_diff_of_two_single_edge_networks_with_one_edge_swapped() {
+        let mut before = Network::new();
+        before.add_link(Link::new("A", "B", "pp"));
+
+        let mut after = Network::new();
+        after.add_link(Link::new("A", "C", "pp"));
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.added_links.len(), 1);
+        assert_eq!(diff.added_links[0].target, NodeId::new("C"));
+        assert_eq!(diff.removed_links.len(), 1);
+        assert_eq!(diff.removed_links[0].target, NodeId::new("B"));
+        assert_eq!(diff.added_nodes, vec![NodeId::new("C")]);
+        assert_eq!(diff.removed_nodes, vec![NodeId::new("B")]);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_of_identical_networks_is_empty() {
+        let network = triangle("C");
+        let diff = network.diff(&network.clone());
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn test_union_with_empty_network_preserves_shadow_links() {
+        let mut network = Network::new();
+        network.add_link(Link::new("A", "B", "pp"));
+        network.generate_shadows();
+        assert_eq!(network.link_count(), 2); // the plain link plus its shadow
+
+        let merged = network.union(&Network::new());
+
+        assert_eq!(merged.link_count(), 2);
+        assert!(merged.has_shadows());
+    }
+
+    #[test]
+    fn test_intersection_of_disjoint_networks_is_empty() {
+        let abc = triangle("C");
+        let mut unrelated = Network::new();
+        unrelated.add_link(Link::new("X", "Y", "pp"));
+
+        let shared = abc.intersection(&unrelated);
+        assert_eq!(shared.link_count(), 0);
+        assert_eq!(shared.node_count(), 0);
+    }
 }
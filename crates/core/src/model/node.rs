@@ -5,7 +5,7 @@
 //! - The node spans horizontally from its first to last incident edge
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fmt;
 
 /// Unique identifier for a node.
@@ -13,7 +13,7 @@ use std::fmt;
 /// This is a wrapper around a string name. In the Java implementation,
 /// nodes have both a numeric ID and a display name. For simplicity,
 /// we use the name as the ID directly.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, bincode::Encode, bincode::Decode)]
 pub struct NodeId(pub String);
 
 impl NodeId {
@@ -76,8 +76,8 @@ pub struct Node {
     /// ## References
     ///
     /// - Java: `AttributeLoader` populates these from column-delimited files
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub attributes: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub attributes: BTreeMap<String, String>,
 }
 
 impl PartialEq for Node {
@@ -99,12 +99,12 @@ impl Node {
     pub fn new(id: impl Into<NodeId>) -> Self {
         Self {
             id: id.into(),
-            attributes: HashMap::new(),
+            attributes: BTreeMap::new(),
         }
     }
 
     /// Create a new node with the given ID and attributes.
-    pub fn with_attributes(id: impl Into<NodeId>, attributes: HashMap<String, String>) -> Self {
+    pub fn with_attributes(id: impl Into<NodeId>, attributes: BTreeMap<String, String>) -> Self {
         Self {
             id: id.into(),
             attributes,
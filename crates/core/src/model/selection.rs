@@ -22,9 +22,10 @@
 use crate::layout::result::NetworkLayout;
 use crate::model::NodeId;
 use indexmap::IndexSet;
+use serde::{Deserialize, Serialize};
 
 /// What is currently selected in the visualization.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SelectionState {
     /// Selected node IDs.
     pub nodes: IndexSet<NodeId>,
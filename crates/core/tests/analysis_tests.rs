@@ -63,63 +63,6 @@ fn mark_pd_directed(network: &mut Network) {
     network.metadata.is_directed = true;
 }
 
-/// Detect cycles in an undirected graph (ignoring shadow links).
-fn has_undirected_cycle(network: &Network) -> bool {
-    let mut adj: HashMap<NodeId, HashSet<NodeId>> = HashMap::new();
-
-    for link in network.links() {
-        if link.is_shadow {
-            continue;
-        }
-        if link.relation.eq_ignore_ascii_case("pd") {
-            continue;
-        }
-        if link.source == link.target {
-            return true; // self-loop is a cycle
-        }
-        adj.entry(link.source.clone())
-            .or_default()
-            .insert(link.target.clone());
-        adj.entry(link.target.clone())
-            .or_default()
-            .insert(link.source.clone());
-    }
-
-    let mut visited: HashSet<NodeId> = HashSet::new();
-    for node in network.node_ids() {
-        if visited.contains(node) {
-            continue;
-        }
-        if dfs_undirected_cycle(node, None, &adj, &mut visited) {
-            return true;
-        }
-    }
-    false
-}
-
-fn dfs_undirected_cycle(
-    node: &NodeId,
-    parent: Option<&NodeId>,
-    adj: &HashMap<NodeId, HashSet<NodeId>>,
-    visited: &mut HashSet<NodeId>,
-) -> bool {
-    visited.insert(node.clone());
-    if let Some(neighbors) = adj.get(node) {
-        for neighbor in neighbors {
-            if Some(neighbor) == parent {
-                continue;
-            }
-            if visited.contains(neighbor) {
-                return true;
-            }
-            if dfs_undirected_cycle(neighbor, Some(node), adj, visited) {
-                return true;
-            }
-        }
-    }
-    false
-}
-
 /// Get the alignment config for a golden dir name.
 /// Returns (g1_file, g2_file, align_file, perfect_align_file).
 fn alignment_config_for_golden(golden_dir: &str) -> Option<(&'static str, &'static str, &'static str, Option<&'static str>)> {
@@ -172,7 +115,7 @@ fn run_cycle_test(input_file: &str, _expected_has_cycle: bool) {
         mark_pd_directed(&mut network);
         biofabric_core::analysis::cycle::find_cycle(&network).has_cycle
     };
-    let undirected_cycle = has_undirected_cycle(&network);
+    let undirected_cycle = biofabric_core::analysis::has_cycle(&network);
     let has_cycle = directed_cycle || undirected_cycle;
 
     assert_eq!(
@@ -479,6 +422,59 @@ fn components_bipartite() {
     run_components_test("bipartite.sif", &[6]);
 }
 
+/// The union-find based [`connected_components_union_find`] must produce
+/// the exact same partition of nodes into components as the BFS-based
+/// [`connected_components`], on every SIF network used by the component
+/// tests above. The two only need to agree on *which nodes end up
+/// together*, not on node/component ordering, since union-find sorts
+/// lexicographically while BFS orders by traversal from the
+/// highest-degree node.
+#[test]
+fn union_find_components_match_bfs_components_on_all_test_networks() {
+    let networks = [
+        "single_node.sif",
+        "single_edge.sif",
+        "triangle.sif",
+        "self_loop.sif",
+        "isolated_nodes.sif",
+        "disconnected_components.sif",
+        "linear_chain.sif",
+        "dense_clique.sif",
+        "multi_relation.sif",
+        "bipartite.sif",
+        "dag_simple.sif",
+        "dag_diamond.sif",
+        "dag_deep.sif",
+    ];
+
+    for name in networks {
+        let network = load_network(&network_path(name));
+
+        let mut bfs_partition: Vec<HashSet<NodeId>> = biofabric_core::analysis::graph::connected_components(&network)
+            .into_iter()
+            .map(|c| c.into_iter().collect())
+            .collect();
+        let mut uf_partition: Vec<HashSet<NodeId>> =
+            biofabric_core::analysis::graph::connected_components_union_find(&network)
+                .into_iter()
+                .map(|c| c.into_iter().collect())
+                .collect();
+
+        // Order-independent comparison: sort each side by a stable key
+        // derived from its members so set equality implies partition
+        // equality regardless of which order each algorithm emits them in.
+        let sort_key = |c: &HashSet<NodeId>| -> Vec<NodeId> {
+            let mut v: Vec<NodeId> = c.iter().cloned().collect();
+            v.sort();
+            v
+        };
+        bfs_partition.sort_by_key(sort_key);
+        uf_partition.sort_by_key(sort_key);
+
+        assert_eq!(bfs_partition, uf_partition, "component partition mismatch for {}", name);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Topological sort tests (DAGs only)
 // ---------------------------------------------------------------------------
@@ -586,7 +582,7 @@ fn run_degree_test(input_file: &str, expected_degrees: &[(&str, usize)]) {
     assert!(input.exists(), "Input file not found: {}", input.display());
 
     let network = load_network(&input);
-    let degrees = compute_endpoint_degrees(&network);
+    let degrees = biofabric_core::analysis::graph::node_degree(&network);
     for (name, expected_deg) in expected_degrees {
         let actual = degrees.get(&NodeId::new(*name)).copied().unwrap_or(0);
         assert_eq!(
@@ -597,25 +593,6 @@ fn run_degree_test(input_file: &str, expected_degrees: &[(&str, usize)]) {
     }
 }
 
-fn compute_endpoint_degrees(network: &Network) -> HashMap<NodeId, usize> {
-    let mut degrees: HashMap<NodeId, usize> = HashMap::new();
-
-    for id in network.node_ids() {
-        degrees.entry(id.clone()).or_insert(0);
-    }
-
-    for link in network.links() {
-        if link.source == link.target {
-            *degrees.entry(link.source.clone()).or_insert(0) += 2;
-        } else {
-            *degrees.entry(link.source.clone()).or_insert(0) += 1;
-            *degrees.entry(link.target.clone()).or_insert(0) += 1;
-        }
-    }
-
-    degrees
-}
-
 #[test]
 fn degree_triangle() {
     // A-B(pp), B-C(pp), A-C(pp) — undirected, with shadows
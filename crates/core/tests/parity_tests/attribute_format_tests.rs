@@ -0,0 +1,64 @@
+// ===========================================================================
+//
+//  NODE-CLUSTER ATTRIBUTE FILE FORMAT TESTS
+//
+//  These tests verify that the `.na`-style ("name = cluster") and
+//  tab-separated ("name\tcluster") attribute file formats produce identical
+//  cluster assignments, and therefore identical NodeCluster layouts.
+//
+// ===========================================================================
+
+use crate::runners::*;
+use biofabric_core::io::sif::parse_string;
+use biofabric_core::layout::cluster::{ClusterOrder, InterClusterPlacement, NodeClusterLayout};
+use biofabric_core::layout::traits::LayoutParams;
+use biofabric_core::worker::NoopMonitor;
+
+#[test]
+fn test_equals_and_tab_formats_produce_identical_assignments() {
+    let equals_content = "ClusterAssignment\nA = group1\nB = group1\nC = group2\n";
+    let tab_content = "ClusterAssignment\nA\tgroup1\nB\tgroup1\nC\tgroup2\n";
+
+    let equals_assignments = parse_cluster_assignments(equals_content);
+    let tab_assignments = parse_cluster_assignments(tab_content);
+
+    assert_eq!(equals_assignments, tab_assignments);
+    assert_eq!(equals_assignments.len(), 3);
+}
+
+#[test]
+fn test_equals_and_tab_formats_produce_identical_node_cluster_layouts() {
+    let network = parse_string("A\tpp\tB\nB\tpp\tC\nC\tpp\tA\n").unwrap();
+    let monitor = NoopMonitor;
+    let params = LayoutParams {
+        include_shadows: true,
+        ..Default::default()
+    };
+
+    let equals_assignments =
+        parse_cluster_assignments("ClusterAssignment\nA = group1\nB = group1\nC = group2\n");
+    let tab_assignments =
+        parse_cluster_assignments("ClusterAssignment\nA\tgroup1\nB\tgroup1\nC\tgroup2\n");
+
+    let equals_layout = NodeClusterLayout::new(equals_assignments)
+        .with_order(ClusterOrder::Name)
+        .with_inter_cluster(InterClusterPlacement::Inline)
+        .full_layout(&network, &params, &monitor)
+        .unwrap();
+    let tab_layout = NodeClusterLayout::new(tab_assignments)
+        .with_order(ClusterOrder::Name)
+        .with_inter_cluster(InterClusterPlacement::Inline)
+        .full_layout(&network, &params, &monitor)
+        .unwrap();
+
+    let equals_rows: Vec<(String, usize)> = equals_layout
+        .iter_nodes()
+        .map(|(id, nl)| (id.to_string(), nl.row))
+        .collect();
+    let tab_rows: Vec<(String, usize)> = tab_layout
+        .iter_nodes()
+        .map(|(id, nl)| (id.to_string(), nl.row))
+        .collect();
+
+    assert_eq!(equals_rows, tab_rows);
+}
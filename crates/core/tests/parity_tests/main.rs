@@ -29,3 +29,4 @@ mod layout_tests;
 mod import_tests;
 mod alignment_tests;
 mod cycle_property_tests;
+mod attribute_format_tests;
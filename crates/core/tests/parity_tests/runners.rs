@@ -407,6 +407,31 @@ pub fn run_control_top_layout(
     edge_layout.layout_edges(&mut build_data, &params, &monitor).unwrap()
 }
 
+/// Parse cluster assignments from `.na`-style content.
+///
+/// Supports two line formats, auto-detected per line so a file can even mix
+/// them:
+/// - `name = cluster` (the original Cytoscape-style `.na` format)
+/// - `name\tcluster` (tab-separated, for users exporting from a spreadsheet)
+///
+/// The `ClusterAssignment` / `NodeCluster` header line and blank lines are
+/// skipped.
+pub fn parse_cluster_assignments(content: &str) -> HashMap<NodeId, String> {
+    let mut assignments: HashMap<NodeId, String> = HashMap::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed == "ClusterAssignment" || trimmed == "NodeCluster" {
+            continue;
+        }
+        if let Some((name, cluster)) = trimmed.split_once('\t') {
+            assignments.insert(NodeId::new(name.trim()), cluster.trim().to_string());
+        } else if let Some((name, cluster)) = trimmed.split_once(" = ") {
+            assignments.insert(NodeId::new(name.trim()), cluster.trim().to_string());
+        }
+    }
+    assignments
+}
+
 /// Run the NodeCluster layout with explicit ordering and placement modes.
 pub fn run_node_cluster_layout_with_params(
     network: &Network,
@@ -427,16 +452,7 @@ pub fn run_node_cluster_layout_with_params(
     let content = std::fs::read_to_string(&na_path)
         .unwrap_or_else(|e| panic!("Failed to read attribute file {}: {}", na_path.display(), e));
 
-    let mut assignments: HashMap<NodeId, String> = HashMap::new();
-    for line in content.lines() {
-        let trimmed = line.trim();
-        if trimmed.is_empty() || trimmed == "ClusterAssignment" || trimmed == "NodeCluster" {
-            continue;
-        }
-        if let Some((name, cluster)) = trimmed.split_once(" = ") {
-            assignments.insert(NodeId::new(name.trim()), cluster.trim().to_string());
-        }
-    }
+    let assignments = parse_cluster_assignments(&content);
 
     let node_layout = NodeClusterLayout::new(assignments)
         .with_order(order)
@@ -656,6 +672,7 @@ pub fn run_alignment_layout(
             mode,
             jaccard_correct.as_ref(),
             DEFAULT_JACCARD_THRESHOLD,
+            false,
             &monitor,
         )
     } else {
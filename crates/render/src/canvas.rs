@@ -0,0 +1,330 @@
+//! An in-memory RGBA raster canvas with axis-aligned line and rect drawing.
+//!
+//! All drawing goes through [`Canvas::fill_rect`], which blends a solid
+//! color into an axis-aligned rectangle using "over" alpha compositing.
+//! [`Canvas::draw_horizontal_line`] and [`Canvas::draw_vertical_line`] are
+//! thin wrappers that turn a centerline + thickness into the equivalent
+//! rectangle, so all three share one coverage-blending implementation.
+//!
+//! ## Antialiasing
+//!
+//! When `antialias` is `true`, a rectangle edge that falls between pixel
+//! boundaries contributes partial coverage to the pixels it overlaps,
+//! producing a smooth blend. When `false`, the rectangle's edges are
+//! rounded to the nearest pixel boundary first, so every covered pixel
+//! gets full coverage and no blending occurs — this is the default, for
+//! deterministic, parity-friendly output.
+
+use biofabric_core::io::color::FabricColor;
+
+/// A simple in-memory RGBA8 raster target.
+///
+/// ## References
+///
+/// - Java: `java.awt.image.BufferedImage` (the Java renderer paints onto
+///   one of these via `Graphics2D`)
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    pixels: Vec<FabricColor>,
+}
+
+impl Canvas {
+    /// Create a new canvas of the given size, filled with `background`.
+    pub fn new(width: usize, height: usize, background: FabricColor) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Canvas width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Canvas height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the color at `(x, y)`, or `None` if out of bounds.
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<FabricColor> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(self.pixels[y * self.width + x])
+    }
+
+    /// Blend `color` onto the pixel at `(x, y)` with the given coverage
+    /// (`0.0`–`1.0`), using source-over alpha compositing. Out-of-bounds
+    /// coordinates are silently ignored.
+    fn blend_pixel(&mut self, x: i64, y: i64, color: FabricColor, coverage: f64) {
+        if x < 0 || y < 0 || coverage <= 0.0 {
+            return;
+        }
+        let (x, y) = (x as usize, y as usize);
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let src_a = coverage.clamp(0.0, 1.0) * (color.a as f64 / 255.0);
+        if src_a <= 0.0 {
+            return;
+        }
+
+        let dst = self.pixels[y * self.width + x];
+        let dst_a = dst.a as f64 / 255.0;
+        let out_a = src_a + dst_a * (1.0 - src_a);
+
+        let blend_channel = |src: u8, dst: u8| -> u8 {
+            if out_a <= 0.0 {
+                return 0;
+            }
+            let v = (src as f64 * src_a + dst as f64 * dst_a * (1.0 - src_a)) / out_a;
+            v.round().clamp(0.0, 255.0) as u8
+        };
+
+        self.pixels[y * self.width + x] = FabricColor {
+            r: blend_channel(color.r, dst.r),
+            g: blend_channel(color.g, dst.g),
+            b: blend_channel(color.b, dst.b),
+            a: (out_a * 255.0).round().clamp(0.0, 255.0) as u8,
+        };
+    }
+
+    /// Fill an axis-aligned rectangle `[x0, x1) x [y0, y1)` with `color`.
+    ///
+    /// When `antialias` is `true`, edges that don't land on pixel
+    /// boundaries get partial coverage, blending `color` with whatever
+    /// is already there. When `false`, the rectangle is rounded to whole
+    /// pixels first, so every covered pixel gets full coverage.
+    ///
+    /// This is the shared primitive behind [`Canvas::draw_horizontal_line`]
+    /// and [`Canvas::draw_vertical_line`].
+    pub fn fill_rect(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, color: FabricColor, antialias: bool) {
+        let (mut x0, mut x1) = (x0.min(x1), x0.max(x1));
+        let (mut y0, mut y1) = (y0.min(y1), y0.max(y1));
+
+        if !antialias {
+            x0 = x0.round();
+            x1 = x1.round();
+            y0 = y0.round();
+            y1 = y1.round();
+        }
+
+        if x1 <= x0 || y1 <= y0 {
+            return;
+        }
+
+        let col_start = x0.floor() as i64;
+        let col_end = x1.ceil() as i64;
+        let row_start = y0.floor() as i64;
+        let row_end = y1.ceil() as i64;
+
+        for row in row_start..row_end {
+            let row_coverage = axis_coverage(row, y0, y1);
+            if row_coverage <= 0.0 {
+                continue;
+            }
+            for col in col_start..col_end {
+                let col_coverage = axis_coverage(col, x0, x1);
+                let coverage = row_coverage * col_coverage;
+                self.blend_pixel(col, row, color, coverage);
+            }
+        }
+    }
+
+    /// Draw a horizontal line centered at row `y`, spanning `[x0, x1]`,
+    /// with the given `width` (thickness perpendicular to the line).
+    pub fn draw_horizontal_line(
+        &mut self,
+        x0: f64,
+        x1: f64,
+        y: f64,
+        width: f64,
+        color: FabricColor,
+        antialias: bool,
+    ) {
+        let half = width / 2.0;
+        self.fill_rect(x0, y - half, x1, y + half, color, antialias);
+    }
+
+    /// Draw a vertical line centered at column `x`, spanning `[y0, y1]`,
+    /// with the given `width` (thickness perpendicular to the line).
+    pub fn draw_vertical_line(
+        &mut self,
+        x: f64,
+        y0: f64,
+        y1: f64,
+        width: f64,
+        color: FabricColor,
+        antialias: bool,
+    ) {
+        let half = width / 2.0;
+        self.fill_rect(x - half, y0, x + half, y1, color, antialias);
+    }
+
+    /// Draw an arbitrary (non-axis-aligned) one-pixel-wide line segment
+    /// from `(x0, y0)` to `(x1, y1)` using a simple DDA walk, stepping
+    /// along whichever axis spans more pixels so every step advances by
+    /// at most one pixel on the other axis.
+    ///
+    /// Unlike [`Canvas::draw_horizontal_line`]/[`Canvas::draw_vertical_line`],
+    /// this has no `width`/`antialias` parameters — it always draws a thin
+    /// line at full per-pixel coverage (only `color`'s own alpha blends),
+    /// which is enough for chord diagrams and
+    /// other sparse diagonal overlays without adding a general polygon
+    /// rasterizer.
+    ///
+    /// ## References
+    ///
+    /// (none — not in the Java original)
+    pub fn draw_line(&mut self, x0: f64, y0: f64, x1: f64, y1: f64, color: FabricColor) {
+        let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil().max(1.0) as usize;
+        for step in 0..=steps {
+            let t = step as f64 / steps as f64;
+            let x = x0 + (x1 - x0) * t;
+            let y = y0 + (y1 - y0) * t;
+            self.blend_pixel(x.round() as i64, y.round() as i64, color, 1.0);
+        }
+    }
+
+    /// Composite `other` onto this canvas with its top-left corner at
+    /// `(x, y)`, using the same source-over alpha blending as
+    /// [`Canvas::fill_rect`]. Pixels of `other` that would fall outside
+    /// this canvas are silently clipped.
+    pub fn blit(&mut self, other: &Canvas, x: i64, y: i64) {
+        for oy in 0..other.height {
+            for ox in 0..other.width {
+                let color = other.pixels[oy * other.width + ox];
+                self.blend_pixel(x + ox as i64, y + oy as i64, color, 1.0);
+            }
+        }
+    }
+}
+
+/// Fraction of pixel `idx` (spanning `[idx, idx + 1)`) covered by the
+/// real-valued interval `[start, end)`.
+fn axis_coverage(idx: i64, start: f64, end: f64) -> f64 {
+    let px_start = idx as f64;
+    let px_end = px_start + 1.0;
+    (end.min(px_end) - start.max(px_start)).max(0.0).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transparent() -> FabricColor {
+        FabricColor::rgba(0, 0, 0, 0)
+    }
+
+    fn opaque_black() -> FabricColor {
+        FabricColor::rgb(0, 0, 0)
+    }
+
+    #[test]
+    fn test_axis_coverage_fully_inside() {
+        assert_eq!(axis_coverage(5, 5.0, 6.0), 1.0);
+    }
+
+    #[test]
+    fn test_axis_coverage_partial() {
+        assert!((axis_coverage(4, 4.8, 5.8) - 0.2).abs() < 1e-9);
+        assert!((axis_coverage(5, 4.8, 5.8) - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_axis_coverage_outside() {
+        assert_eq!(axis_coverage(10, 4.8, 5.8), 0.0);
+    }
+
+    #[test]
+    fn test_antialias_off_produces_hard_edges() {
+        let mut canvas = Canvas::new(10, 10, transparent());
+        canvas.draw_horizontal_line(0.0, 10.0, 5.3, 1.0, opaque_black(), false);
+
+        // Hard-pixel mode rounds 5.3 to row 5; every touched pixel is
+        // either fully transparent or fully opaque, never in between.
+        for y in 0..10 {
+            let a = canvas.get_pixel(0, y).unwrap().a;
+            assert!(a == 0 || a == 255, "row {y} had intermediate alpha {a}");
+        }
+        assert_eq!(canvas.get_pixel(0, 5).unwrap().a, 255);
+    }
+
+    #[test]
+    fn test_antialias_on_produces_intermediate_alpha_at_edges() {
+        let mut canvas = Canvas::new(10, 10, transparent());
+        canvas.draw_horizontal_line(0.0, 10.0, 5.3, 1.0, opaque_black(), true);
+
+        // The line spans rows [4.8, 5.8): row 4 gets 20% coverage, row 5
+        // gets 80% coverage. Both are partial ("intermediate alpha").
+        let a4 = canvas.get_pixel(0, 4).unwrap().a;
+        let a5 = canvas.get_pixel(0, 5).unwrap().a;
+        assert!(a4 > 0 && a4 < 255, "expected intermediate alpha, got {a4}");
+        assert!(a5 > 0 && a5 < 255, "expected intermediate alpha, got {a5}");
+        assert!(a5 > a4);
+
+        // Rows outside the line's span remain untouched.
+        assert_eq!(canvas.get_pixel(0, 3).unwrap().a, 0);
+        assert_eq!(canvas.get_pixel(0, 6).unwrap().a, 0);
+    }
+
+    #[test]
+    fn test_antialias_smooths_a_thick_lines_edges_but_not_its_interior() {
+        // A 4px-thick line centered at y=5.3 spans rows [3.3, 7.3): rows 4-6
+        // are fully interior, rows 3 and 7 are partially covered edge rows.
+        let mut aa = Canvas::new(10, 10, transparent());
+        aa.draw_horizontal_line(0.0, 10.0, 5.3, 4.0, opaque_black(), true);
+
+        let mut hard = Canvas::new(10, 10, transparent());
+        hard.draw_horizontal_line(0.0, 10.0, 5.3, 4.0, opaque_black(), false);
+
+        for y in 4..=6 {
+            assert_eq!(aa.get_pixel(0, y).unwrap().a, 255, "interior row {y} should be fully opaque");
+        }
+
+        let aa_edge = aa.get_pixel(0, 3).unwrap().a;
+        assert!(aa_edge > 0 && aa_edge < 255, "expected partial coverage on the AA edge row, got {aa_edge}");
+
+        let hard_edge = hard.get_pixel(0, 3).unwrap().a;
+        assert!(hard_edge == 0 || hard_edge == 255, "hard-edge row should have no intermediate alpha, got {hard_edge}");
+    }
+
+    #[test]
+    fn test_fill_rect_interior_is_fully_opaque() {
+        let mut canvas = Canvas::new(10, 10, transparent());
+        canvas.fill_rect(2.0, 2.0, 8.0, 8.0, opaque_black(), true);
+
+        for y in 3..7 {
+            for x in 3..7 {
+                assert_eq!(canvas.get_pixel(x, y).unwrap().a, 255);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fill_rect_fractional_border_blends_with_antialias() {
+        let mut canvas = Canvas::new(10, 10, transparent());
+        canvas.fill_rect(2.5, 2.0, 8.0, 8.0, opaque_black(), true);
+
+        // Column 2 is only half-covered by the rect starting at x=2.5.
+        let a = canvas.get_pixel(2, 4).unwrap().a;
+        assert!(a > 0 && a < 255, "expected partial coverage, got {a}");
+    }
+
+    #[test]
+    fn test_draw_vertical_line_matches_fill_rect() {
+        let mut canvas = Canvas::new(10, 10, transparent());
+        canvas.draw_vertical_line(5.3, 0.0, 10.0, 1.0, opaque_black(), true);
+
+        let a4 = canvas.get_pixel(4, 0).unwrap().a;
+        let a5 = canvas.get_pixel(5, 0).unwrap().a;
+        assert!(a4 > 0 && a4 < 255);
+        assert!(a5 > 0 && a5 < 255);
+    }
+}
@@ -0,0 +1,139 @@
+//! Stacked multi-network comparison renders.
+//!
+//! A [`Session`] can carry extra `(network, layout)` pairs as
+//! [`ComparisonPanel`]s alongside its primary network, for before/after or
+//! condition-A/condition-B figures. [`render_comparison`] renders the
+//! primary network plus every panel with [`node_card`](crate::node_card)'s
+//! layout renderer and stacks them vertically, separated by a thin bar, so
+//! the panels read as one composite image.
+//!
+//! Every panel is rendered with the same `display` options passed to
+//! [`render_comparison`] (typically `session.display_options`), including
+//! their `color_assignment`, so a node present in more than one panel is
+//! drawn with the same color everywhere it appears.
+
+use crate::node_card::render_layout;
+use crate::Canvas;
+use biofabric_core::io::color::FabricColor;
+use biofabric_core::io::display_options::DisplayOptions;
+use biofabric_core::io::session::Session;
+
+/// Height, in pixels, of the separator bar drawn between panels.
+const SEPARATOR_HEIGHT: usize = 4;
+
+/// Render `session`'s primary network and every comparison panel, stacked
+/// vertically in order with a [`SEPARATOR_HEIGHT`]-pixel bar between each,
+/// onto one combined [`Canvas`].
+///
+/// All panels are rendered at their natural width (one grid cell per
+/// row/column, per [`node_card`](crate::node_card)'s cell size); the
+/// combined canvas is as wide as the widest panel, and panels narrower than
+/// that are left-aligned with the remainder left as background.
+pub fn render_comparison(session: &Session, display: &DisplayOptions) -> Canvas {
+    let mut panel_canvases: Vec<Canvas> = Vec::with_capacity(1 + session.comparison_panels.len());
+    panel_canvases.push(render_layout(
+        session
+            .layout
+            .as_ref()
+            .expect("session must have a layout for comparison rendering"),
+        display,
+    ));
+    for panel in &session.comparison_panels {
+        panel_canvases.push(render_layout(&panel.layout, display));
+    }
+
+    let width = panel_canvases.iter().map(Canvas::width).max().unwrap_or(1);
+    let separator_count = panel_canvases.len().saturating_sub(1);
+    let height = panel_canvases.iter().map(Canvas::height).sum::<usize>()
+        + separator_count * SEPARATOR_HEIGHT;
+
+    let mut combined = Canvas::new(width, height, FabricColor::rgb(255, 255, 255));
+
+    let mut y = 0i64;
+    for (i, panel) in panel_canvases.iter().enumerate() {
+        combined.blit(panel, 0, y);
+        y += panel.height() as i64;
+        if i + 1 < panel_canvases.len() {
+            combined.fill_rect(
+                0.0,
+                y as f64,
+                width as f64,
+                (y + SEPARATOR_HEIGHT as i64) as f64,
+                FabricColor::rgb(128, 128, 128),
+                false,
+            );
+            y += SEPARATOR_HEIGHT as i64;
+        }
+    }
+
+    combined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biofabric_core::io::color::ColorAssignment;
+    use biofabric_core::layout::{
+        DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout,
+    };
+    use biofabric_core::model::{Link, Network, NodeId};
+    use biofabric_core::worker::NoopMonitor;
+
+    fn layout_for(network: &Network) -> biofabric_core::layout::result::NetworkLayout {
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        layout_algo
+            .layout(network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_render_comparison_stacks_panels_with_consistent_colors_and_combined_height() {
+        let mut network_a = Network::new();
+        network_a.add_link(Link::new("hub", "leafA", "pp"));
+        network_a.add_link(Link::new("hub", "leafB", "pp"));
+        let layout_a = layout_for(&network_a);
+
+        let mut network_b = Network::new();
+        network_b.add_link(Link::new("hub", "otherX", "pp"));
+        let layout_b = layout_for(&network_b);
+
+        let session = Session::with_layout(network_a, layout_a.clone())
+            .with_comparison_panel(network_b.clone(), layout_b.clone());
+
+        assert_eq!(
+            session.display_options.color_assignment,
+            Some(ColorAssignment::from_names(["hub", "leafA", "leafB", "otherX"]))
+        );
+
+        let combined = render_comparison(&session, &session.display_options);
+
+        let expected_height =
+            render_layout(&layout_a, &session.display_options).height()
+                + SEPARATOR_HEIGHT
+                + render_layout(&layout_b, &session.display_options).height();
+        assert_eq!(combined.height(), expected_height);
+
+        let hub = NodeId::new("hub");
+        let row_a = layout_a.get_node(&hub).unwrap().row;
+        let row_b = layout_b.get_node(&hub).unwrap().row;
+
+        let panel_a_height = render_layout(&layout_a, &session.display_options).height();
+        let y_in_a = (row_a as f64 + 0.5) as usize * (panel_a_height / layout_a.row_count.max(1));
+        let y_in_b = panel_a_height
+            + SEPARATOR_HEIGHT
+            + (row_b as f64 + 0.5) as usize
+                * (render_layout(&layout_b, &session.display_options).height() / layout_b.row_count.max(1));
+
+        let white = FabricColor::rgb(255, 255, 255);
+        let hub_color_a = (0..combined.width())
+            .filter_map(|x| combined.get_pixel(x, y_in_a))
+            .find(|&c| c != white)
+            .expect("hub's row in panel A should have a drawn line");
+        let hub_color_b = (0..combined.width())
+            .filter_map(|x| combined.get_pixel(x, y_in_b))
+            .find(|&c| c != white)
+            .expect("hub's row in panel B should have a drawn line");
+
+        assert_eq!(hub_color_a, hub_color_b);
+    }
+}
@@ -0,0 +1,848 @@
+//! Full-layout raster rendering with progress reporting.
+//!
+//! [`render_layout_to_image`] draws every node row and link column in a
+//! [`NetworkLayout`] onto a [`Canvas`](crate::Canvas) at full size — the
+//! raster counterpart of [`render_layout_to_svg`](crate::render_layout_to_svg)
+//! and the whole-network analogue of [`node_card`](crate::node_card)'s
+//! per-node crops. [`render_layout_to_image_with_monitor`] is the same
+//! render, but reports progress and checks for cancellation via a
+//! [`LoopReporter`](biofabric_core::worker::LoopReporter) — worth it on
+//! something the size of the ~5,900-node AThaliana network, where a full
+//! render can run long enough that a caller wants to show progress or let
+//! the user cancel.
+
+use crate::node_card::{node_color, node_value_bounds, scaled_link_width, weight_bounds, CELL_SIZE};
+use crate::Canvas;
+use biofabric_core::io::color::{ColorPalette, FabricColor};
+use biofabric_core::io::display_options::{CropRegion, DisplayOptions, LayoutStyle};
+use biofabric_core::io::session::Session;
+use biofabric_core::layout::result::{LinkLayout, NetworkLayout};
+use biofabric_core::model::SelectionState;
+use biofabric_core::worker::{CancelledError, LoopReporter, NoopMonitor, ProgressMonitor};
+use std::collections::HashMap;
+
+/// Render `layout` to a raster [`Canvas`], with no progress reporting.
+///
+/// Delegates to [`render_layout_to_image_with_monitor`] with a
+/// [`NoopMonitor`], which never cancels — so this can't fail.
+pub fn render_layout_to_image(layout: &NetworkLayout, display: &DisplayOptions) -> Canvas {
+    render_layout_to_image_with_monitor(layout, display, &NoopMonitor)
+        .expect("NoopMonitor never requests cancellation")
+}
+
+/// Render `session`'s layout, dimming every node/link not in
+/// `session.selection` by [`DisplayOptions::selection_dim`] and
+/// highlighting the selected ones with a [`DisplayOptions::selection_color`]
+/// halo — the raster counterpart of the interactive frontend's "highlighted
+/// subset" view. Delegates straight to [`render_layout_to_image`] when the
+/// selection is empty, or `session` has no layout yet (an empty canvas).
+///
+/// ## References
+///
+/// (none — not in the Java original)
+pub fn render_session_to_image(session: &Session, display: &DisplayOptions) -> Canvas {
+    let Some(layout) = &session.layout else {
+        return Canvas::new(1, 1, FabricColor::rgb(255, 255, 255));
+    };
+    if session.selection.is_empty() {
+        return render_layout_to_image(layout, display);
+    }
+    render_layout_with_monitor(layout, display, Some(&session.selection), &NoopMonitor)
+        .expect("NoopMonitor never requests cancellation")
+}
+
+/// Render `layout` to a raster [`Canvas`], reporting progress across the
+/// link pass (0%–50%) and the node pass (50%–100%) and checking for
+/// cancellation between rows.
+///
+/// # Errors
+///
+/// Returns [`CancelledError`] if `monitor` requests cancellation before
+/// the render completes.
+pub fn render_layout_to_image_with_monitor(
+    layout: &NetworkLayout,
+    display: &DisplayOptions,
+    monitor: &dyn ProgressMonitor,
+) -> Result<Canvas, CancelledError> {
+    render_layout_with_monitor(layout, display, None, monitor)
+}
+
+/// Shared implementation behind [`render_layout_to_image_with_monitor`] and
+/// [`render_session_to_image`] — `selection`, when given a non-empty
+/// [`SelectionState`], dims non-selected geometry and haloes selected
+/// geometry instead of drawing every node/link at full, uniform strength.
+fn render_layout_with_monitor(
+    layout: &NetworkLayout,
+    display: &DisplayOptions,
+    selection: Option<&SelectionState>,
+    monitor: &dyn ProgressMonitor,
+) -> Result<Canvas, CancelledError> {
+    if display.layout_style == LayoutStyle::Radial {
+        return render_radial_with_monitor(layout, display, selection, monitor);
+    }
+
+    let crop = display.crop.unwrap_or(CropRegion {
+        min_row: 0,
+        max_row: layout.row_count.saturating_sub(1),
+        min_col: 0,
+        max_col: layout.column_count.saturating_sub(1),
+    });
+
+    let palette = ColorPalette::default_palette();
+    let value_bounds = display.node_value_attribute.as_deref().and_then(|attr| node_value_bounds(layout, attr));
+    let width = ((crop.max_col + 1 - crop.min_col).max(1) as f64 * CELL_SIZE).ceil() as usize;
+    let height = ((crop.max_row + 1 - crop.min_row).max(1) as f64 * CELL_SIZE).ceil() as usize;
+    let mut canvas = Canvas::new(width, height, FabricColor::rgb(255, 255, 255));
+    let row_offset = crop.min_row as f64 * CELL_SIZE;
+    let col_offset = crop.min_col as f64 * CELL_SIZE;
+
+    let link_count = layout.iter_links().count() as u64;
+    let node_count = layout.iter_nodes().count() as u64;
+    monitor.set_total(link_count + node_count);
+
+    let computed_drain_zones = display.show_drain_zones.then(|| layout.compute_drain_zones());
+
+    if display.show_grid {
+        draw_grid(&mut canvas, display, &crop, row_offset, col_offset, width, height);
+    }
+
+    let active_selection = selection.filter(|s| !s.is_empty());
+    let highlight_color = active_selection.map(|_| selection_highlight_color(display));
+
+    let bounds = weight_bounds(layout);
+    let mut link_progress = LoopReporter::new(link_count, 20, monitor, 0.0, 0.5, "rendering links");
+    for (link_index, ll) in layout.iter_links().enumerate() {
+        if !(ll.is_shadow && !display.show_shadows) {
+            let column = if display.show_shadows {
+                ll.column
+            } else {
+                ll.column_no_shadows.unwrap_or(ll.column)
+            };
+            let in_crop = column >= crop.min_col
+                && column <= crop.max_col
+                && ll.top_row() <= crop.max_row
+                && ll.bottom_row() >= crop.min_row;
+            if in_crop {
+                let x = (column as f64 + 0.5) * CELL_SIZE - col_offset;
+                let y0 = ll.top_row().max(crop.min_row) as f64 * CELL_SIZE - row_offset;
+                let y1 = (ll.bottom_row().min(crop.max_row) as f64 + 1.0) * CELL_SIZE - row_offset;
+                let mut color = node_color(display, layout, &ll.source, ll.color_index, &palette, value_bounds);
+                if ll.is_shadow {
+                    color.a = display.shadow_alpha;
+                }
+                let width = scaled_link_width(display, ll.weight, bounds);
+                if let Some(selection) = active_selection {
+                    if selection.is_link_selected(link_index) {
+                        let halo_width = width + display.selection_line_width;
+                        canvas.draw_vertical_line(x, y0, y1, halo_width, highlight_color.unwrap(), display.antialias);
+                    } else {
+                        color.a = (color.a as f32 * display.selection_dim).round() as u8;
+                    }
+                }
+                canvas.draw_vertical_line(x, y0, y1, width, color, display.antialias);
+
+                if display.show_arrows && ll.directed == Some(true) {
+                    draw_arrowhead(&mut canvas, x, ll, row_offset, &crop, color);
+                }
+            }
+        }
+        link_progress.tick()?;
+    }
+    link_progress.finish();
+
+    let mut node_progress = LoopReporter::new(node_count, 20, monitor, 0.5, 1.0, "rendering nodes");
+    for (node_id, nl) in layout.iter_nodes() {
+        let (min_col, max_col, has_edges) = if display.show_shadows {
+            (nl.min_col, nl.max_col, nl.has_edges())
+        } else {
+            (nl.min_col_no_shadows, nl.max_col_no_shadows, nl.has_edges_no_shadows())
+        };
+        let in_crop =
+            has_edges && nl.row >= crop.min_row && nl.row <= crop.max_row && min_col <= crop.max_col && max_col >= crop.min_col;
+        if in_crop {
+            let y = (nl.row as f64 + 0.5) * CELL_SIZE - row_offset;
+            let x0 = min_col.max(crop.min_col) as f64 * CELL_SIZE - col_offset;
+            let x1 = (max_col.min(crop.max_col) as f64 + 1.0) * CELL_SIZE - col_offset;
+            let mut color = node_color(display, layout, node_id, nl.color_index, &palette, value_bounds);
+            if let Some(selection) = active_selection {
+                if selection.is_node_selected(node_id) {
+                    let halo_width = display.node_line_width + display.selection_line_width;
+                    canvas.draw_horizontal_line(x0, x1, y, halo_width, highlight_color.unwrap(), display.antialias);
+                } else {
+                    color.a = (color.a as f32 * display.selection_dim).round() as u8;
+                }
+            }
+
+            if display.node_zone_coloring {
+                let (zone_x0, zone_x1) = if display.full_width_zones {
+                    (0.0, width as f64)
+                } else {
+                    (x0, x1)
+                };
+                let mut zone_color = color;
+                zone_color.a = display.node_zone_opacity;
+                canvas.fill_rect(zone_x0, y - CELL_SIZE / 2.0, zone_x1, y + CELL_SIZE / 2.0, zone_color, display.antialias);
+            }
+
+            canvas.draw_horizontal_line(x0, x1, y, display.node_line_width, color, display.antialias);
+
+            if display.show_drain_zones {
+                let zones = if display.show_shadows {
+                    nl.shadow_drain_zones
+                        .as_ref()
+                        .or_else(|| computed_drain_zones.as_ref().and_then(|(_, shadow)| shadow.get(node_id)))
+                } else {
+                    nl.plain_drain_zones
+                        .as_ref()
+                        .or_else(|| computed_drain_zones.as_ref().and_then(|(plain, _)| plain.get(node_id)))
+                };
+                if let Some(zones) = zones {
+                    for &(zone_min_col, zone_max_col) in zones {
+                        if zone_max_col < crop.min_col || zone_min_col > crop.max_col {
+                            continue;
+                        }
+                        let zx0 = zone_min_col.max(crop.min_col) as f64 * CELL_SIZE - col_offset;
+                        let zx1 = (zone_max_col.min(crop.max_col) as f64 + 1.0) * CELL_SIZE - col_offset;
+                        canvas.fill_rect(zx0, y - CELL_SIZE / 2.0, zx1, y + CELL_SIZE / 2.0, DRAIN_ZONE_TINT, display.antialias);
+                    }
+                }
+            }
+        }
+        node_progress.tick()?;
+    }
+    node_progress.finish();
+
+    if display.draw_legend {
+        canvas = append_legend(canvas, layout, display);
+    }
+
+    Ok(canvas)
+}
+
+/// Relation short codes present in `layout` that have a known alignment
+/// legend color, paired with that color, in [`EdgeType::all`] order.
+///
+/// Empty for a layout with no alignment relations (e.g. an ordinary,
+/// non-alignment network), which is what makes [`append_legend`] a no-op
+/// on those layouts.
+///
+/// [`EdgeType::all`]: biofabric_core::alignment::EdgeType::all
+fn legend_entries(layout: &NetworkLayout) -> Vec<(String, FabricColor)> {
+    use biofabric_core::alignment::{alignment_relation_color, EdgeType};
+
+    let present: std::collections::HashSet<&str> = layout.iter_links().map(|ll| ll.relation.as_str()).collect();
+    EdgeType::all()
+        .iter()
+        .map(|ty| ty.short_code())
+        .filter(|code| present.contains(code))
+        .filter_map(|code| alignment_relation_color(code).map(|color| (code.to_string(), color)))
+        .collect()
+}
+
+/// Pixel size of one legend swatch (a solid square, no text label — this
+/// crate has no font rasterizer, see [`crate::node_card`]'s module doc for
+/// the same "deliberately bare" tradeoff).
+const LEGEND_SWATCH_SIZE: f64 = CELL_SIZE * 2.0;
+
+/// Reserve a strip alongside `canvas` and draw one color swatch per
+/// [`legend_entries`] relation present in `layout`. A no-op (returns
+/// `canvas` unchanged) when no alignment relations are present.
+fn append_legend(canvas: Canvas, layout: &NetworkLayout, display: &DisplayOptions) -> Canvas {
+    let entries = legend_entries(layout);
+    if entries.is_empty() {
+        return canvas;
+    }
+
+    let swatch = LEGEND_SWATCH_SIZE;
+    let strip_thickness = swatch.ceil() as usize + 2;
+
+    let (width, height) = match display.legend_side {
+        biofabric_core::io::display_options::LegendSide::Right => (canvas.width() + strip_thickness, canvas.height().max((entries.len() as f64 * swatch).ceil() as usize)),
+        biofabric_core::io::display_options::LegendSide::Bottom => (canvas.width().max((entries.len() as f64 * swatch).ceil() as usize), canvas.height() + strip_thickness),
+    };
+
+    let mut composed = Canvas::new(width, height, FabricColor::rgb(255, 255, 255));
+    composed.blit(&canvas, 0, 0);
+
+    for (i, (_relation, color)) in entries.iter().enumerate() {
+        let (x0, y0) = match display.legend_side {
+            biofabric_core::io::display_options::LegendSide::Right => (canvas.width() as f64 + 1.0, i as f64 * swatch),
+            biofabric_core::io::display_options::LegendSide::Bottom => (i as f64 * swatch, canvas.height() as f64 + 1.0),
+        };
+        composed.fill_rect(x0, y0, x0 + swatch, y0 + swatch, *color, false);
+    }
+
+    composed
+}
+
+/// Contrasting overlay color for [`DisplayOptions::show_drain_zones`] —
+/// semi-transparent black, dark enough to stand out against any palette
+/// color underneath without fully hiding it.
+const DRAIN_ZONE_TINT: FabricColor = FabricColor::rgba(0, 0, 0, 90);
+
+/// Faint overlay color for [`DisplayOptions::show_grid`] — light enough
+/// that it reads as a background aid rather than competing with node/link
+/// geometry drawn on top of it.
+const GRID_LINE_TINT: FabricColor = FabricColor::rgba(0, 0, 0, 24);
+
+/// Draw faint gridlines at every [`DisplayOptions::grid_spacing`]-th row and
+/// column boundary within `crop`, spanning the full `canvas`. Called before
+/// any node/link geometry is drawn, so that geometry ends up on top.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+fn draw_grid(canvas: &mut Canvas, display: &DisplayOptions, crop: &CropRegion, row_offset: f64, col_offset: f64, width: usize, height: usize) {
+    let spacing = display.grid_spacing.max(1);
+
+    let first_col = crop.min_col.div_ceil(spacing) * spacing;
+    let mut col = first_col;
+    while col <= crop.max_col {
+        let x = col as f64 * CELL_SIZE - col_offset;
+        canvas.draw_vertical_line(x, 0.0, height as f64, 1.0, GRID_LINE_TINT, display.antialias);
+        col += spacing;
+    }
+
+    let first_row = crop.min_row.div_ceil(spacing) * spacing;
+    let mut row = first_row;
+    while row <= crop.max_row {
+        let y = row as f64 * CELL_SIZE - row_offset;
+        canvas.draw_horizontal_line(0.0, width as f64, y, 1.0, GRID_LINE_TINT, display.antialias);
+        row += spacing;
+    }
+}
+
+/// Draw a small chevron-shaped arrowhead pointing at `ll`'s target row, for
+/// [`DisplayOptions::show_arrows`]. Drawn as two short diagonal segments
+/// (via [`Canvas::draw_line`]) rather than a filled polygon, the same
+/// minimal-primitive approach the chord-diagram overlay already uses,
+/// since this renderer has no general polygon rasterizer.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+fn draw_arrowhead(canvas: &mut Canvas, x: f64, ll: &LinkLayout, row_offset: f64, crop: &CropRegion, color: FabricColor) {
+    if ll.target_row < crop.min_row || ll.target_row > crop.max_row {
+        return;
+    }
+
+    const ARROW_LEN: f64 = 6.0;
+    const ARROW_HALF_WIDTH: f64 = 4.0;
+
+    let tip_y = (ll.target_row as f64 + 0.5) * CELL_SIZE - row_offset;
+    let dir = if ll.target_row as f64 >= ll.source_row as f64 { 1.0 } else { -1.0 };
+    let base_y = tip_y - dir * ARROW_LEN;
+
+    canvas.draw_line(x - ARROW_HALF_WIDTH, base_y, x, tip_y, color);
+    canvas.draw_line(x + ARROW_HALF_WIDTH, base_y, x, tip_y, color);
+}
+
+/// Identifies one tile's position in the grid produced by
+/// [`render_layout_to_tiles`].
+///
+/// ## References
+///
+/// (none — not in the Java original)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileCoord {
+    /// Row of this tile in the tile grid (not a layout row).
+    pub tile_row: usize,
+    /// Column of this tile in the tile grid (not a layout column).
+    pub tile_col: usize,
+}
+
+/// Render `layout` as a grid of independently-allocated tiles instead of
+/// one `width * height` buffer, so exports of huge layouts (e.g. the
+/// ~5,900-node AThaliana network at full resolution) don't need to hold
+/// the whole image in memory at once.
+///
+/// Each tile covers up to `tile_size` rows and `tile_size` columns *in
+/// layout coordinates* (not pixels), via [`DisplayOptions::crop`] — tiles
+/// along the right/bottom edge are smaller than `tile_size` when the
+/// layout's dimensions don't divide evenly. Any `crop` already set on
+/// `display` is ignored; each tile gets its own.
+///
+/// [`stitch_tiles`] recombines the tiles into the same [`Canvas`] that
+/// [`render_layout_to_image`] would have produced in one pass.
+pub fn render_layout_to_tiles<'a>(
+    layout: &'a NetworkLayout,
+    display: &'a DisplayOptions,
+    tile_size: usize,
+) -> impl Iterator<Item = (TileCoord, Canvas)> + 'a {
+    let tile_size = tile_size.max(1);
+    let row_count = layout.row_count.max(1);
+    let column_count = layout.column_count.max(1);
+    let tile_rows = row_count.div_ceil(tile_size);
+    let tile_cols = column_count.div_ceil(tile_size);
+
+    (0..tile_rows).flat_map(move |tile_row| {
+        (0..tile_cols).map(move |tile_col| {
+            let min_row = tile_row * tile_size;
+            let max_row = ((tile_row + 1) * tile_size - 1).min(row_count - 1);
+            let min_col = tile_col * tile_size;
+            let max_col = ((tile_col + 1) * tile_size - 1).min(column_count - 1);
+            let tile_display = DisplayOptions {
+                crop: Some(CropRegion { min_row, max_row, min_col, max_col }),
+                ..display.clone()
+            };
+            let canvas = render_layout_to_image(layout, &tile_display);
+            (TileCoord { tile_row, tile_col }, canvas)
+        })
+    })
+}
+
+/// Recombine tiles produced by [`render_layout_to_tiles`] (called with the
+/// same `tile_size`) into one [`Canvas`], for callers that want to verify
+/// or further process the stitched result in memory rather than writing
+/// each tile straight to its own region of an output file.
+///
+/// `tile_size` is clamped to at least 1, the same way
+/// [`render_layout_to_tiles`] clamps it, so passing the same raw
+/// `tile_size` to both (including `0`) places tiles consistently instead
+/// of stacking them all at the origin.
+pub fn stitch_tiles(tiles: impl IntoIterator<Item = (TileCoord, Canvas)>, tile_size: usize) -> Canvas {
+    let tile_size = tile_size.max(1);
+    let tiles: Vec<_> = tiles.into_iter().collect();
+    let max_right = tiles.iter().map(|(coord, canvas)| coord.tile_col * tile_size * CELL_SIZE as usize + canvas.width()).max().unwrap_or(0);
+    let max_bottom = tiles
+        .iter()
+        .map(|(coord, canvas)| coord.tile_row * tile_size * CELL_SIZE as usize + canvas.height())
+        .max()
+        .unwrap_or(0);
+
+    let mut stitched = Canvas::new(max_right.max(1), max_bottom.max(1), FabricColor::rgb(255, 255, 255));
+    for (coord, canvas) in &tiles {
+        let x = (coord.tile_col * tile_size) as i64 * CELL_SIZE as i64;
+        let y = (coord.tile_row * tile_size) as i64 * CELL_SIZE as i64;
+        stitched.blit(canvas, x, y);
+    }
+    stitched
+}
+
+/// Radial rendering: nodes placed around a circle in row order (via
+/// [`NetworkLayout::radial_coordinates`]), links drawn as straight chords.
+///
+/// Canvas size is fixed at one [`CELL_SIZE`] cell per row on the circle's
+/// diameter, which keeps adjacent nodes from overlapping on small networks
+/// while not growing unreasonably large on bigger ones.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+fn render_radial_with_monitor(
+    layout: &NetworkLayout,
+    display: &DisplayOptions,
+    selection: Option<&SelectionState>,
+    monitor: &dyn ProgressMonitor,
+) -> Result<Canvas, CancelledError> {
+    let palette = ColorPalette::default_palette();
+    let value_bounds = display.node_value_attribute.as_deref().and_then(|attr| node_value_bounds(layout, attr));
+    let diameter = ((layout.row_count.max(1)) as f64 * CELL_SIZE).max(CELL_SIZE);
+    let size = diameter.ceil() as usize;
+    let mut canvas = Canvas::new(size, size, FabricColor::rgb(255, 255, 255));
+
+    let center = diameter / 2.0;
+    let radius = center - CELL_SIZE;
+    let points: HashMap<biofabric_core::NodeId, (f64, f64)> = layout
+        .radial_coordinates()
+        .into_iter()
+        .map(|(id, x, y)| (id, (center + x * radius, center + y * radius)))
+        .collect();
+
+    let active_selection = selection.filter(|s| !s.is_empty());
+    let highlight_color = active_selection.map(|_| selection_highlight_color(display));
+
+    let link_count = layout.iter_links().count() as u64;
+    monitor.set_total(link_count);
+
+    let mut link_progress = LoopReporter::new(link_count, 20, monitor, 0.0, 1.0, "rendering radial chords");
+    for (link_index, ll) in layout.iter_links().enumerate() {
+        if !(ll.is_shadow && !display.show_shadows) {
+            if let (Some(&(x0, y0)), Some(&(x1, y1))) = (points.get(&ll.source), points.get(&ll.target)) {
+                let mut color = node_color(display, layout, &ll.source, ll.color_index, &palette, value_bounds);
+                if ll.is_shadow {
+                    color.a = display.shadow_alpha;
+                }
+                if let Some(selection) = active_selection {
+                    if selection.is_link_selected(link_index) {
+                        canvas.draw_line(x0, y0, x1, y1, highlight_color.unwrap());
+                    } else {
+                        color.a = (color.a as f32 * display.selection_dim).round() as u8;
+                    }
+                }
+                canvas.draw_line(x0, y0, x1, y1, color);
+            }
+        }
+        link_progress.tick()?;
+    }
+    link_progress.finish();
+
+    Ok(canvas)
+}
+
+/// Resolve [`DisplayOptions::selection_color`] to a [`FabricColor`],
+/// falling back to opaque yellow if it isn't valid hex.
+fn selection_highlight_color(display: &DisplayOptions) -> FabricColor {
+    FabricColor::from_hex(&display.selection_color).unwrap_or(FabricColor::rgb(255, 255, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biofabric_core::layout::{
+        DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout,
+    };
+    use biofabric_core::io::session::Session;
+    use biofabric_core::model::{Link, Network, SelectionState};
+    use biofabric_core::worker::NoopMonitor;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+    fn star_network() -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "pp"));
+        network.add_link(Link::new("hub", "leafB", "pp"));
+        network.add_link(Link::new("hub", "leafC", "pp"));
+        network
+    }
+
+    fn star_layout() -> NetworkLayout {
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        layout_algo
+            .layout(&star_network(), &LayoutParams::default(), &NoopMonitor)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_render_layout_to_image_matches_monitored_render_with_noop_monitor() {
+        let layout = star_layout();
+        let display = DisplayOptions::default();
+
+        let plain = render_layout_to_image(&layout, &display);
+        let monitored = render_layout_to_image_with_monitor(&layout, &display, &NoopMonitor).unwrap();
+
+        assert_eq!(plain.width(), monitored.width());
+        assert_eq!(plain.height(), monitored.height());
+        for y in 0..plain.height() {
+            for x in 0..plain.width() {
+                assert_eq!(plain.get_pixel(x, y), monitored.get_pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_selecting_one_node_only_changes_that_nodes_row() {
+        let network = star_network();
+        let layout = star_layout();
+        let leaf_a = biofabric_core::NodeId::new("leafA");
+        let selected_row = layout.get_node(&leaf_a).unwrap().row;
+
+        // Pin `selection_dim` at 1.0 (no fade) so the only difference from
+        // the baseline render is the halo drawn around the selected node's
+        // row — the dim multiplier itself is a separate, already-tested
+        // concern (see `default_selection_dim`).
+        let mut display = DisplayOptions::default();
+        display.selection_dim = 1.0;
+
+        let baseline = render_layout_to_image(&layout, &display);
+
+        let mut selection = SelectionState::new();
+        selection.select_node(leaf_a);
+        let session = Session::with_layout(network, layout).with_selection(selection);
+        let highlighted = render_session_to_image(&session, &display);
+
+        assert_eq!(baseline.width(), highlighted.width());
+        assert_eq!(baseline.height(), highlighted.height());
+
+        let row_band_start = (selected_row as f64 * CELL_SIZE) as usize;
+        let row_band_end = ((selected_row as f64 + 1.0) * CELL_SIZE).ceil() as usize;
+        let mut other_rows_differ = false;
+        let mut selected_row_differs = false;
+        for y in 0..baseline.height() {
+            for x in 0..baseline.width() {
+                if baseline.get_pixel(x, y) != highlighted.get_pixel(x, y) {
+                    if y >= row_band_start && y < row_band_end {
+                        selected_row_differs = true;
+                    } else {
+                        other_rows_differ = true;
+                    }
+                }
+            }
+        }
+
+        assert!(selected_row_differs, "selecting a node should change its own row's pixels");
+        assert!(!other_rows_differ, "selecting a node should not change any other row's pixels");
+    }
+
+    #[test]
+    fn test_show_arrows_draws_arrowhead_pixels_only_for_directed_links() {
+        let mut directed_link = Link::new("hub", "leafA", "pp");
+        directed_link.directed = Some(true);
+        let mut network = Network::new();
+        network.add_link(directed_link);
+        network.add_link(Link::new("hub", "leafB", "pp"));
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo.layout(&network, &LayoutParams::default(), &NoopMonitor).unwrap();
+
+        let directed_col = layout.iter_links().find(|ll| ll.directed == Some(true)).unwrap().column;
+        let plain_col = layout.iter_links().find(|ll| ll.directed != Some(true)).unwrap().column;
+
+        let baseline = render_layout_to_image(&layout, &DisplayOptions::default());
+        let mut with_arrows = DisplayOptions::default();
+        with_arrows.show_arrows = true;
+        let arrowed = render_layout_to_image(&layout, &with_arrows);
+
+        assert_eq!(baseline.width(), arrowed.width());
+        assert_eq!(baseline.height(), arrowed.height());
+
+        let col_of = |x: usize| -> usize { (x as f64 / CELL_SIZE) as usize };
+        let mut directed_col_changed = false;
+        let mut plain_col_changed = false;
+        for y in 0..baseline.height() {
+            for x in 0..baseline.width() {
+                if baseline.get_pixel(x, y) != arrowed.get_pixel(x, y) {
+                    match col_of(x) {
+                        c if c == directed_col => directed_col_changed = true,
+                        c if c == plain_col => plain_col_changed = true,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        assert!(directed_col_changed, "enabling arrows should add pixels to the directed link's column");
+        assert!(!plain_col_changed, "enabling arrows should not touch the undirected link's column");
+    }
+
+    /// A monitor that tracks the highest `done` value it's seen and can be
+    /// told to cancel on its next `update`/`update_with_phase` call.
+    #[derive(Default)]
+    struct RecordingMonitor {
+        max_done: AtomicU64,
+        cancel: AtomicBool,
+    }
+
+    impl ProgressMonitor for RecordingMonitor {
+        fn set_total(&self, _total: u64) {}
+
+        fn update(&self, done: u64) -> bool {
+            self.update_with_phase(done, "")
+        }
+
+        fn update_with_phase(&self, done: u64, _phase: &str) -> bool {
+            self.max_done.fetch_max(done, Ordering::SeqCst);
+            !self.cancel.load(Ordering::SeqCst)
+        }
+
+        fn keep_going(&self) -> bool {
+            !self.cancel.load(Ordering::SeqCst)
+        }
+    }
+
+    #[test]
+    fn test_cancelling_monitor_aborts_the_render() {
+        let layout = star_layout();
+        let monitor = RecordingMonitor::default();
+        monitor.cancel.store(true, Ordering::SeqCst);
+
+        let result = render_layout_to_image_with_monitor(&layout, &DisplayOptions::default(), &monitor);
+        assert!(matches!(result, Err(CancelledError)));
+    }
+
+    #[test]
+    fn test_monitor_is_driven_to_completion_when_not_cancelled() {
+        let layout = star_layout();
+        let monitor = RecordingMonitor::default();
+
+        render_layout_to_image_with_monitor(&layout, &DisplayOptions::default(), &monitor).unwrap();
+
+        // Progress is reported in percent: the link pass covers 0-50 and the
+        // node pass 50-100, so a completed render ends at 100.
+        assert_eq!(monitor.max_done.load(Ordering::SeqCst), 100);
+    }
+
+    #[test]
+    fn test_crop_excludes_node_outside_its_window() {
+        // "hub" is row 0; cropping to rows 1..=row_count-1 drops hub's row
+        // and keeps the three leaf rows.
+        let layout = star_layout();
+        let full = render_layout_to_image(&layout, &DisplayOptions::default());
+        assert!(full.height() > 0);
+
+        let cropped_display = DisplayOptions {
+            crop: Some(biofabric_core::io::display_options::CropRegion {
+                min_row: 1,
+                max_row: layout.row_count - 1,
+                min_col: 0,
+                max_col: layout.column_count - 1,
+            }),
+            ..Default::default()
+        };
+        let cropped = render_layout_to_image(&layout, &cropped_display);
+
+        // The crop excludes one row, so the cropped canvas should be one
+        // cell shorter than the full render.
+        assert!(cropped.height() < full.height());
+
+        // The leaf rows and the link segments crossing them are still drawn,
+        // so the cropped render is not all white background.
+        let white = FabricColor::rgb(255, 255, 255);
+        let mut any_non_background = false;
+        for y in 0..cropped.height() {
+            for x in 0..cropped.width() {
+                if cropped.get_pixel(x, y).unwrap() != white {
+                    any_non_background = true;
+                }
+            }
+        }
+        assert!(any_non_background, "expected the cropped render to still draw the remaining rows");
+    }
+
+    #[test]
+    fn test_show_drain_zones_highlights_differ_from_base_render() {
+        // "hub" drains all three leaves into one contiguous column span on
+        // its own row, so its row is a known, predictable drain zone.
+        let layout = star_layout();
+        let base = DisplayOptions::default();
+        let with_zones = DisplayOptions { show_drain_zones: true, ..Default::default() };
+
+        let plain = render_layout_to_image(&layout, &base);
+        let highlighted = render_layout_to_image(&layout, &with_zones);
+
+        assert_eq!(plain.width(), highlighted.width());
+        assert_eq!(plain.height(), highlighted.height());
+
+        let mut any_pixel_differs = false;
+        for y in 0..plain.height() {
+            for x in 0..plain.width() {
+                if plain.get_pixel(x, y) != highlighted.get_pixel(x, y) {
+                    any_pixel_differs = true;
+                }
+            }
+        }
+        assert!(any_pixel_differs, "expected the drain-zone overlay to change at least one pixel");
+    }
+
+    #[test]
+    fn test_stitched_tiles_match_a_single_buffer_render() {
+        let layout = star_layout();
+        let display = DisplayOptions::default();
+
+        let whole = render_layout_to_image(&layout, &display);
+        let tiles = render_layout_to_tiles(&layout, &display, 2);
+        let stitched = stitch_tiles(tiles, 2);
+
+        assert_eq!(whole.width(), stitched.width());
+        assert_eq!(whole.height(), stitched.height());
+        for y in 0..whole.height() {
+            for x in 0..whole.width() {
+                assert_eq!(whole.get_pixel(x, y), stitched.get_pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_stitch_tiles_clamps_a_zero_tile_size_like_render_layout_to_tiles() {
+        let layout = star_layout();
+        let display = DisplayOptions::default();
+
+        let whole = render_layout_to_image(&layout, &display);
+        let tiles = render_layout_to_tiles(&layout, &display, 0);
+        let stitched = stitch_tiles(tiles, 0);
+
+        assert_eq!(whole.width(), stitched.width());
+        assert_eq!(whole.height(), stitched.height());
+        for y in 0..whole.height() {
+            for x in 0..whole.width() {
+                assert_eq!(whole.get_pixel(x, y), stitched.get_pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_radial_layout_style_renders_a_square_canvas_without_cancelling() {
+        let layout = star_layout();
+        let display = DisplayOptions {
+            layout_style: biofabric_core::io::display_options::LayoutStyle::Radial,
+            ..Default::default()
+        };
+
+        let canvas = render_layout_to_image(&layout, &display);
+        assert_eq!(canvas.width(), canvas.height());
+        assert!(canvas.width() > 0);
+    }
+
+    #[test]
+    fn test_show_grid_changes_margin_pixels_but_not_node_geometry_pixels() {
+        let layout = star_layout();
+        let plain = DisplayOptions::default();
+        let with_grid = DisplayOptions { show_grid: true, grid_spacing: 1, ..Default::default() };
+
+        let baseline = render_layout_to_image(&layout, &plain);
+        let gridded = render_layout_to_image(&layout, &with_grid);
+
+        assert_eq!(baseline.width(), gridded.width());
+        assert_eq!(baseline.height(), gridded.height());
+
+        // "hub" is row 0; its node line runs straight across the row's
+        // center, so a pixel there is geometry, not background margin, and
+        // should be drawn identically whether or not the grid is enabled —
+        // the grid renders beneath it, and the node's own line fully covers
+        // that pixel in both renders.
+        let hub_row = layout.get_node(&biofabric_core::NodeId::new("hub")).unwrap().row;
+        let hub_y = ((hub_row as f64 + 0.5) * CELL_SIZE) as usize;
+        let mut geometry_differs = false;
+        let mut margin_differs = false;
+        for y in 0..baseline.height() {
+            for x in 0..baseline.width() {
+                if baseline.get_pixel(x, y) != gridded.get_pixel(x, y) {
+                    if y == hub_y {
+                        geometry_differs = true;
+                    } else {
+                        margin_differs = true;
+                    }
+                }
+            }
+        }
+
+        assert!(!geometry_differs, "gridlines should not change pixels already covered by node geometry");
+        assert!(margin_differs, "enabling the grid should change at least one background pixel");
+    }
+
+    fn alignment_layout() -> NetworkLayout {
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "P"));
+        network.add_link(Link::new("hub", "leafB", "pBp"));
+        network.add_link(Link::new("hub", "leafC", "pp"));
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        layout_algo.layout(&network, &LayoutParams::default(), &NoopMonitor).unwrap()
+    }
+
+    #[test]
+    fn test_legend_widens_canvas_for_alignment_layout_but_not_plain_layout() {
+        let no_legend = DisplayOptions::default();
+        let with_legend = DisplayOptions {
+            draw_legend: true,
+            ..Default::default()
+        };
+
+        let aligned = alignment_layout();
+        let aligned_plain = render_layout_to_image(&aligned, &no_legend);
+        let aligned_legend = render_layout_to_image(&aligned, &with_legend);
+        assert!(
+            aligned_legend.width() > aligned_plain.width(),
+            "expected a wider canvas once a legend strip is added for an alignment layout"
+        );
+        assert_eq!(aligned_legend.height().max(aligned_plain.height()), aligned_legend.height());
+
+        let plain = star_layout();
+        let plain_no_legend = render_layout_to_image(&plain, &no_legend);
+        let plain_with_legend = render_layout_to_image(&plain, &with_legend);
+        assert_eq!(
+            plain_no_legend.width(),
+            plain_with_legend.width(),
+            "a non-alignment layout has no known relation colors, so no legend strip should be added"
+        );
+        assert_eq!(plain_no_legend.height(), plain_with_legend.height());
+    }
+}
@@ -0,0 +1,33 @@
+//! Raster canvas and drawing primitives for BioFabric rendering.
+//!
+//! This crate provides the pixel-level building blocks (`Canvas`,
+//! axis-aligned line/rect drawing) and the renderers built on them: full
+//! layout images, per-node cards, minimaps, stacked comparisons and SVG
+//! text export. It has no dependency on any raster image file format —
+//! PNG and other encoders live downstream of this crate.
+//!
+//! ## References
+//!
+//! - Java: `org.systemsbiology.biofabric.ui.render.BioFabricPanel` (paints
+//!   directly via `Graphics2D`; this crate is the Rust equivalent of the
+//!   low-level drawing calls it makes)
+
+mod canvas;
+mod comparison;
+mod layout_image;
+mod minimap;
+mod node_card;
+mod quantize;
+mod svg;
+
+pub use biofabric_core::io::color::FabricColor;
+pub use canvas::Canvas;
+pub use comparison::render_comparison;
+pub use layout_image::{
+    render_layout_to_image, render_layout_to_image_with_monitor, render_layout_to_tiles, render_session_to_image,
+    stitch_tiles, TileCoord,
+};
+pub use minimap::{render_density_overview, render_minimap};
+pub use node_card::{render_node_card, RenderError};
+pub use quantize::{quantize, IndexedImage, QuantizeError};
+pub use svg::render_layout_to_svg;
@@ -0,0 +1,271 @@
+//! Overview/minimap strip rendering.
+//!
+//! A minimap is the whole layout scaled down into a small fixed-size box,
+//! for navigation UIs that show "where am I" alongside a full-resolution
+//! viewport. Unlike [`crate::render_node_card`], it covers every row and
+//! column rather than a cropped neighborhood, so features are drawn as thin
+//! one-pixel-wide lines regardless of [`DisplayOptions`]' configured line
+//! widths — at minimap scale a node/link is lucky to get a single pixel, and
+//! honoring the full-size line width would just smear everything together.
+
+use crate::node_card::{node_color, node_value_bounds};
+use crate::Canvas;
+use biofabric_core::io::color::{ColorPalette, FabricColor};
+use biofabric_core::io::display_options::DisplayOptions;
+use biofabric_core::layout::result::NetworkLayout;
+
+/// Render `layout` scaled to fit a `width`×`height` box, for use as a
+/// navigation overview/minimap.
+///
+/// Every row and column is scaled down uniformly to fit the requested
+/// dimensions; line widths are fixed at one pixel rather than following
+/// `display`'s `node_line_width`/`link_line_width`, and antialiasing is
+/// always off, since both only matter at resolutions finer than a minimap
+/// renders at.
+///
+/// `width` and `height` are clamped to at least 1 pixel.
+pub fn render_minimap(layout: &NetworkLayout, display: &DisplayOptions, width: usize, height: usize) -> Canvas {
+    let width = width.max(1);
+    let height = height.max(1);
+    let palette = ColorPalette::default_palette();
+    let value_bounds = display.node_value_attribute.as_deref().and_then(|attr| node_value_bounds(layout, attr));
+    let mut canvas = Canvas::new(width, height, FabricColor::rgb(255, 255, 255));
+
+    let column_count = if display.show_shadows {
+        layout.column_count
+    } else {
+        layout.column_count_no_shadows
+    }
+    .max(1);
+    let row_count = layout.row_count.max(1);
+
+    let x_scale = width as f64 / column_count as f64;
+    let y_scale = height as f64 / row_count as f64;
+
+    for (node_id, nl) in layout.iter_nodes() {
+        let (min_col, max_col, has_edges) = if display.show_shadows {
+            (nl.min_col, nl.max_col, nl.has_edges())
+        } else {
+            (nl.min_col_no_shadows, nl.max_col_no_shadows, nl.has_edges_no_shadows())
+        };
+        if !has_edges {
+            continue;
+        }
+        let y = (nl.row as f64 + 0.5) * y_scale;
+        let x0 = min_col as f64 * x_scale;
+        let x1 = (max_col as f64 + 1.0) * x_scale;
+        let color = node_color(display, layout, node_id, nl.color_index, &palette, value_bounds);
+
+        if display.node_zone_coloring {
+            let (zone_x0, zone_x1) = if display.full_width_zones { (0.0, width as f64) } else { (x0, x1) };
+            let mut zone_color = color;
+            zone_color.a = display.node_zone_opacity;
+            canvas.fill_rect(zone_x0, y - y_scale / 2.0, zone_x1, y + y_scale / 2.0, zone_color, false);
+        }
+
+        canvas.draw_horizontal_line(x0, x1, y, 1.0, color, false);
+    }
+
+    for ll in layout.iter_links() {
+        if ll.is_shadow && !display.show_shadows {
+            continue;
+        }
+        let column = if display.show_shadows {
+            ll.column
+        } else {
+            ll.column_no_shadows.unwrap_or(ll.column)
+        };
+        let x = (column as f64 + 0.5) * x_scale;
+        let y0 = ll.top_row() as f64 * y_scale;
+        let y1 = (ll.bottom_row() as f64 + 1.0) * y_scale;
+        let mut color = node_color(display, layout, &ll.source, ll.color_index, &palette, value_bounds);
+        if ll.is_shadow {
+            color.a = display.shadow_alpha;
+        }
+        // `display.weight_thickness_scale` doesn't apply at minimap scale
+        // (lines are already fixed at one pixel here, see the module doc),
+        // so it's ignored entirely rather than partially honored.
+        let _ = display.weight_thickness_scale;
+        canvas.draw_vertical_line(x, y0, y1, 1.0, color, false);
+    }
+
+    canvas
+}
+
+/// Render a downscaled grayscale density map of `layout`, for a fast
+/// "is this layout sane" glance or a navigation thumbnail, ignoring color
+/// entirely.
+///
+/// Unlike [`render_minimap`], which scales to an exact `width`×`height` and
+/// colors each node/link by its palette index, this scales to fit within
+/// `max_dim` on its longer side (preserving the layout's row/column aspect
+/// ratio) and instead counts how many node/link segments fall in each
+/// output cell. Denser cells are darker; an empty cell stays background
+/// white. This is cheap enough to run on every layout as a sanity check
+/// before a full render.
+///
+/// ## References
+///
+/// (none — not in the Java original)
+pub fn render_density_overview(layout: &NetworkLayout, max_dim: usize) -> Canvas {
+    let max_dim = max_dim.max(1);
+    let column_count = layout.column_count.max(1);
+    let row_count = layout.row_count.max(1);
+
+    let longer_side = column_count.max(row_count) as f64;
+    let scale = max_dim as f64 / longer_side;
+    let width = ((column_count as f64 * scale).round() as usize).max(1);
+    let height = ((row_count as f64 * scale).round() as usize).max(1);
+
+    let x_scale = width as f64 / column_count as f64;
+    let y_scale = height as f64 / row_count as f64;
+
+    let mut density = vec![0u32; width * height];
+    let mut bump = |x: usize, y: usize| {
+        if x < width && y < height {
+            density[y * width + x] += 1;
+        }
+    };
+
+    for (_, nl) in layout.iter_nodes() {
+        if !nl.has_edges() {
+            continue;
+        }
+        let y = (nl.row as f64 * y_scale) as usize;
+        let col_start = (nl.min_col as f64 * x_scale) as usize;
+        let col_end = ((nl.max_col as f64 + 1.0) * x_scale).ceil() as usize;
+        for x in col_start..col_end.max(col_start + 1) {
+            bump(x, y);
+        }
+    }
+
+    for ll in layout.iter_links() {
+        let x = (ll.column as f64 * x_scale) as usize;
+        let row_start = (ll.top_row() as f64 * y_scale) as usize;
+        let row_end = ((ll.bottom_row() as f64 + 1.0) * y_scale).ceil() as usize;
+        for y in row_start..row_end.max(row_start + 1) {
+            bump(x, y);
+        }
+    }
+
+    let max_count = density.iter().copied().max().unwrap_or(0).max(1);
+    let mut canvas = Canvas::new(width, height, FabricColor::rgb(255, 255, 255));
+    for y in 0..height {
+        for x in 0..width {
+            let count = density[y * width + x];
+            if count > 0 {
+                let intensity = (count as f64 / max_count as f64 * 255.0).round() as u8;
+                let level = 255 - intensity;
+                canvas.fill_rect(x as f64, y as f64, x as f64 + 1.0, y as f64 + 1.0, FabricColor::rgb(level, level, level), false);
+            }
+        }
+    }
+
+    canvas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biofabric_core::layout::{
+        DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout,
+    };
+    use biofabric_core::model::{Link, Network, NodeId};
+    use biofabric_core::worker::NoopMonitor;
+
+    fn star_network() -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "pp"));
+        network.add_link(Link::new("hub", "leafB", "pp"));
+        network.add_link(Link::new("hub", "leafC", "pp"));
+        network
+    }
+
+    #[test]
+    fn test_minimap_dimensions_match_request() {
+        let network = star_network();
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let minimap = render_minimap(&layout, &DisplayOptions::default(), 64, 32);
+
+        assert_eq!(minimap.width(), 64);
+        assert_eq!(minimap.height(), 32);
+    }
+
+    #[test]
+    fn test_minimap_of_nonempty_layout_has_non_background_pixels() {
+        let network = star_network();
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let minimap = render_minimap(&layout, &DisplayOptions::default(), 64, 32);
+        let background = FabricColor::rgb(255, 255, 255);
+
+        let mut found_non_background = false;
+        for y in 0..minimap.height() {
+            for x in 0..minimap.width() {
+                if minimap.get_pixel(x, y) != Some(background) {
+                    found_non_background = true;
+                }
+            }
+        }
+
+        assert!(found_non_background, "minimap of a non-empty layout should draw something");
+    }
+
+    #[test]
+    fn test_density_overview_marks_dense_region_darker_than_sparse_region() {
+        use biofabric_core::layout::result::LinkLayout;
+
+        let mut layout = NetworkLayout::new();
+        layout.row_count = 10;
+        layout.column_count = 10;
+
+        // Five stacked links all in column 0, rows 0..=1: a dense cluster.
+        for i in 0..5 {
+            layout.links.push(LinkLayout::new(
+                0,
+                NodeId::new(format!("dense_src_{i}")),
+                NodeId::new(format!("dense_dst_{i}")),
+                0,
+                1,
+                "pp",
+                false,
+            ));
+        }
+        // One lone link far away in column 9, row 9: a sparse region.
+        layout.links.push(LinkLayout::new(
+            9,
+            NodeId::new("sparse_src"),
+            NodeId::new("sparse_dst"),
+            9,
+            9,
+            "pp",
+            false,
+        ));
+
+        let overview = render_density_overview(&layout, 10);
+        let dense_pixel = overview.get_pixel(0, 0).unwrap();
+        let sparse_pixel = overview.get_pixel(9, 9).unwrap();
+
+        assert!(dense_pixel.r < sparse_pixel.r, "dense region ({dense_pixel:?}) should be darker than sparse region ({sparse_pixel:?})");
+    }
+
+    #[test]
+    fn test_minimap_of_empty_layout_is_all_background() {
+        let layout = NetworkLayout::new();
+        let minimap = render_minimap(&layout, &DisplayOptions::default(), 16, 16);
+        let background = FabricColor::rgb(255, 255, 255);
+
+        for y in 0..minimap.height() {
+            for x in 0..minimap.width() {
+                assert_eq!(minimap.get_pixel(x, y), Some(background));
+            }
+        }
+    }
+}
@@ -0,0 +1,665 @@
+//! Per-node "fabric card" mini-renders.
+//!
+//! A fabric card is a small raster image of a single node's immediate
+//! neighborhood: its own row, its neighbors' rows, and the columns of its
+//! incident links. It's meant for thumbnail galleries, where rendering the
+//! full network per node would be wasteful.
+//!
+//! Cropping reuses [`NetworkLayout::extract_submodel`], the same
+//! row/column-compression logic `biofabric-core` already uses for full
+//! subnetwork extraction, so a card's layout is consistent with what a
+//! "real" extraction of that neighborhood would produce.
+
+use crate::Canvas;
+use biofabric_core::io::color::{ColorPalette, FabricColor};
+use biofabric_core::io::display_options::DisplayOptions;
+use biofabric_core::layout::result::NetworkLayout;
+use biofabric_core::model::{Network, NodeId};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Errors that can occur while rendering a node card.
+#[derive(Error, Debug)]
+pub enum RenderError {
+    /// `node` has no entry in `layout`.
+    #[error("node {0} not found in layout")]
+    UnknownNode(NodeId),
+}
+
+/// Pixel size of one row/column grid cell in a rendered card.
+pub(crate) const CELL_SIZE: f64 = 10.0;
+
+/// Render a compact card for `node`: its row, its neighbors' rows, and the
+/// columns of its incident links, cropped from `layout` and drawn onto a
+/// fresh [`Canvas`].
+///
+/// # Errors
+///
+/// Returns [`RenderError::UnknownNode`] if `node` isn't present in `layout`.
+pub fn render_node_card(
+    network: &Network,
+    layout: &NetworkLayout,
+    node: &NodeId,
+    display: &DisplayOptions,
+) -> Result<Canvas, RenderError> {
+    if layout.get_node(node).is_none() {
+        return Err(RenderError::UnknownNode(node.clone()));
+    }
+
+    let mut extract_set: HashSet<NodeId> = HashSet::new();
+    extract_set.insert(node.clone());
+    for ll in layout.iter_links() {
+        if &ll.source == node {
+            extract_set.insert(ll.target.clone());
+        } else if &ll.target == node {
+            extract_set.insert(ll.source.clone());
+        }
+    }
+
+    let (_sub_network, sub_layout) = layout.extract_submodel(network, &extract_set);
+
+    Ok(render_layout(&sub_layout, display))
+}
+
+/// Resolve the palette index to use for `node`, preferring
+/// [`DisplayOptions::color_assignment`] (so the node matches its color in
+/// any other render sharing the same assignment) and falling back to the
+/// layout-assigned `fallback` index when `node` isn't covered by it.
+pub(crate) fn shared_color_index(display: &DisplayOptions, node: &NodeId, fallback: usize) -> usize {
+    display
+        .color_assignment
+        .as_ref()
+        .and_then(|assignment| assignment.color_index(node))
+        .unwrap_or(fallback)
+}
+
+/// Resolve the color to draw `node` (and, by extension, its incident links,
+/// which reuse their source node's color) with.
+///
+/// When [`DisplayOptions::node_value_attribute`] names an attribute and
+/// `node` carries a parseable numeric value for it, that value is mapped
+/// through [`ColorPalette::ramp`] against `value_bounds` — the min/max of
+/// that attribute across the whole layout, from [`node_value_bounds`].
+/// Otherwise falls back to the cyclic palette via [`shared_color_index`].
+///
+/// ## References
+///
+/// (none — not in the Java original)
+pub(crate) fn node_color(
+    display: &DisplayOptions,
+    layout: &NetworkLayout,
+    node: &NodeId,
+    fallback_index: usize,
+    palette: &ColorPalette,
+    value_bounds: Option<(f64, f64)>,
+) -> FabricColor {
+    if let (Some(attribute), Some((min, max))) = (&display.node_value_attribute, value_bounds) {
+        let value = layout
+            .node_attributes
+            .get(node)
+            .and_then(|attrs| attrs.get(attribute))
+            .and_then(|v| v.parse::<f64>().ok());
+        if let Some(value) = value {
+            return ColorPalette::ramp(value, min, max);
+        }
+    }
+    palette.get(shared_color_index(display, node, fallback_index))
+}
+
+/// The `(min, max)` value among `layout.node_attributes`' entries for
+/// `attribute`, parsed as numbers — `None` if no node has a parseable
+/// value, so callers can skip ramp coloring and fall back to the palette.
+pub(crate) fn node_value_bounds(layout: &NetworkLayout, attribute: &str) -> Option<(f64, f64)> {
+    layout
+        .node_attributes
+        .values()
+        .filter_map(|attrs| attrs.get(attribute))
+        .filter_map(|v| v.parse::<f64>().ok())
+        .fold(None, |acc, v| match acc {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+        })
+}
+
+/// The `(min, max)` weight among `layout`'s links that carry one, or `None`
+/// if no link has a weight to normalize against.
+pub(crate) fn weight_bounds(layout: &NetworkLayout) -> Option<(f64, f64)> {
+    layout.iter_links().filter_map(|ll| ll.weight).fold(None, |acc, w| match acc {
+        None => Some((w, w)),
+        Some((lo, hi)) => Some((lo.min(w), hi.max(w))),
+    })
+}
+
+/// Resolve the screen-pixel line width for a link, scaling by its
+/// normalized weight when [`DisplayOptions::weight_thickness_scale`] is
+/// set and the link has a weight; otherwise falls back to
+/// [`DisplayOptions::link_line_width`].
+///
+/// `bounds` is the `(min, max)` weight across the whole layout, from
+/// [`weight_bounds`] — weight is normalized against it rather than against
+/// a fixed scale, since raw weights can be in any unit. A layout where
+/// every weighted link shares the same weight normalizes to the midpoint
+/// of the thickness range.
+pub(crate) fn scaled_link_width(
+    display: &DisplayOptions,
+    weight: Option<f64>,
+    bounds: Option<(f64, f64)>,
+) -> f64 {
+    let (Some((min_px, max_px)), Some(w), Some((min_w, max_w))) =
+        (display.weight_thickness_scale, weight, bounds)
+    else {
+        return display.link_line_width;
+    };
+
+    if (max_w - min_w).abs() < f64::EPSILON {
+        return (min_px + max_px) / 2.0;
+    }
+
+    let t = (w - min_w) / (max_w - min_w);
+    min_px + t * (max_px - min_px)
+}
+
+/// Draw every node row and link column in `layout` onto a fresh canvas,
+/// one [`CELL_SIZE`]-pixel grid cell per row/column.
+///
+/// This is deliberately bare — no labels, no annotations — since it backs
+/// thumbnail-sized card renders rather than full exports.
+pub(crate) fn render_layout(layout: &NetworkLayout, display: &DisplayOptions) -> Canvas {
+    let palette = ColorPalette::default_palette();
+    let value_bounds = display.node_value_attribute.as_deref().and_then(|attr| node_value_bounds(layout, attr));
+    let width = ((layout.column_count.max(1)) as f64 * CELL_SIZE).ceil() as usize;
+    let height = ((layout.row_count.max(1)) as f64 * CELL_SIZE).ceil() as usize;
+    let mut canvas = Canvas::new(width, height, FabricColor::rgb(255, 255, 255));
+
+    for (node_id, nl) in layout.iter_nodes() {
+        let (min_col, max_col, has_edges) = if display.show_shadows {
+            (nl.min_col, nl.max_col, nl.has_edges())
+        } else {
+            (nl.min_col_no_shadows, nl.max_col_no_shadows, nl.has_edges_no_shadows())
+        };
+        if !has_edges {
+            continue;
+        }
+        let y = (nl.row as f64 + 0.5) * CELL_SIZE;
+        let x0 = min_col as f64 * CELL_SIZE;
+        let x1 = (max_col as f64 + 1.0) * CELL_SIZE;
+        let color = node_color(display, layout, node_id, nl.color_index, &palette, value_bounds);
+
+        if display.node_zone_coloring {
+            let (zone_x0, zone_x1) = if display.full_width_zones {
+                (0.0, width as f64)
+            } else {
+                (x0, x1)
+            };
+            let mut zone_color = color;
+            zone_color.a = display.node_zone_opacity;
+            canvas.fill_rect(zone_x0, y - CELL_SIZE / 2.0, zone_x1, y + CELL_SIZE / 2.0, zone_color, display.antialias);
+        }
+
+        canvas.draw_horizontal_line(x0, x1, y, display.node_line_width, color, display.antialias);
+    }
+
+    let bounds = weight_bounds(layout);
+    for ll in layout.iter_links() {
+        if ll.is_shadow && !display.show_shadows {
+            continue;
+        }
+        let column = if display.show_shadows {
+            ll.column
+        } else {
+            ll.column_no_shadows.unwrap_or(ll.column)
+        };
+        let x = (column as f64 + 0.5) * CELL_SIZE;
+        let y0 = ll.top_row() as f64 * CELL_SIZE;
+        let y1 = (ll.bottom_row() as f64 + 1.0) * CELL_SIZE;
+        let mut color = node_color(display, layout, &ll.source, ll.color_index, &palette, value_bounds);
+        if ll.is_shadow {
+            color.a = display.shadow_alpha;
+        }
+        let width = scaled_link_width(display, ll.weight, bounds);
+        canvas.draw_vertical_line(x, y0, y1, width, color, display.antialias);
+    }
+
+    canvas
+}
+
+/// One `<rect>` worth of geometry and resolved color, for
+/// [`render_layout_svg_parts`].
+pub(crate) struct SvgRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub alpha: f64,
+}
+
+/// One `<line>` worth of geometry and resolved color, for
+/// [`render_layout_svg_parts`].
+pub(crate) struct SvgLine {
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+    pub width: f64,
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub alpha: f64,
+}
+
+/// Compute the same node-row/link-column geometry [`render_layout`] draws
+/// onto a [`Canvas`], as plain data instead of pixels, so a vector
+/// exporter (`svg::render_layout_to_svg`) can emit it as markup.
+///
+/// Returns `(width, height, zone-tint rects, node/link lines)`, using the
+/// same [`CELL_SIZE`] scale and the same color resolution
+/// (`shared_color_index`) as the raster path, so a vector export and a
+/// raster render of the same layout agree on every line's position and
+/// color.
+pub(crate) fn render_layout_svg_parts(
+    layout: &NetworkLayout,
+    display: &DisplayOptions,
+) -> (f64, f64, Vec<SvgRect>, Vec<SvgLine>) {
+    let palette = ColorPalette::default_palette();
+    let value_bounds = display.node_value_attribute.as_deref().and_then(|attr| node_value_bounds(layout, attr));
+    let width = (layout.column_count.max(1)) as f64 * CELL_SIZE;
+    let height = (layout.row_count.max(1)) as f64 * CELL_SIZE;
+
+    let mut rects = Vec::new();
+    let mut lines = Vec::new();
+
+    for (node_id, nl) in layout.iter_nodes() {
+        let (min_col, max_col, has_edges) = if display.show_shadows {
+            (nl.min_col, nl.max_col, nl.has_edges())
+        } else {
+            (nl.min_col_no_shadows, nl.max_col_no_shadows, nl.has_edges_no_shadows())
+        };
+        if !has_edges {
+            continue;
+        }
+        let y = (nl.row as f64 + 0.5) * CELL_SIZE;
+        let x0 = min_col as f64 * CELL_SIZE;
+        let x1 = (max_col as f64 + 1.0) * CELL_SIZE;
+        let color = node_color(display, layout, node_id, nl.color_index, &palette, value_bounds);
+
+        if display.node_zone_coloring {
+            let (zone_x0, zone_x1) = if display.full_width_zones {
+                (0.0, width)
+            } else {
+                (x0, x1)
+            };
+            rects.push(SvgRect {
+                x: zone_x0,
+                y: y - CELL_SIZE / 2.0,
+                width: zone_x1 - zone_x0,
+                height: CELL_SIZE,
+                r: color.r,
+                g: color.g,
+                b: color.b,
+                alpha: display.node_zone_opacity as f64 / 255.0,
+            });
+        }
+
+        lines.push(SvgLine {
+            x1: x0,
+            y1: y,
+            x2: x1,
+            y2: y,
+            width: display.node_line_width,
+            r: color.r,
+            g: color.g,
+            b: color.b,
+            alpha: color.a as f64 / 255.0,
+        });
+    }
+
+    let bounds = weight_bounds(layout);
+    for ll in layout.iter_links() {
+        if ll.is_shadow && !display.show_shadows {
+            continue;
+        }
+        let column = if display.show_shadows {
+            ll.column
+        } else {
+            ll.column_no_shadows.unwrap_or(ll.column)
+        };
+        let x = (column as f64 + 0.5) * CELL_SIZE;
+        let y0 = ll.top_row() as f64 * CELL_SIZE;
+        let y1 = (ll.bottom_row() as f64 + 1.0) * CELL_SIZE;
+        let mut color = node_color(display, layout, &ll.source, ll.color_index, &palette, value_bounds);
+        if ll.is_shadow {
+            color.a = display.shadow_alpha;
+        }
+        lines.push(SvgLine {
+            x1: x,
+            y1: y0,
+            x2: x,
+            y2: y1,
+            width: scaled_link_width(display, ll.weight, bounds),
+            r: color.r,
+            g: color.g,
+            b: color.b,
+            alpha: color.a as f64 / 255.0,
+        });
+    }
+
+    (width, height, rects, lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biofabric_core::io::color::ColorAssignment;
+    use biofabric_core::layout::{
+        DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout,
+    };
+    use biofabric_core::model::Link;
+    use biofabric_core::worker::NoopMonitor;
+
+    fn star_network() -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "pp"));
+        network.add_link(Link::new("hub", "leafB", "pp"));
+        network.add_link(Link::new("hub", "leafC", "pp"));
+        network
+    }
+
+    #[test]
+    fn test_node_card_for_hub_contains_all_leaf_rows() {
+        let network = star_network();
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let hub = NodeId::new("hub");
+        let card = render_node_card(&network, &layout, &hub, &DisplayOptions::default()).unwrap();
+
+        // The card has one row per node in the star (hub + 3 leaves), so it
+        // must be tall enough to hold all of them.
+        assert!(card.height() >= 4 * CELL_SIZE as usize);
+
+        // Rendering an unknown node is an error, not a panic.
+        let unknown = NodeId::new("does-not-exist");
+        assert!(matches!(
+            render_node_card(&network, &layout, &unknown, &DisplayOptions::default()),
+            Err(RenderError::UnknownNode(_))
+        ));
+    }
+
+    #[test]
+    fn test_color_assignment_colors_shared_node_identically_across_renders() {
+        let mut network_a = Network::new();
+        network_a.add_link(Link::new("hub", "leafA", "pp"));
+        network_a.add_link(Link::new("hub", "leafB", "pp"));
+
+        let mut network_b = Network::new();
+        network_b.add_link(Link::new("hub", "otherX", "pp"));
+        network_b.add_link(Link::new("otherX", "otherY", "pp"));
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout_a = layout_algo
+            .layout(&network_a, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+        let layout_b = layout_algo
+            .layout(&network_b, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let mut display = DisplayOptions::default();
+        display.color_assignment = Some(ColorAssignment::from_names([
+            "hub", "leafA", "leafB", "otherX", "otherY",
+        ]));
+
+        let canvas_a = render_layout(&layout_a, &display);
+        let canvas_b = render_layout(&layout_b, &display);
+
+        let hub = NodeId::new("hub");
+        let row_a = layout_a.get_node(&hub).unwrap().row;
+        let row_b = layout_b.get_node(&hub).unwrap().row;
+        let y_a = ((row_a as f64 + 0.5) * CELL_SIZE) as usize;
+        let y_b = ((row_b as f64 + 0.5) * CELL_SIZE) as usize;
+
+        let hub_color_a = (0..canvas_a.width())
+            .filter_map(|x| canvas_a.get_pixel(x, y_a))
+            .find(|&c| c != FabricColor::rgb(255, 255, 255))
+            .expect("hub's row should have a drawn line in render A");
+        let hub_color_b = (0..canvas_b.width())
+            .filter_map(|x| canvas_b.get_pixel(x, y_b))
+            .find(|&c| c != FabricColor::rgb(255, 255, 255))
+            .expect("hub's row should have a drawn line in render B");
+
+        assert_eq!(hub_color_a, hub_color_b);
+    }
+
+    #[test]
+    fn test_node_value_attribute_colors_nodes_by_ramp_instead_of_palette() {
+        let mut network = star_network();
+        network.set_node_attribute(&NodeId::new("hub"), "expression", "0.0");
+        network.set_node_attribute(&NodeId::new("leafA"), "expression", "10.0");
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let mut display = DisplayOptions::default();
+        display.node_value_attribute = Some("expression".to_string());
+
+        let canvas = render_layout(&layout, &display);
+
+        let hub_row = layout.get_node(&NodeId::new("hub")).unwrap().row;
+        let leaf_a_row = layout.get_node(&NodeId::new("leafA")).unwrap().row;
+        let hub_y = ((hub_row as f64 + 0.5) * CELL_SIZE) as usize;
+        let leaf_a_y = ((leaf_a_row as f64 + 0.5) * CELL_SIZE) as usize;
+
+        let hub_color = (0..canvas.width())
+            .filter_map(|x| canvas.get_pixel(x, hub_y))
+            .find(|&c| c != FabricColor::rgb(255, 255, 255))
+            .expect("hub's row should have a drawn line");
+        let leaf_a_color = (0..canvas.width())
+            .filter_map(|x| canvas.get_pixel(x, leaf_a_y))
+            .find(|&c| c != FabricColor::rgb(255, 255, 255))
+            .expect("leafA's row should have a drawn line");
+
+        assert_eq!(hub_color, FabricColor::rgb(0, 0, 255));
+        assert_eq!(leaf_a_color, FabricColor::rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_weight_thickness_scale_is_a_noop_without_edge_weights() {
+        let network = star_network();
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        // None of `star_network`'s links carry a weight, so there's nothing
+        // to normalize against — setting `weight_thickness_scale` should
+        // render identically to leaving it unset.
+        let plain = render_layout(&layout, &DisplayOptions::default());
+        let mut scaled_display = DisplayOptions::default();
+        scaled_display.weight_thickness_scale = Some((0.5, 5.0));
+        let scaled = render_layout(&layout, &scaled_display);
+
+        assert_eq!(plain.width(), scaled.width());
+        assert_eq!(plain.height(), scaled.height());
+        for y in 0..plain.height() {
+            for x in 0..plain.width() {
+                assert_eq!(plain.get_pixel(x, y), scaled.get_pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn test_weight_thickness_scale_widens_heavier_links() {
+        let mut network = Network::new();
+        network.add_link({
+            let mut link = Link::new("hub", "leafA", "pp");
+            link.weight = Some(0.0);
+            link
+        });
+        network.add_link({
+            let mut link = Link::new("hub", "leafB", "pp");
+            link.weight = Some(10.0);
+            link
+        });
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let bounds = weight_bounds(&layout);
+        assert_eq!(bounds, Some((0.0, 10.0)));
+
+        let mut display = DisplayOptions::default();
+        display.weight_thickness_scale = Some((1.0, 9.0));
+
+        let light_width = scaled_link_width(&display, Some(0.0), bounds);
+        let heavy_width = scaled_link_width(&display, Some(10.0), bounds);
+        assert_eq!(light_width, 1.0);
+        assert_eq!(heavy_width, 9.0);
+
+        // An actual render with this display should differ from a render at
+        // the flat default width, since the links are no longer drawn at a
+        // uniform thickness.
+        let plain = render_layout(&layout, &DisplayOptions::default());
+        let scaled = render_layout(&layout, &display);
+        assert_eq!(plain.width(), scaled.width());
+        assert_eq!(plain.height(), scaled.height());
+        let mut any_pixel_differs = false;
+        for y in 0..plain.height() {
+            for x in 0..plain.width() {
+                if plain.get_pixel(x, y) != scaled.get_pixel(x, y) {
+                    any_pixel_differs = true;
+                }
+            }
+        }
+        assert!(any_pixel_differs);
+    }
+
+    /// Render a shadowed star network and return the pixel color found
+    /// partway down the first shadow link's column.
+    fn render_and_sample_shadow_link(shadow_alpha: u8) -> FabricColor {
+        let mut network = star_network();
+        network.generate_shadows();
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let params = LayoutParams {
+            include_shadows: true,
+            ..Default::default()
+        };
+        let layout = layout_algo.layout(&network, &params, &NoopMonitor).unwrap();
+
+        let shadow_link = layout
+            .iter_links()
+            .find(|ll| ll.is_shadow)
+            .expect("star network with shadows generated should have a shadow link");
+        let x = ((shadow_link.column as f64) + 0.5) * CELL_SIZE;
+        let y = ((shadow_link.top_row() as f64) + 0.5) * CELL_SIZE;
+
+        let mut display = DisplayOptions::default();
+        display.show_shadows = true;
+        display.shadow_alpha = shadow_alpha;
+
+        let canvas = render_layout(&layout, &display);
+        canvas
+            .get_pixel(x as usize, y as usize)
+            .expect("shadow link column should be within the canvas")
+    }
+
+    #[test]
+    fn test_shadow_alpha_255_renders_shadow_links_fully_opaque() {
+        let white = FabricColor::rgb(255, 255, 255);
+        let pixel = render_and_sample_shadow_link(255);
+        assert_ne!(pixel, white);
+        assert_eq!(pixel.a, 255);
+    }
+
+    #[test]
+    fn test_shadow_alpha_40_renders_shadow_links_fainter_than_255() {
+        let opaque = render_and_sample_shadow_link(255);
+        let faint = render_and_sample_shadow_link(40);
+
+        // Blended against the white canvas background, a low-alpha shadow
+        // line should end up visibly closer to white than the opaque one.
+        let distance_from_white = |c: FabricColor| -> u32 {
+            (255 - c.r as u32) + (255 - c.g as u32) + (255 - c.b as u32)
+        };
+        assert!(distance_from_white(faint) < distance_from_white(opaque));
+    }
+
+    #[test]
+    fn test_default_node_zone_coloring_tints_the_nodes_link_span() {
+        let network = star_network();
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let hub = NodeId::new("hub");
+        let hub_nl = layout.get_node(&hub).unwrap();
+
+        let plain = render_layout(&layout, &DisplayOptions::default());
+
+        let mut display = DisplayOptions::default();
+        display.node_zone_coloring = true;
+        display.node_zone_opacity = 255;
+        let tinted = render_layout(&layout, &display);
+
+        // Sample near the top-left corner of the node's span: a point
+        // that's inside the zone band but away from both the node's own
+        // horizontal line (centered in the row) and any link's vertical
+        // line (centered in its column), so only the zone tint can
+        // account for a difference here.
+        let y = (hub_nl.row as f64 * CELL_SIZE + 1.0) as usize;
+        let inside_x = (hub_nl.min_col as f64 * CELL_SIZE + 1.0) as usize;
+
+        assert_ne!(plain.get_pixel(inside_x, y), tinted.get_pixel(inside_x, y));
+    }
+
+    #[test]
+    fn test_full_width_node_zone_coloring_tints_the_entire_row() {
+        // Two disconnected edges: the layout places `nodeX`-`nodeY` in its
+        // own row range, so its link column never crosses `hub`'s row and
+        // can't be mistaken for hub's own zone tint.
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "pp"));
+        network.add_link(Link::new("nodeX", "nodeY", "pp"));
+
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let hub = NodeId::new("hub");
+        let hub_nl = layout.get_node(&hub).unwrap();
+        let y = ((hub_nl.row as f64 + 0.5) * CELL_SIZE) as usize;
+        let white = FabricColor::rgb(255, 255, 255);
+
+        let mut default_display = DisplayOptions::default();
+        default_display.node_zone_coloring = true;
+        default_display.node_zone_opacity = 255;
+        let default_canvas = render_layout(&layout, &default_display);
+
+        let mut full_width_display = default_display.clone();
+        full_width_display.full_width_zones = true;
+        let full_width_canvas = render_layout(&layout, &full_width_display);
+
+        // A column past hub's own link span, on a row range the other
+        // edge doesn't touch: untinted in default mode, tinted in
+        // full-width mode.
+        let far_col = layout.column_count.saturating_sub(1);
+        assert!(far_col > hub_nl.max_col, "test network should have a column beyond hub's span");
+        let far_x = ((far_col as f64) + 0.5) * CELL_SIZE;
+        assert_eq!(default_canvas.get_pixel(far_x as usize, y), Some(white));
+        assert_ne!(full_width_canvas.get_pixel(far_x as usize, y), Some(white));
+
+        // The full-width band must reach all the way to the image's right edge.
+        let last_pixel_x = full_width_canvas.width() - 1;
+        assert_ne!(full_width_canvas.get_pixel(last_pixel_x, y), Some(white));
+    }
+}
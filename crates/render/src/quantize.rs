@@ -0,0 +1,145 @@
+//! Indexed-palette quantization for rendered canvases.
+//!
+//! BioFabric renders are almost entirely flat fills (node/link/background
+//! colors drawn from a bounded palette plus a handful of annotation tints),
+//! so a truecolor PNG of one wastes most of its bytes on a color depth the
+//! image never uses. [`quantize`] converts a [`Canvas`] into an
+//! [`IndexedImage`]: a small palette of the colors actually present plus a
+//! per-pixel index into it. This crate stays encoder-agnostic (see the
+//! crate-level docs), so turning an [`IndexedImage`] into an indexed PNG is
+//! left to whatever downstream code owns the PNG encoder.
+
+use crate::canvas::Canvas;
+use biofabric_core::io::color::FabricColor;
+use std::collections::HashMap;
+
+/// A raster image addressed through a palette rather than direct color.
+#[derive(Debug, Clone)]
+pub struct IndexedImage {
+    /// Image width in pixels.
+    pub width: usize,
+
+    /// Image height in pixels.
+    pub height: usize,
+
+    /// The distinct colors used by this image, in first-seen order.
+    pub palette: Vec<FabricColor>,
+
+    /// Per-pixel index into `palette`, row-major (same layout as [`Canvas`]).
+    pub indices: Vec<u8>,
+}
+
+impl IndexedImage {
+    /// Look up the color at `(x, y)` by resolving its palette index.
+    pub fn get_pixel(&self, x: usize, y: usize) -> Option<FabricColor> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = self.indices[y * self.width + x];
+        self.palette.get(index as usize).copied()
+    }
+}
+
+/// Quantize a [`Canvas`] into an indexed-palette image.
+///
+/// Since BioFabric renders draw from a small fixed color set (the node/link
+/// palette plus annotation tints), this is a lossless, exact color-to-index
+/// mapping rather than an approximate quantization — every pixel in the
+/// result decodes back to precisely the color it had in `canvas`.
+///
+/// # Errors
+///
+/// Returns [`QuantizeError::TooManyColors`] if the canvas uses more than 256
+/// distinct colors, since palette indices are stored as `u8`. This should
+/// not happen for a normal BioFabric render; it indicates the canvas was
+/// drawn with antialiasing (which blends colors continuously) or otherwise
+/// isn't the flat-fill style this format is meant for.
+pub fn quantize(canvas: &Canvas) -> Result<IndexedImage, QuantizeError> {
+    let mut palette: Vec<FabricColor> = Vec::new();
+    let mut palette_lookup: HashMap<(u8, u8, u8, u8), u8> = HashMap::new();
+    let mut indices = Vec::with_capacity(canvas.width() * canvas.height());
+
+    for y in 0..canvas.height() {
+        for x in 0..canvas.width() {
+            let color = canvas.get_pixel(x, y).expect("in-bounds pixel");
+            let key = (color.r, color.g, color.b, color.a);
+            let index = match palette_lookup.get(&key) {
+                Some(&index) => index,
+                None => {
+                    let index = palette.len();
+                    if index > u8::MAX as usize {
+                        return Err(QuantizeError::TooManyColors(palette.len() + 1));
+                    }
+                    let index = index as u8;
+                    palette.push(color);
+                    palette_lookup.insert(key, index);
+                    index
+                }
+            };
+            indices.push(index);
+        }
+    }
+
+    Ok(IndexedImage {
+        width: canvas.width(),
+        height: canvas.height(),
+        palette,
+        indices,
+    })
+}
+
+/// Errors from [`quantize`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum QuantizeError {
+    /// The canvas used more distinct colors than a `u8` palette index can address.
+    #[error("canvas uses {0} distinct colors, which exceeds the 256-color indexed palette limit")]
+    TooManyColors(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantize_decodes_to_identical_pixels() {
+        let mut canvas = Canvas::new(4, 4, FabricColor::rgb(255, 255, 255));
+        canvas.fill_rect(0.0, 0.0, 2.0, 2.0, FabricColor::rgb(200, 0, 0), false);
+        canvas.fill_rect(2.0, 2.0, 4.0, 4.0, FabricColor::rgb(0, 0, 200), false);
+
+        let indexed = quantize(&canvas).unwrap();
+
+        for y in 0..canvas.height() {
+            for x in 0..canvas.width() {
+                assert_eq!(
+                    indexed.get_pixel(x, y),
+                    canvas.get_pixel(x, y),
+                    "mismatch at ({x}, {y})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_quantize_palette_is_bounded_by_distinct_colors() {
+        let mut canvas = Canvas::new(10, 10, FabricColor::rgb(255, 255, 255));
+        canvas.fill_rect(0.0, 0.0, 5.0, 5.0, FabricColor::rgb(10, 20, 30), false);
+
+        let indexed = quantize(&canvas).unwrap();
+
+        // Only the background and the one fill color are present.
+        assert_eq!(indexed.palette.len(), 2);
+    }
+
+    #[test]
+    fn test_quantize_rejects_more_than_256_colors() {
+        // 257 distinct colors, one past the u8 index limit.
+        let mut canvas = Canvas::new(257, 1, FabricColor::rgb(0, 0, 0));
+        for x in 0..canvas.width() {
+            let shade = (x % 256) as u8;
+            canvas.fill_rect(x as f64, 0.0, (x + 1) as f64, 1.0, FabricColor::rgb(shade, (x / 256) as u8, 0), false);
+        }
+
+        let err = quantize(&canvas).unwrap_err();
+        assert!(matches!(err, QuantizeError::TooManyColors(_)));
+    }
+}
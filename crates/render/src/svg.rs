@@ -0,0 +1,95 @@
+//! SVG export for fabric layouts.
+//!
+//! Mirrors [`node_card`](crate::node_card)'s raster layout renderer — the
+//! same grid of node horizontals and link verticals, at the same
+//! [`CELL_SIZE`](crate::node_card) scale and with the same color resolution
+//! (`DisplayOptions::color_assignment` via `shared_color_index`) — but
+//! emits `<line>`/`<rect>` SVG elements instead of pixels, so a figure can
+//! be embedded in a paper and scaled without quality loss.
+//!
+//! This is deliberately a standalone full-layout export (there's no
+//! `extract_submodel` cropping here, unlike [`render_node_card`](crate::render_node_card));
+//! it's meant for exporting a whole network, not a per-node card.
+
+use crate::node_card::render_layout_svg_parts;
+use biofabric_core::io::display_options::DisplayOptions;
+use biofabric_core::layout::result::NetworkLayout;
+
+/// Render `layout` to a standalone SVG document string.
+///
+/// Node rows are drawn as horizontal `<line>` elements, link columns as
+/// vertical `<line>` elements, and (when `display.node_zone_coloring` is
+/// set) node zone tints as translucent `<rect>` elements — the vector
+/// equivalent of what [`node_card::render_node_card`](crate::render_node_card)
+/// draws onto a raster [`Canvas`](crate::Canvas).
+pub fn render_layout_to_svg(layout: &NetworkLayout, display: &DisplayOptions) -> String {
+    let (width, height, rects, lines) = render_layout_svg_parts(layout, display);
+
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+    ));
+    for rect in &rects {
+        svg.push_str(&format!(
+            "  <rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" fill=\"rgb({},{},{})\" fill-opacity=\"{:.4}\"/>\n",
+            rect.x, rect.y, rect.width, rect.height, rect.r, rect.g, rect.b, rect.alpha,
+        ));
+    }
+    for line in &lines {
+        svg.push_str(&format!(
+            "  <line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\" stroke=\"rgb({},{},{})\" stroke-opacity=\"{:.4}\" stroke-width=\"{:.2}\"/>\n",
+            line.x1, line.y1, line.x2, line.y2, line.r, line.g, line.b, line.alpha, line.width,
+        ));
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Number of `<line>` elements a call to [`render_layout_to_svg`] would
+/// emit for `layout` under `display` — node horizontals plus link
+/// verticals — matching what the caller would count by grepping the
+/// output for `"<line"`.
+#[cfg(test)]
+fn count_svg_lines(layout: &NetworkLayout, display: &DisplayOptions) -> usize {
+    let (_, _, _, lines) = render_layout_svg_parts(layout, display);
+    lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biofabric_core::layout::{
+        DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout,
+    };
+    use biofabric_core::model::{Link, Network};
+    use biofabric_core::worker::NoopMonitor;
+
+    fn star_network() -> Network {
+        let mut network = Network::new();
+        network.add_link(Link::new("hub", "leafA", "pp"));
+        network.add_link(Link::new("hub", "leafB", "pp"));
+        network.add_link(Link::new("hub", "leafC", "pp"));
+        network
+    }
+
+    #[test]
+    fn test_svg_contains_one_line_per_drawn_node_row_and_link_column() {
+        let network = star_network();
+        let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+        let layout = layout_algo
+            .layout(&network, &LayoutParams::default(), &NoopMonitor)
+            .unwrap();
+
+        let display = DisplayOptions::default();
+        let svg = render_layout_to_svg(&layout, &display);
+
+        let expected = count_svg_lines(&layout, &display);
+        // 4 nodes (hub + 3 leaves) each with edges, plus 3 links.
+        assert_eq!(expected, 4 + 3);
+
+        let actual_line_count = svg.matches("<line").count();
+        assert_eq!(actual_line_count, expected);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -0,0 +1,53 @@
+// End-to-end smoke test: a zero-node network survives the full
+// layout -> render -> quantize -> BIF pipeline without panicking.
+
+use biofabric_core::io::color::FabricColor;
+use biofabric_core::io::display_options::DisplayOptions;
+use biofabric_core::io::session::Session;
+use biofabric_core::io::sif;
+use biofabric_core::io::xml::{read_session_reader, write_session_string};
+use biofabric_core::layout::{
+    DefaultEdgeLayout, DefaultNodeLayout, LayoutParams, NetworkLayoutAlgorithm, TwoPhaseLayout,
+};
+use biofabric_core::worker::NoopMonitor;
+use biofabric_render::{quantize, render_minimap};
+use std::io::BufReader;
+
+#[test]
+fn empty_network_survives_layout_render_and_bif_roundtrip() {
+    // A SIF file with only a comment line parses to a network with no
+    // nodes or links at all.
+    let network = sif::parse_string("# just a comment\n").unwrap();
+    assert_eq!(network.node_count(), 0);
+
+    let layout_algo = TwoPhaseLayout::new(DefaultNodeLayout::new(), DefaultEdgeLayout::new());
+    let layout = layout_algo
+        .layout(&network, &LayoutParams::default(), &NoopMonitor)
+        .unwrap();
+    assert_eq!(layout.row_count, 0);
+    assert_eq!(layout.column_count, 0);
+
+    // Render: a blank canvas at the requested size, not a panic or a
+    // zero-sized image.
+    let minimap = render_minimap(&layout, &DisplayOptions::default(), 64, 32);
+    assert_eq!((minimap.width(), minimap.height()), (64, 32));
+    let background = FabricColor::rgb(255, 255, 255);
+    for y in 0..minimap.height() {
+        for x in 0..minimap.width() {
+            assert_eq!(minimap.get_pixel(x, y), Some(background));
+        }
+    }
+
+    // Quantization (the step before PNG encoding, which this crate
+    // deliberately leaves to downstream code) sees a single-color palette.
+    let indexed = quantize(&minimap).unwrap();
+    assert_eq!((indexed.width, indexed.height), (64, 32));
+    assert_eq!(indexed.palette, vec![background]);
+
+    // BIF export/import: a valid, parseable empty session.
+    let session = Session::with_layout(network, layout);
+    let xml = write_session_string(&session).unwrap();
+    let roundtripped = read_session_reader(BufReader::new(xml.as_bytes())).unwrap();
+    assert_eq!(roundtripped.network.node_count(), 0);
+    assert_eq!(roundtripped.layout.unwrap().row_count, 0);
+}